@@ -0,0 +1,526 @@
+//! Custom derive for [`clap`](https://docs.rs/clap) which builds an `App` from a struct or enum
+//! definition, and a constructor which fills that struct or enum from the resulting
+//! `ArgMatches`. This means the arguments only need to be declared once.
+//!
+//! # Structs
+//!
+//! Each field of a struct becomes an [`Arg`] named after the field. The field's doc comment is
+//! used as the argument's help message, and the struct's doc comment as the `App`'s about
+//! message. What kind of argument each field becomes depends on its type:
+//!
+//! * `bool` is a flag, i.e. `matches.is_present(..)`
+//! * `u64` (or any integer) marked with `#[clap(count)]` counts the occurrences of a flag
+//! * `Option<T>` is an optional argument which takes a value
+//! * `Vec<T>` is an argument which takes multiple values, one per occurrence for options (i.e.
+//!   `-l ssl -l z`)
+//! * Anything else (`T`) is a required argument which takes a value, unless it has a
+//!   `default_value`
+//!
+//! Any `T` other than `String` is parsed using it's `FromStr` implementation, and invalid values
+//! are reported by clap as a validation error. Fields with neither a `short` nor a `long` are
+//! positional arguments, except for flags which default to a `long` of the field's name with
+//! underscores replaced by hyphens.
+//!
+//! # Enums
+//!
+//! Each variant of an enum becomes a [`SubCommand`] named after the variant in kebab-case (i.e.
+//! `FetchAll` becomes `fetch-all`) and documented by the variant's doc comment. Fields of struct
+//! variants become the subcommand's arguments following the same rules as struct fields, and a
+//! single field tuple variant uses the arguments of the (also derived) type it contains.
+//!
+//! An enum is used as the subcommands of a struct by adding a field marked with
+//! `#[clap(subcommand)]`. If the field is an `Option` the subcommand is optional, otherwise
+//! [`AppSettings::SubcommandRequiredElseHelp`] is set.
+//!
+//! # Attributes
+//!
+//! Any other `#[clap(..)]` attribute is passed along to the builder as a method call. A
+//! `key = "value"` pair calls `.key("value")` and a bare `word` calls `.word(true)`, the same way
+//! [`clap_app!`] treats `+word`. On a struct or enum these go to the `App`, on a field to the
+//! `Arg`. The additional attributes handled by the derive itself are:
+//!
+//! * `name = ".."` on a struct, enum or variant sets the `App` or `SubCommand` name
+//! * `subcommand` on a field uses the field's (derived) enum type as subcommands
+//! * `count` on an integer field counts the occurrences of a flag
+//! * `arg_enum` on a field sets the possible values to those of an [`arg_enum!`] type
+//!
+//! # Generated methods
+//!
+//! * `fn clap() -> App<'static, 'static>`
+//! * `fn from_matches(&ArgMatches) -> Self`
+//! * `fn from_args() -> Self` which parses `std::env::args_os`
+//!
+//! # Examples
+//!
+//! ```ignore
+//! #[macro_use]
+//! extern crate clap;
+//! #[macro_use]
+//! extern crate clap_derive;
+//!
+//! arg_enum! {
+//!     #[derive(Debug)]
+//!     enum Format { Json, Text }
+//! }
+//!
+//! /// Does awesome things
+//! #[derive(ClapApp)]
+//! #[clap(name = "myapp", version = "1.0")]
+//! struct MyApp {
+//!     /// Turns on verbose output
+//!     #[clap(short = "v", count)]
+//!     verbose: u64,
+//!     /// The output format
+//!     #[clap(long = "format", arg_enum, default_value = "Text")]
+//!     format: Format,
+//!     /// The file to read
+//!     input: String,
+//!     #[clap(subcommand)]
+//!     cmd: Option<Command>,
+//! }
+//!
+//! #[derive(ClapApp)]
+//! enum Command {
+//!     /// Fetches the latest changes
+//!     Fetch {
+//!         /// Only print what would be fetched
+//!         #[clap(long = "dry-run")]
+//!         dry_run: bool,
+//!     },
+//! }
+//!
+//! fn main() {
+//!     let app = MyApp::from_args();
+//! }
+//! ```
+//! [`Arg`]: https://docs.rs/clap/*/clap/struct.Arg.html
+//! [`SubCommand`]: https://docs.rs/clap/*/clap/struct.SubCommand.html
+//! [`AppSettings::SubcommandRequiredElseHelp`]: https://docs.rs/clap/*/clap/enum.AppSettings.html#variant.SubcommandRequiredElseHelp
+//! [`clap_app!`]: https://docs.rs/clap/*/clap/macro.clap_app.html
+//! [`arg_enum!`]: https://docs.rs/clap/*/clap/macro.arg_enum.html
+
+extern crate proc_macro;
+extern crate syn;
+#[macro_use]
+extern crate quote;
+
+use proc_macro::TokenStream;
+use quote::Tokens;
+use syn::{Attribute, Body, DeriveInput, Field, Ident, Lit, MetaItem, NestedMetaItem,
+          PathParameters, StrStyle, Ty, VariantData};
+
+/// Generates the `clap`, `from_matches` and `from_args` methods for a struct or enum. See the
+/// crate level documentation for details.
+#[proc_macro_derive(ClapApp, attributes(clap))]
+pub fn clap_app(input: TokenStream) -> TokenStream {
+    let s = input.to_string();
+    let ast = syn::parse_derive_input(&s).unwrap();
+    let gen = impl_clap_app(&ast);
+    gen.parse().unwrap()
+}
+
+fn impl_clap_app(ast: &DeriveInput) -> Tokens {
+    match ast.body {
+        Body::Struct(VariantData::Struct(ref fields)) => impl_struct(ast, fields),
+        Body::Enum(ref variants) => impl_enum(ast, variants),
+        _ => panic!("#[derive(ClapApp)] is only supported on structs with named fields and enums"),
+    }
+}
+
+fn impl_struct(ast: &DeriveInput, fields: &[Field]) -> Tokens {
+    let name = &ast.ident;
+    let app_name = app_name(&ast.attrs);
+    let methods = app_methods(&ast.attrs);
+    let augment = gen_augment(fields);
+    let constructor = gen_constructor(quote!(#name), fields);
+
+    quote! {
+        #[allow(dead_code)]
+        impl #name {
+            pub fn clap() -> ::clap::App<'static, 'static> {
+                let app = ::clap::App::new(#app_name) #methods;
+                Self::augment_clap(app)
+            }
+
+            #[doc(hidden)]
+            pub fn augment_clap<'a, 'b>(app: ::clap::App<'a, 'b>) -> ::clap::App<'a, 'b>
+                where 'b: 'a
+            {
+                #augment
+            }
+
+            #[allow(unused_variables)]
+            pub fn from_matches(matches: &::clap::ArgMatches) -> Self {
+                #constructor
+            }
+
+            pub fn from_args() -> Self {
+                Self::from_matches(&Self::clap().get_matches())
+            }
+        }
+    }
+}
+
+fn impl_enum(ast: &DeriveInput, variants: &[syn::Variant]) -> Tokens {
+    let name = &ast.ident;
+    let app_name = app_name(&ast.attrs);
+    let methods = app_methods(&ast.attrs);
+
+    let mut subcmds = vec![];
+    let mut arms = vec![];
+    for v in variants {
+        let vname = &v.ident;
+        let sc_name = attr_str(&v.attrs, "name").unwrap_or_else(|| to_kebab(vname.as_ref()));
+        let sc_methods = app_methods(&v.attrs);
+        match v.data {
+            VariantData::Unit => {
+                subcmds.push(quote!(::clap::SubCommand::with_name(#sc_name) #sc_methods));
+                arms.push(quote!((#sc_name, _) => Some(#name::#vname)));
+            }
+            VariantData::Struct(ref fields) => {
+                let augment = gen_augment(fields);
+                let constructor = gen_constructor(quote!(#name::#vname), fields);
+                subcmds.push(quote!({
+                    let app = ::clap::SubCommand::with_name(#sc_name) #sc_methods;
+                    #augment
+                }));
+                arms.push(quote!((#sc_name, Some(matches)) => Some(#constructor)));
+            }
+            VariantData::Tuple(ref fields) if fields.len() == 1 => {
+                let ty = &fields[0].ty;
+                subcmds.push(quote!({
+                    let app = ::clap::SubCommand::with_name(#sc_name) #sc_methods;
+                    <#ty>::augment_clap(app)
+                }));
+                arms.push(quote!((#sc_name, Some(matches)) => {
+                    Some(#name::#vname(<#ty>::from_matches(matches)))
+                }));
+            }
+            VariantData::Tuple(..) => {
+                panic!("#[derive(ClapApp)] only supports tuple variants with a single field")
+            }
+        }
+    }
+
+    quote! {
+        #[allow(dead_code)]
+        impl #name {
+            pub fn clap() -> ::clap::App<'static, 'static> {
+                let app = ::clap::App::new(#app_name)
+                    #methods
+                    .setting(::clap::AppSettings::SubcommandRequiredElseHelp);
+                Self::augment_subcommands(app)
+            }
+
+            #[doc(hidden)]
+            pub fn augment_subcommands<'a, 'b>(app: ::clap::App<'a, 'b>) -> ::clap::App<'a, 'b>
+                where 'b: 'a
+            {
+                app #( .subcommand(#subcmds) )*
+            }
+
+            pub fn from_matches(matches: &::clap::ArgMatches) -> Self {
+                Self::from_subcommand(matches.subcommand())
+                    .expect("a subcommand is required")
+            }
+
+            #[doc(hidden)]
+            pub fn from_subcommand(sub: (&str, Option<&::clap::ArgMatches>)) -> Option<Self> {
+                match sub {
+                    #( #arms, )*
+                    _ => None,
+                }
+            }
+
+            pub fn from_args() -> Self {
+                Self::from_matches(&Self::clap().get_matches())
+            }
+        }
+    }
+}
+
+// What kind of argument (or subcommand) a field turns into, based on its type and attributes
+enum Kind<'a> {
+    Flag,
+    Count(&'a Ty),
+    Optional(&'a Ty),
+    Multiple(&'a Ty),
+    Single(&'a Ty),
+    Subcommand(&'a Ty, bool),
+}
+
+fn field_kind(field: &Field) -> Kind {
+    let ty = &field.ty;
+    let attrs = clap_attrs(&field.attrs);
+    let has = |w: &str| attrs.iter().any(|&(ref k, _)| k == w);
+    if has("subcommand") {
+        match inner_ty(ty, "Option") {
+            Some(inner) => Kind::Subcommand(inner, true),
+            None => Kind::Subcommand(ty, false),
+        }
+    } else if has("count") {
+        Kind::Count(ty)
+    } else if is_ty(ty, "bool") {
+        Kind::Flag
+    } else if let Some(inner) = inner_ty(ty, "Option") {
+        Kind::Optional(inner)
+    } else if let Some(inner) = inner_ty(ty, "Vec") {
+        Kind::Multiple(inner)
+    } else {
+        Kind::Single(ty)
+    }
+}
+
+// Generates the statements which add all fields as arguments to an `app` binding, and evaluate
+// to the resulting App
+fn gen_augment(fields: &[Field]) -> Tokens {
+    let mut args = vec![];
+    let mut subcmds = quote!();
+    for field in fields {
+        let kind = field_kind(field);
+        let name = field_name(field);
+        let attrs = clap_attrs(&field.attrs);
+        let has = |w: &str| attrs.iter().any(|&(ref k, _)| k == w);
+
+        let mut methods = Tokens::new();
+        if let Some(help) = doc_comment(&field.attrs) {
+            methods.append(quote!(.help(#help)).as_str());
+        }
+        let is_switch = has("short") || has("long");
+        match kind {
+            Kind::Subcommand(ty, optional) => {
+                subcmds = if optional {
+                    quote!(let app = <#ty>::augment_subcommands(app);)
+                } else {
+                    quote! {
+                        let app = <#ty>::augment_subcommands(app)
+                            .setting(::clap::AppSettings::SubcommandRequiredElseHelp);
+                    }
+                };
+                continue;
+            }
+            Kind::Flag | Kind::Count(..) => {
+                if !is_switch {
+                    let long = to_kebab(field.ident.as_ref().unwrap().as_ref());
+                    methods.append(quote!(.long(#long)).as_str());
+                }
+                if let Kind::Count(..) = kind {
+                    methods.append(quote!(.multiple(true)).as_str());
+                }
+            }
+            Kind::Optional(ty) => {
+                methods.append(quote!(.takes_value(true)).as_str());
+                methods.append(validator(ty).as_str());
+            }
+            Kind::Multiple(ty) => {
+                methods.append(quote!(.takes_value(true).multiple(true)).as_str());
+                if is_switch {
+                    methods.append(quote!(.number_of_values(1)).as_str());
+                }
+                methods.append(validator(ty).as_str());
+            }
+            Kind::Single(ty) => {
+                let required = !has("default_value");
+                methods.append(quote!(.takes_value(true).required(#required)).as_str());
+                methods.append(validator(ty).as_str());
+            }
+        }
+        if has("arg_enum") {
+            let ty = match kind {
+                Kind::Optional(ty) | Kind::Multiple(ty) | Kind::Single(ty) => ty,
+                _ => panic!("#[clap(arg_enum)] requires a field which takes a value"),
+            };
+            methods.append(quote!(.possible_values(&<#ty>::variants())).as_str());
+        }
+        methods.append(builder_methods(&attrs, &["subcommand", "count", "arg_enum"]).as_str());
+        args.push(quote!(.arg(::clap::Arg::with_name(#name) #methods)));
+    }
+    quote! {
+        let app = app #( #args )*;
+        #subcmds
+        app
+    }
+}
+
+// Generates the expression which builds `path { .. }` from a `matches` binding
+fn gen_constructor(path: Tokens, fields: &[Field]) -> Tokens {
+    let mut inits = vec![];
+    for field in fields {
+        let ident = field.ident.as_ref().unwrap();
+        let name = field_name(field);
+        let value = match field_kind(field) {
+            Kind::Flag => quote!(matches.is_present(#name)),
+            Kind::Count(ty) => quote!(matches.occurrences_of(#name) as #ty),
+            Kind::Optional(ty) => {
+                let parse = parse_value(ty);
+                quote!(matches.value_of(#name).map(|s| #parse))
+            }
+            Kind::Multiple(ty) => {
+                let parse = parse_value(ty);
+                quote! {
+                    matches.values_of(#name)
+                        .map(|vals| vals.map(|s| #parse).collect())
+                        .unwrap_or_else(Vec::new)
+                }
+            }
+            Kind::Single(ty) => {
+                let parse = parse_value(ty);
+                quote!(matches.value_of(#name).map(|s| #parse).unwrap())
+            }
+            Kind::Subcommand(ty, true) => quote!(<#ty>::from_subcommand(matches.subcommand())),
+            Kind::Subcommand(ty, false) => {
+                quote!(<#ty>::from_subcommand(matches.subcommand()).unwrap())
+            }
+        };
+        inits.push(quote!(#ident: #value));
+    }
+    quote!(#path { #( #inits ),* })
+}
+
+// Values which aren't `String`s are validated with their `FromStr` implementation so that
+// invalid values are reported by clap like any other validation error
+fn validator(ty: &Ty) -> Tokens {
+    if is_ty(ty, "String") {
+        quote!()
+    } else {
+        quote! {
+            .validator(|s| s.parse::<#ty>().map(|_| ()).map_err(|e| e.to_string()))
+        }
+    }
+}
+
+fn parse_value(ty: &Ty) -> Tokens {
+    if is_ty(ty, "String") {
+        quote!(s.to_owned())
+    } else {
+        quote!(s.parse::<#ty>().ok().expect("value was already validated"))
+    }
+}
+
+fn app_name(attrs: &[Attribute]) -> Tokens {
+    match attr_str(attrs, "name") {
+        Some(name) => quote!(#name),
+        None => quote!(env!("CARGO_PKG_NAME")),
+    }
+}
+
+// Builder calls for a struct, enum or variant, with the doc comment used as `about` unless one
+// was given explicitly
+fn app_methods(attrs: &[Attribute]) -> Tokens {
+    let clap = clap_attrs(attrs);
+    let mut methods = Tokens::new();
+    if !clap.iter().any(|&(ref k, _)| k == "about") {
+        if let Some(about) = doc_comment(attrs) {
+            methods.append(quote!(.about(#about)).as_str());
+        }
+    }
+    methods.append(builder_methods(&clap, &["name"]).as_str());
+    methods
+}
+
+// Turns `key = "value"` into `.key("value")` and `word` into `.word(true)`
+fn builder_methods(attrs: &[(String, Option<Lit>)], skip: &[&str]) -> Tokens {
+    let mut methods = Tokens::new();
+    for &(ref key, ref val) in attrs.iter().filter(|&&(ref k, _)| !skip.contains(&&**k)) {
+        let method = Ident::new(&**key);
+        match *val {
+            Some(ref lit) => methods.append(quote!(.#method(#lit)).as_str()),
+            None => methods.append(quote!(.#method(true)).as_str()),
+        }
+    }
+    methods
+}
+
+// Collects the contents of all `#[clap(..)]` attributes as (key, value) pairs
+fn clap_attrs(attrs: &[Attribute]) -> Vec<(String, Option<Lit>)> {
+    let mut res = vec![];
+    for attr in attrs {
+        if let MetaItem::List(ref ident, ref items) = attr.value {
+            if ident != "clap" {
+                continue;
+            }
+            for item in items {
+                match *item {
+                    NestedMetaItem::MetaItem(MetaItem::Word(ref w)) => {
+                        res.push((w.to_string(), None))
+                    }
+                    NestedMetaItem::MetaItem(MetaItem::NameValue(ref k, ref v)) => {
+                        res.push((k.to_string(), Some(v.clone())))
+                    }
+                    _ => panic!("unsupported #[clap(..)] attribute, expected `key = value` or `word`"),
+                }
+            }
+        }
+    }
+    res
+}
+
+fn attr_str(attrs: &[Attribute], key: &str) -> Option<String> {
+    clap_attrs(attrs).into_iter().filter(|&(ref k, _)| k == key).filter_map(|(_, v)| match v {
+        Some(Lit::Str(s, _)) => Some(s),
+        _ => None,
+    }).next()
+}
+
+// Joins the lines of a doc comment (either `///` or `#[doc = ".."]`) into a single line
+fn doc_comment(attrs: &[Attribute]) -> Option<String> {
+    let lines = attrs.iter()
+        .filter_map(|a| match a.value {
+            MetaItem::NameValue(ref ident, Lit::Str(ref s, _)) if ident == "doc" => {
+                Some(s.trim_left_matches("///").trim_left_matches("//!").trim().to_owned())
+            }
+            _ => None,
+        })
+        .filter(|l| !l.is_empty())
+        .collect::<Vec<_>>();
+    if lines.is_empty() {
+        None
+    } else {
+        Some(lines.join(" "))
+    }
+}
+
+fn field_name(field: &Field) -> Lit {
+    Lit::Str(field.ident.as_ref().unwrap().to_string(), StrStyle::Cooked)
+}
+
+fn is_ty(ty: &Ty, name: &str) -> bool {
+    match *ty {
+        Ty::Path(None, ref path) => path.segments.last().map_or(false, |s| s.ident == name),
+        _ => false,
+    }
+}
+
+// Returns `T` for a type such as `Option<T>` when `name` is "Option"
+fn inner_ty<'a>(ty: &'a Ty, name: &str) -> Option<&'a Ty> {
+    if let Ty::Path(None, ref path) = *ty {
+        if let Some(seg) = path.segments.last() {
+            if seg.ident == name {
+                if let PathParameters::AngleBracketed(ref data) = seg.parameters {
+                    if data.types.len() == 1 {
+                        return Some(&data.types[0]);
+                    }
+                }
+            }
+        }
+    }
+    None
+}
+
+// "FetchAll" and "fetch_all" both become "fetch-all"
+fn to_kebab(s: &str) -> String {
+    let mut res = String::with_capacity(s.len());
+    for (i, c) in s.chars().enumerate() {
+        if c.is_uppercase() {
+            if i != 0 {
+                res.push('-');
+            }
+            res.extend(c.to_lowercase());
+        } else if c == '_' {
+            res.push('-');
+        } else {
+            res.push(c);
+        }
+    }
+    res
+}
@@ -0,0 +1,184 @@
+#[macro_use]
+extern crate clap;
+#[macro_use]
+extern crate clap_derive;
+
+use clap::ErrorKind;
+
+arg_enum! {
+    #[derive(Debug, PartialEq)]
+    pub enum Format {
+        Json,
+        Text
+    }
+}
+
+/// Does awesome things
+#[derive(ClapApp, Debug, PartialEq)]
+#[clap(name = "myapp", version = "1.0")]
+struct MyApp {
+    /// Turns on verbose output
+    #[clap(short = "v", count)]
+    verbose: u64,
+    /// Doesn't actually do anything
+    dry_run: bool,
+    /// The output format
+    #[clap(long = "format", arg_enum, default_value = "Text")]
+    format: Format,
+    /// How many times to retry
+    #[clap(short = "r", long = "retries")]
+    retries: Option<u32>,
+    /// Extra libraries to link
+    #[clap(short = "l")]
+    libs: Vec<String>,
+    /// The file to read
+    input: String,
+}
+
+#[derive(ClapApp, Debug, PartialEq)]
+#[clap(name = "git")]
+struct Git {
+    #[clap(short = "q")]
+    quiet: bool,
+    #[clap(subcommand)]
+    cmd: Command,
+}
+
+#[derive(ClapApp, Debug, PartialEq)]
+#[clap(name = "git")]
+struct OptionalGit {
+    #[clap(subcommand)]
+    cmd: Option<Command>,
+}
+
+#[derive(ClapApp, Debug, PartialEq)]
+struct Add {
+    /// Files to add
+    files: Vec<String>,
+}
+
+#[derive(ClapApp, Debug, PartialEq)]
+#[clap(name = "git")]
+enum Command {
+    /// Fetches the latest changes
+    Fetch {
+        #[clap(long = "dry-run")]
+        dry_run: bool,
+        remote: Option<String>,
+    },
+    /// Adds files to the index
+    Add(Add),
+    #[clap(name = "st")]
+    Status,
+    FetchAll,
+}
+
+#[test]
+fn derive_all_kinds() {
+    let m = MyApp::clap()
+        .get_matches_from_safe(vec!["myapp", "-vv", "--dry-run", "--format", "Json", "-r", "3",
+                                    "-l", "ssl", "-l", "z", "file.txt"])
+        .unwrap();
+    assert_eq!(MyApp::from_matches(&m),
+               MyApp {
+                   verbose: 2,
+                   dry_run: true,
+                   format: Format::Json,
+                   retries: Some(3),
+                   libs: vec!["ssl".to_owned(), "z".to_owned()],
+                   input: "file.txt".to_owned(),
+               });
+}
+
+#[test]
+fn derive_defaults() {
+    let m = MyApp::clap().get_matches_from_safe(vec!["myapp", "file.txt"]).unwrap();
+    assert_eq!(MyApp::from_matches(&m),
+               MyApp {
+                   verbose: 0,
+                   dry_run: false,
+                   format: Format::Text,
+                   retries: None,
+                   libs: vec![],
+                   input: "file.txt".to_owned(),
+               });
+}
+
+#[test]
+fn derive_required_positional() {
+    let r = MyApp::clap().get_matches_from_safe(vec!["myapp"]);
+    assert!(r.is_err());
+    assert_eq!(r.unwrap_err().kind, ErrorKind::MissingRequiredArgument);
+}
+
+#[test]
+fn derive_invalid_parse() {
+    let r = MyApp::clap().get_matches_from_safe(vec!["myapp", "-r", "lots", "file.txt"]);
+    assert!(r.is_err());
+    assert_eq!(r.unwrap_err().kind, ErrorKind::ValueValidation);
+}
+
+#[test]
+fn derive_arg_enum_possible_values() {
+    let r = MyApp::clap().get_matches_from_safe(vec!["myapp", "--format", "Yaml", "file.txt"]);
+    assert!(r.is_err());
+    assert_eq!(r.unwrap_err().kind, ErrorKind::InvalidValue);
+}
+
+#[test]
+fn derive_doc_comments_help() {
+    let app = MyApp::clap();
+    let mut help = vec![];
+    app.write_help(&mut help).unwrap();
+    let help = String::from_utf8(help).unwrap();
+    assert!(help.starts_with("myapp 1.0\nDoes awesome things"));
+    assert!(help.contains("Turns on verbose output"));
+    assert!(help.contains("--dry-run"));
+    assert!(help.contains("Doesn't actually do anything"));
+    assert!(help.contains("<input>"));
+}
+
+#[test]
+fn derive_subcommand_struct_variant() {
+    let m = Git::clap()
+        .get_matches_from_safe(vec!["git", "-q", "fetch", "--dry-run", "origin"])
+        .unwrap();
+    assert_eq!(Git::from_matches(&m),
+               Git {
+                   quiet: true,
+                   cmd: Command::Fetch {
+                       dry_run: true,
+                       remote: Some("origin".to_owned()),
+                   },
+               });
+}
+
+#[test]
+fn derive_subcommand_tuple_variant() {
+    let m = Git::clap().get_matches_from_safe(vec!["git", "add", "a.rs", "b.rs"]).unwrap();
+    assert_eq!(Git::from_matches(&m).cmd,
+               Command::Add(Add { files: vec!["a.rs".to_owned(), "b.rs".to_owned()] }));
+}
+
+#[test]
+fn derive_subcommand_names() {
+    let m = Command::clap().get_matches_from_safe(vec!["git", "st"]).unwrap();
+    assert_eq!(Command::from_matches(&m), Command::Status);
+    let m = Command::clap().get_matches_from_safe(vec!["git", "fetch-all"]).unwrap();
+    assert_eq!(Command::from_matches(&m), Command::FetchAll);
+}
+
+#[test]
+fn derive_subcommand_required() {
+    let r = Git::clap().get_matches_from_safe(vec!["git"]);
+    assert!(r.is_err());
+    assert_eq!(r.unwrap_err().kind, ErrorKind::MissingArgumentOrSubcommand);
+}
+
+#[test]
+fn derive_subcommand_optional() {
+    let m = OptionalGit::clap().get_matches_from_safe(vec!["git"]).unwrap();
+    assert_eq!(OptionalGit::from_matches(&m), OptionalGit { cmd: None });
+    let m = OptionalGit::clap().get_matches_from_safe(vec!["git", "st"]).unwrap();
+    assert_eq!(OptionalGit::from_matches(&m), OptionalGit { cmd: Some(Command::Status) });
+}
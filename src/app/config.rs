@@ -0,0 +1,170 @@
+// Std
+use std::collections::BTreeMap;
+use std::fs::File;
+use std::io::{self, Read};
+use std::path::Path;
+
+// Third Party
+use ini::Ini;
+use toml;
+use yaml_rust::{Yaml, YamlLoader};
+
+// Internal
+use errors::{Error, Result as ClapResult};
+use fmt::ColorWhen;
+
+// The keys of a config file (or of one of its subcommand tables), with every value as a string
+// so it goes through the same parsing and validation as the command line
+#[doc(hidden)]
+#[derive(Debug, Clone, Default)]
+pub struct ConfigTable {
+    values: BTreeMap<String, Vec<String>>,
+    tables: BTreeMap<String, ConfigTable>,
+}
+
+impl ConfigTable {
+    pub fn values(&self) -> &BTreeMap<String, Vec<String>> {
+        &self.values
+    }
+
+    pub fn table(&self, name: &str) -> Option<&ConfigTable> {
+        self.tables.get(name)
+    }
+
+    // Layers `other` on top of this table, its keys replacing ours and its tables merged with
+    // ours
+    pub fn merge(&mut self, other: ConfigTable) {
+        self.values.extend(other.values);
+        for (name, table) in other.tables {
+            self.tables.entry(name).or_insert_with(ConfigTable::default).merge(table);
+        }
+    }
+
+    fn table_mut(&mut self, path: &str) -> &mut ConfigTable {
+        path.split('.')
+            .fold(self, |t, name| t.tables.entry(name.to_owned()).or_insert_with(ConfigTable::default))
+    }
+}
+
+// Reads the config file at `path`, returning `None` if it doesn't exist
+pub fn read(path: &Path, color: ColorWhen) -> ClapResult<Option<ConfigTable>> {
+    debugln!("fn=config::read; path={:?}", path);
+    let mut contents = String::new();
+    match File::open(path).and_then(|mut f| f.read_to_string(&mut contents)) {
+        Ok(_) => (),
+        Err(ref e) if e.kind() == io::ErrorKind::NotFound => {
+            debugln!("Config file doesn't exist, skipping");
+            return Ok(None);
+        }
+        Err(e) => return Err(Error::config_file(path.display().to_string(), e, color)),
+    }
+    let ext = path.extension().and_then(|e| e.to_str()).unwrap_or("");
+    let table = match &*ext.to_lowercase() {
+        "toml" => from_toml(&contents),
+        "ini" | "cfg" | "conf" => from_ini(&contents),
+        "yml" | "yaml" => from_yaml(&contents),
+        _ => Err(format!("unknown config file format '{}', expected toml, ini or yaml", ext)),
+    };
+    table.map(Some).map_err(|e| Error::config_file(path.display().to_string(), e, color))
+}
+
+fn from_toml(contents: &str) -> Result<ConfigTable, String> {
+    let mut parser = toml::Parser::new(contents);
+    match parser.parse() {
+        Some(t) => toml_table(t),
+        None => {
+            let e = &parser.errors[0];
+            let (line, col) = parser.to_linecol(e.lo);
+            Err(format!("{} at line {} column {}", e.desc, line + 1, col + 1))
+        }
+    }
+}
+
+fn toml_table(t: toml::Table) -> Result<ConfigTable, String> {
+    let mut table = ConfigTable::default();
+    for (key, val) in t {
+        match val {
+            toml::Value::Table(t) => {
+                table.tables.insert(key, try!(toml_table(t)));
+            }
+            toml::Value::Array(vals) => {
+                let vals = try!(vals.into_iter().map(|v| toml_scalar(&key, v)).collect());
+                table.values.insert(key, vals);
+            }
+            v => {
+                let v = try!(toml_scalar(&key, v));
+                table.values.insert(key, vec![v]);
+            }
+        }
+    }
+    Ok(table)
+}
+
+fn toml_scalar(key: &str, val: toml::Value) -> Result<String, String> {
+    match val {
+        toml::Value::String(s) | toml::Value::Datetime(s) => Ok(s),
+        toml::Value::Integer(i) => Ok(i.to_string()),
+        toml::Value::Float(f) => Ok(f.to_string()),
+        toml::Value::Boolean(b) => Ok(b.to_string()),
+        _ => Err(format!("the value of '{}' isn't a string, number or boolean", key)),
+    }
+}
+
+fn from_ini(contents: &str) -> Result<ConfigTable, String> {
+    let ini = try!(Ini::load_from_str(contents)
+        .map_err(|e| format!("{} at line {} column {}", e.msg, e.line + 1, e.col + 1)));
+    let mut table = ConfigTable::default();
+    for (section, props) in ini.iter() {
+        // Sections, with dots between nested subcommands, are subcommand tables
+        let t = match *section {
+            Some(ref s) => table.table_mut(s),
+            None => &mut table,
+        };
+        for (key, val) in props {
+            t.values.insert(key.clone(), vec![val.clone()]);
+        }
+    }
+    Ok(table)
+}
+
+fn from_yaml(contents: &str) -> Result<ConfigTable, String> {
+    let docs = try!(YamlLoader::load_from_str(contents).map_err(|e| e.to_string()));
+    match docs.into_iter().next() {
+        Some(Yaml::Hash(h)) => yaml_table(h),
+        None | Some(Yaml::Null) => Ok(ConfigTable::default()),
+        Some(_) => Err("expected a mapping of argument names to values".to_owned()),
+    }
+}
+
+fn yaml_table(h: ::yaml_rust::yaml::Hash) -> Result<ConfigTable, String> {
+    let mut table = ConfigTable::default();
+    for (key, val) in h {
+        let key = match key {
+            Yaml::String(s) => s,
+            k => return Err(format!("expected a string key, found {:?}", k)),
+        };
+        match val {
+            Yaml::Hash(h) => {
+                table.tables.insert(key, try!(yaml_table(h)));
+            }
+            Yaml::Array(vals) => {
+                let vals = try!(vals.into_iter().map(|v| yaml_scalar(&key, v)).collect());
+                table.values.insert(key, vals);
+            }
+            v => {
+                let v = try!(yaml_scalar(&key, v));
+                table.values.insert(key, vec![v]);
+            }
+        }
+    }
+    Ok(table)
+}
+
+fn yaml_scalar(key: &str, val: Yaml) -> Result<String, String> {
+    match val {
+        Yaml::String(s) | Yaml::Real(s) => Ok(s),
+        Yaml::Integer(i) => Ok(i.to_string()),
+        Yaml::Boolean(b) => Ok(b.to_string()),
+        _ => Err(format!("the value of '{}' isn't a string, number or boolean", key)),
+    }
+}
@@ -51,6 +51,21 @@ fn as_arg_trait<'a, 'b, T: ArgWithOrder<'a, 'b>>(x: &T) -> &ArgWithOrder<'a, 'b>
     x
 }
 
+// The width of an arg as written by `Help::long`, which displays negatable flags as `--[no-]flag`
+fn arg_width<'b, 'c>(arg: &ArgWithDisplay<'b, 'c>) -> usize {
+    let w = arg.to_string().len();
+    if arg.is_set(ArgSettings::Negatable) {
+        w + "[no-]".len()
+    } else {
+        w
+    }
+}
+
+// Whether an option may be used without its value, e.g. `--color[=<WHEN>]`
+fn value_optional<'b, 'c>(arg: &ArgWithDisplay<'b, 'c>) -> bool {
+    arg.default_missing_val().is_some() || arg.min_vals() == Some(0)
+}
+
 impl<'b, 'c> DispOrder for App<'b, 'c> {
     fn disp_ord(&self) -> usize {
         999
@@ -86,6 +101,7 @@ pub struct Help<'a> {
     cizer: Colorizer,
     longest: usize,
     force_next_line: bool,
+    use_long: bool,
 }
 
 // Public Functions
@@ -97,7 +113,8 @@ impl<'a> Help<'a> {
                color: bool,
                cizer: Colorizer,
                term_w: Option<usize>,
-               max_w: Option<usize>)
+               max_w: Option<usize>,
+               use_long: bool)
                -> Self {
         debugln!("fn=Help::new;");
         Help {
@@ -118,21 +135,26 @@ impl<'a> Help<'a> {
             cizer: cizer,
             longest: 0,
             force_next_line: false,
+            use_long: use_long,
         }
     }
 
     /// Reads help settings from an App
     /// and write its help to the wrapped stream.
-    pub fn write_app_help(w: &'a mut Write, app: &App) -> ClapResult<()> {
+    ///
+    /// The long help messages are used if `use_long` is true (i.e. for `--help`).
+    pub fn write_app_help(w: &'a mut Write, app: &App, use_long: bool) -> ClapResult<()> {
         debugln!("fn=Help::write_app_help;");
-        Self::write_parser_help(w, &app.p)
+        Self::write_parser_help(w, &app.p, use_long)
     }
 
     /// Reads help settings from a Parser
     /// and write its help to the wrapped stream.
-    pub fn write_parser_help(w: &'a mut Write, parser: &Parser) -> ClapResult<()> {
+    ///
+    /// The long help messages are used if `use_long` is true (i.e. for `--help`).
+    pub fn write_parser_help(w: &'a mut Write, parser: &Parser, use_long: bool) -> ClapResult<()> {
         debugln!("fn=Help::write_parser_help;");
-        Self::_write_parser_help(w, parser, false)
+        Self::_write_parser_help(w, parser, false, use_long)
     }
 
     /// Reads help settings from a Parser
@@ -140,11 +162,15 @@ impl<'a> Help<'a> {
     /// formatting when required.
     pub fn write_parser_help_to_stderr(w: &'a mut Write, parser: &Parser) -> ClapResult<()> {
         debugln!("fn=Help::write_parser_help;");
-        Self::_write_parser_help(w, parser, true)
+        Self::_write_parser_help(w, parser, true, false)
     }
 
     #[doc(hidden)]
-    pub fn _write_parser_help(w: &'a mut Write, parser: &Parser, stderr: bool) -> ClapResult<()> {
+    pub fn _write_parser_help(w: &'a mut Write,
+                              parser: &Parser,
+                              stderr: bool,
+                              use_long: bool)
+                              -> ClapResult<()> {
         debugln!("fn=Help::write_parser_help;");
         let nlh = parser.is_set(AppSettings::NextLineHelp);
         let hide_v = parser.is_set(AppSettings::HidePossibleValuesInHelp);
@@ -159,7 +185,8 @@ impl<'a> Help<'a> {
                   color,
                   cizer,
                   parser.meta.term_w,
-                  parser.meta.max_w)
+                  parser.meta.max_w,
+                  use_long)
             .write_help(parser)
     }
 
@@ -190,7 +217,7 @@ impl<'a> Help<'a> {
             !(arg.is_set(ArgSettings::Hidden)) || arg.is_set(ArgSettings::NextLineHelp)
         }) {
             if arg.longest_filter() {
-                self.longest = cmp::max(self.longest, arg.to_string().len());
+                self.longest = cmp::max(self.longest, arg_width(arg.as_base()));
             }
             if !arg.is_set(ArgSettings::Hidden) {
                 arg_v.push(arg)
@@ -225,7 +252,7 @@ impl<'a> Help<'a> {
         }) {
             if arg.longest_filter() {
                 debugln!("Longest...{}", self.longest);
-                self.longest = cmp::max(self.longest, arg.to_string().len());
+                self.longest = cmp::max(self.longest, arg_width(arg.as_base()));
                 debugln!("New Longest...{}", self.longest);
             }
             let btm = ord_m.entry(arg.disp_ord()).or_insert(BTreeMap::new());
@@ -281,12 +308,24 @@ impl<'a> Help<'a> {
                 }
                 try!(color!(self, "--{}", l, good))
             }
-            try!(write!(self.writer, " "));
+            if arg.is_set(ArgSettings::RequireEquals) {
+                if value_optional(arg) {
+                    try!(color!(self, "[=", good));
+                } else {
+                    try!(color!(self, "=", good));
+                }
+            } else {
+                try!(write!(self.writer, " "));
+            }
         } else if let Some(l) = arg.long() {
             if arg.short().is_some() {
                 try!(write!(self.writer, ", "));
             }
-            try!(color!(self, "--{}", l, good));
+            if arg.is_set(ArgSettings::Negatable) {
+                try!(color!(self, "--[no-]{}", l, good));
+            } else {
+                try!(color!(self, "--{}", l, good));
+            }
         }
         Ok(())
     }
@@ -295,6 +334,7 @@ impl<'a> Help<'a> {
     fn val<'b, 'c>(&mut self, arg: &ArgWithDisplay<'b, 'c>) -> Result<String, io::Error> {
         debugln!("fn=val;arg={}", arg);
         if arg.takes_value() {
+            let mut wrote_term = false;
             if let Some(vec) = arg.val_names() {
                 let mut it = vec.iter().peekable();
                 while let Some((_, val)) = it.next() {
@@ -324,12 +364,22 @@ impl<'a> Help<'a> {
                     try!(color!(self, "...", good));
                 }
             } else {
+                // The Display impl already writes any value terminator
                 try!(color!(self, "{}", arg, good));
+                wrote_term = true;
+            }
+            if arg.has_switch() && arg.is_set(ArgSettings::RequireEquals) && value_optional(arg) {
+                try!(color!(self, "]", good));
+            }
+            if let Some(t) = arg.val_terminator() {
+                if !wrote_term {
+                    try!(color!(self, " {}", t, good));
+                }
             }
         }
 
         let spec_vals = self.spec_vals(arg);
-        let h = arg.help().unwrap_or("");
+        let h = self.arg_help(arg);
         let h_w = str_width(h) + str_width(&*spec_vals);
         let nlh = self.next_line_help || arg.is_set(ArgSettings::NextLineHelp);
         let taken = self.longest + 12;
@@ -351,7 +401,7 @@ impl<'a> Help<'a> {
             debug!("next_line...");
             if !(nlh || self.force_next_line) {
                 sdebugln!("No");
-                let self_len = arg.to_string().len();
+                let self_len = arg_width(arg);
                 // subtract ourself
                 let mut spcs = self.longest - self_len;
                 // Since we're writing spaces from the tab point we first need to know if we
@@ -429,7 +479,7 @@ impl<'a> Help<'a> {
     fn help<'b, 'c>(&mut self, arg: &ArgWithDisplay<'b, 'c>, spec_vals: &str) -> io::Result<()> {
         debugln!("fn=help;");
         let mut help = String::new();
-        let h = arg.help().unwrap_or("");
+        let h = self.arg_help(arg);
         let nlh = self.next_line_help || arg.is_set(ArgSettings::NextLineHelp);
         debugln!("Next Line...{:?}", nlh);
 
@@ -500,9 +550,46 @@ impl<'a> Help<'a> {
         Ok(())
     }
 
+    /// Gets the help message of an argument, preferring the long message when writing the long
+    /// help and the short message otherwise.
+    fn arg_help<'b, 'c>(&self, arg: &ArgWithDisplay<'b, 'c>) -> &'c str {
+        if self.use_long {
+            arg.long_help().or_else(|| arg.help())
+        } else {
+            arg.help().or_else(|| arg.long_help())
+        }
+        .unwrap_or("")
+    }
+
+    /// Gets the about message of a Parser, preferring the long message when writing the long help
+    /// and the short message otherwise.
+    fn about<'b, 'c>(&self, parser: &Parser<'b, 'c>) -> Option<&'c str> {
+        if self.use_long {
+            parser.meta.long_about.or(parser.meta.about)
+        } else {
+            parser.meta.about.or(parser.meta.long_about)
+        }
+    }
+
     fn spec_vals(&self, a: &ArgWithDisplay) -> String {
         debugln!("fn=spec_vals;a={}", a);
         let mut spec_vals = vec![];
+        if let Some(env) = a.env() {
+            debugln!("Found environment variable...[{:?}:{:?}]", env.0, env.1);
+            if a.is_set(ArgSettings::HideEnvValues) {
+                spec_vals.push(format!(" [env: {}]", env.0.to_string_lossy()));
+            } else {
+                let env_val = env.1
+                    .map_or(String::new(), |val| val.to_string_lossy().into_owned());
+                spec_vals.push(format!(" [env: {}={}]",
+                                       env.0.to_string_lossy(),
+                                       if self.color {
+                                           self.cizer.good(env_val)
+                                       } else {
+                                           Format::None(env_val)
+                                       }));
+            }
+        }
         if let Some(pv) = a.default_val() {
             debugln!("Found default value...[{}]", pv);
             spec_vals.push(format!(" [default: {}]",
@@ -512,6 +599,19 @@ impl<'a> Help<'a> {
                                        Format::None(pv)
                                    }));
         }
+        if let Some(ifs) = a.default_vals_ifs() {
+            for &(arg, val, def) in ifs {
+                debugln!("Found conditional default value...[{}]", def);
+                let cond = val.map_or(arg.to_owned(), |v| format!("{}={}", arg, v));
+                spec_vals.push(format!(" [default: {} if {}]",
+                                       if self.color {
+                                           self.cizer.good(def)
+                                       } else {
+                                           Format::None(def)
+                                       },
+                                       cond));
+            }
+        }
         if let Some(ref aliases) = a.aliases() {
             debugln!("Found aliases...{:?}", aliases);
             spec_vals.push(format!(" [aliases: {}]",
@@ -666,7 +766,7 @@ impl<'a> Help<'a> {
         if let Some(author) = parser.meta.author {
             try!(write!(self.writer, "{}\n", author));
         }
-        if let Some(about) = parser.meta.about {
+        if let Some(about) = self.about(parser) {
             try!(write!(self.writer, "{}\n", about));
         }
 
@@ -866,7 +966,7 @@ impl<'a> Help<'a> {
                 b"about" => {
                     try!(write!(self.writer,
                                 "{}",
-                                parser.meta.about.unwrap_or("unknown about")));
+                                self.about(parser).unwrap_or("unknown about")));
                 }
                 b"usage" => {
                     try!(write!(self.writer, "{}", parser.create_usage_no_title(&[])));
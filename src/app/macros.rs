@@ -130,7 +130,12 @@ macro_rules! _handle_group_reqs{
 macro_rules! validate_multiples {
     ($_self:ident, $a:ident, $m:ident) => {
         debugln!("macro=validate_multiples!;");
-        if $m.contains(&$a.name) && !$a.settings.is_set(ArgSettings::Multiple) {
+        // Apart from help and version, arguments with an action may be used more than once
+        let repeatable = match $a.action() {
+            None | Some(&ArgAction::Help) | Some(&ArgAction::Version) => false,
+            Some(_) => true,
+        };
+        if $m.contains(&$a.name) && !$a.settings.is_set(ArgSettings::Multiple) && !repeatable {
             // Not the first time, and we don't allow multiples
             return Err(Error::unexpected_multiple_usage($a,
                 &*$_self.create_current_usage($m),
@@ -155,7 +160,7 @@ macro_rules! parse_positional {
             $pos_counter == $_self.positionals.len()) {
             $_self.trailing_vals = true;
         }
-        if let Err(e) = $_self.add_val_to_arg($p, &$arg_os, $matcher) {
+        if let Err(e) = $_self.add_val_to_arg($p, &$arg_os, $matcher, true) {
             return Err(e);
         }
 
@@ -1,3 +1,12 @@
+// Std
+use std::cell::RefCell;
+#[cfg(feature = "config")]
+use std::path::PathBuf;
+use std::rc::Rc;
+
+// Internal
+use app::prompt::PromptStreams;
+
 #[doc(hidden)]
 #[allow(missing_debug_implementations)]
 pub struct AppMeta<'b> {
@@ -6,6 +15,7 @@ pub struct AppMeta<'b> {
     pub author: Option<&'b str>,
     pub version: Option<&'b str>,
     pub about: Option<&'b str>,
+    pub long_about: Option<&'b str>,
     pub more_help: Option<&'b str>,
     pub pre_help: Option<&'b str>,
     pub aliases: Option<Vec<(&'b str, bool)>>, // (name, visible)
@@ -16,6 +26,9 @@ pub struct AppMeta<'b> {
     pub term_w: Option<usize>,
     pub max_w: Option<usize>,
     pub template: Option<&'b str>,
+    pub prompt_streams: Option<Rc<RefCell<PromptStreams>>>,
+    #[cfg(feature = "config")]
+    pub config_files: Vec<PathBuf>,
 }
 
 impl<'b> Default for AppMeta<'b> {
@@ -24,6 +37,7 @@ impl<'b> Default for AppMeta<'b> {
             name: String::new(),
             author: None,
             about: None,
+            long_about: None,
             more_help: None,
             pre_help: None,
             version: None,
@@ -36,6 +50,9 @@ impl<'b> Default for AppMeta<'b> {
             aliases: None,
             term_w: None,
             max_w: None,
+            prompt_streams: None,
+            #[cfg(feature = "config")]
+            config_files: vec![],
         }
     }
 }
@@ -55,6 +72,7 @@ impl<'b> Clone for AppMeta<'b> {
             name: self.name.clone(),
             author: self.author,
             about: self.about,
+            long_about: self.long_about,
             more_help: self.more_help,
             pre_help: self.pre_help,
             version: self.version,
@@ -67,6 +85,9 @@ impl<'b> Clone for AppMeta<'b> {
             aliases: self.aliases.clone(),
             term_w: self.term_w,
             max_w: self.max_w,
+            prompt_streams: self.prompt_streams.clone(),
+            #[cfg(feature = "config")]
+            config_files: self.config_files.clone(),
         }
     }
 }
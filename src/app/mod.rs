@@ -4,27 +4,43 @@ mod macros;
 pub mod parser;
 mod meta;
 mod help;
+mod response_files;
+mod prompt;
+#[cfg(feature = "config")]
+mod config;
+#[cfg(feature = "serde")]
+mod serde_owned;
 
 // Std
 use std::borrow::Borrow;
+use std::cell::RefCell;
 use std::env;
-use std::ffi::OsString;
+use std::any::Any;
+use std::ffi::{OsStr, OsString};
 use std::fmt;
 use std::io::{self, BufRead, BufWriter, Write};
 use std::path::Path;
+#[cfg(feature = "config")]
+use std::path::PathBuf;
 use std::process;
 use std::rc::Rc;
+use std::sync::Arc;
 use std::result::Result as StdResult;
 
 // Third Party
-use vec_map::VecMap;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Deserializer};
+use vec_map::{self, VecMap};
 #[cfg(feature = "yaml")]
 use yaml_rust::Yaml;
 
 // Internal
 use app::help::Help;
+use app::prompt::PromptStreams;
 use app::parser::Parser;
-use args::{AnyArg, Arg, ArgGroup, ArgMatcher, ArgMatches, ArgSettings};
+#[cfg(feature = "serde")]
+use app::serde_owned::OwnedArgMatches;
+use args::{AnyArg, Arg, ArgAction, ArgGroup, ArgMatcher, ArgMatches, ArgSettings};
 use errors::Error;
 use errors::Result as ClapResult;
 pub use self::settings::AppSettings;
@@ -223,6 +239,28 @@ impl<'a, 'b> App<'a, 'b> {
         self
     }
 
+    /// Sets a long string describing what the program does. This will be displayed when the user
+    /// requests the help information with `--help` (or the `help` subcommand), while the message
+    /// set with [`App::about`] is displayed for `-h`. If only one of the two is set, it is used
+    /// for both.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use clap::{App, Arg};
+    /// App::new("myprog")
+    ///     .long_about(
+    /// "Does really amazing things to great people. Now let's talk a little
+    ///  more in depth about how this subcommand really works. It may take about
+    ///  a few lines of text, but that's ok!")
+    /// # ;
+    /// ```
+    /// [`App::about`]: ./struct.App.html#method.about
+    pub fn long_about<S: Into<&'b str>>(mut self, about: S) -> Self {
+        self.p.meta.long_about = Some(about.into());
+        self
+    }
+
     /// Adds additional help information to be displayed in addition to auto-generated help. This
     /// information is displayed **after** the auto-generated help information. This is often used
     /// to describe how to use the arguments, or caveats to be noted.
@@ -622,6 +660,100 @@ impl<'a, 'b> App<'a, 'b> {
         self
     }
 
+    /// Sets the streams used to [prompt] for the values of missing required arguments, instead
+    /// of `stdin` and `stderr`. Prompting with these streams doesn't depend on `stdin` being a
+    /// terminal, which makes it possible to script or test the prompts.
+    ///
+    /// **NOTE:** The streams are shared with all subcommands which don't set their own.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// # use std::io::{self, Cursor};
+    /// let m = App::new("prog")
+    ///     .prompt_streams(Cursor::new("prod\n"), io::sink())
+    ///     .arg(Arg::with_name("cluster")
+    ///         .long("cluster")
+    ///         .required(true)
+    ///         .prompt("Cluster name"))
+    ///     .get_matches_from(vec!["prog"]);
+    ///
+    /// assert_eq!(m.value_of("cluster"), Some("prod"));
+    /// ```
+    /// [prompt]: ./struct.Arg.html#method.prompt
+    pub fn prompt_streams<R, W>(mut self, input: R, output: W) -> Self
+        where R: BufRead + 'static,
+              W: Write + 'static
+    {
+        self.p.meta.prompt_streams = Some(Rc::new(RefCell::new(PromptStreams {
+            input: Box::new(input),
+            output: Box::new(output),
+        })));
+        self
+    }
+
+    /// Adds a config file to read argument values from, when they weren't given on the command
+    /// line or by an [environment variable]. Each key names an [`Arg`], and its value (or array
+    /// of values) is used exactly as if it had been given on the command line, i.e. it's checked
+    /// against the [possible values], [validator] and [value parser] of the argument. Flags take
+    /// `true`, `false` or how many times they occur. A table (or INI section, with dots between
+    /// nested subcommands) named after a subcommand holds the values for that subcommand.
+    ///
+    /// Config files are layered in the order they're added, so add the system-wide file first,
+    /// then the user's and then the project's, to let the later files override the earlier ones.
+    /// Files which don't exist are skipped, and keys which don't name an argument are ignored.
+    ///
+    /// The format is picked from the file's extension: `.toml`, `.ini`, `.cfg` or `.conf` (INI),
+    /// and `.yml` or `.yaml`.
+    ///
+    /// **NOTE:** This requires the `config` Cargo feature.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use clap::{App, Arg};
+    /// let m = App::new("prog")
+    ///     .config_file("/etc/prog.toml")
+    ///     .config_file("/home/user/.config/prog.toml")
+    ///     .config_file("prog.toml")
+    ///     .arg(Arg::with_name("cluster")
+    ///         .long("cluster")
+    ///         .takes_value(true))
+    ///     .get_matches();
+    /// ```
+    /// [environment variable]: ./struct.Arg.html#method.env
+    /// [`Arg`]: ./struct.Arg.html
+    /// [possible values]: ./struct.Arg.html#method.possible_values
+    /// [validator]: ./struct.Arg.html#method.validator
+    /// [value parser]: ./struct.Arg.html#method.value_parser
+    #[cfg(feature = "config")]
+    pub fn config_file<P: Into<PathBuf>>(mut self, path: P) -> Self {
+        self.p.meta.config_files.push(path.into());
+        self
+    }
+
+    /// Adds several config files at once, see [`App::config_file`].
+    ///
+    /// **NOTE:** This requires the `config` Cargo feature.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use clap::App;
+    /// App::new("prog")
+    ///     .config_files(&["/etc/prog.toml", "prog.toml"])
+    /// # ;
+    /// ```
+    /// [`App::config_file`]: ./struct.App.html#method.config_file
+    #[cfg(feature = "config")]
+    pub fn config_files<P: AsRef<Path>>(mut self, paths: &[P]) -> Self {
+        for p in paths {
+            self.p.meta.config_files.push(p.as_ref().to_path_buf());
+        }
+        self
+    }
+
     /// Adds an [argument] to the list of valid possibilties.
     ///
     /// # Examples
@@ -1031,7 +1163,49 @@ impl<'a, 'b> App<'a, 'b> {
     /// ```
     /// [`io::Write`]: https://doc.rust-lang.org/std/io/trait.Write.html
     pub fn write_help<W: Write>(&self, w: &mut W) -> ClapResult<()> {
-        Help::write_app_help(w, self)
+        Help::write_app_help(w, self, false)
+    }
+
+    /// Prints the full help message to [`io::stdout()`] using a [`BufWriter`], using the long
+    /// help messages set with [`Arg::long_help`] and [`App::long_about`] (i.e. the message
+    /// displayed for `--help`)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::App;
+    /// let mut app = App::new("myprog");
+    /// app.print_long_help();
+    /// ```
+    /// [`io::stdout()`]: https://doc.rust-lang.org/std/io/fn.stdout.html
+    /// [`BufWriter`]: https://doc.rust-lang.org/std/io/struct.BufWriter.html
+    /// [`Arg::long_help`]: ./struct.Arg.html#method.long_help
+    /// [`App::long_about`]: ./struct.App.html#method.long_about
+    pub fn print_long_help(&mut self) -> ClapResult<()> {
+        self.p.create_help_and_version();
+        let out = io::stdout();
+        let mut buf_w = BufWriter::new(out.lock());
+        self.write_long_help(&mut buf_w)
+    }
+
+    /// Writes the full help message to the user to a [`io::Write`] object, using the long help
+    /// messages set with [`Arg::long_help`] and [`App::long_about`] (i.e. the message displayed
+    /// for `--help`)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::App;
+    /// use std::io;
+    /// let mut app = App::new("myprog");
+    /// let mut out = io::stdout();
+    /// app.write_long_help(&mut out).expect("failed to write to stdout");
+    /// ```
+    /// [`io::Write`]: https://doc.rust-lang.org/std/io/trait.Write.html
+    /// [`Arg::long_help`]: ./struct.Arg.html#method.long_help
+    /// [`App::long_about`]: ./struct.App.html#method.long_about
+    pub fn write_long_help<W: Write>(&self, w: &mut W) -> ClapResult<()> {
+        Help::write_app_help(w, self, true)
     }
 
     /// Writes the version message to the user to a [`io::Write`] object
@@ -1179,6 +1353,62 @@ impl<'a, 'b> App<'a, 'b> {
         self.p.gen_completions_to(for_shell, buf);
     }
 
+    /// Generate a man page (in roff `man(7)` format) for the application at compile time, the
+    /// same way [`App::gen_completions`] generates completion scripts. The page contains the
+    /// `NAME`, `SYNOPSIS`, `DESCRIPTION`, `OPTIONS` and `ARGS` sections, along with a
+    /// subsection for each subcommand. [`Hidden`] arguments and subcommands are left out, and
+    /// arguments are sorted according to their [`Arg::display_order`].
+    ///
+    /// The file is written to `{out_dir}/{bin_name}.1`
+    ///
+    /// # Examples
+    ///
+    /// Using the same `src/cli.rs` as the [completions example], a `build.rs` could contain:
+    ///
+    /// ```ignore
+    /// extern crate clap;
+    ///
+    /// include!("src/cli.rs");
+    ///
+    /// fn main() {
+    ///     let mut app = build_cli();
+    ///     app.gen_manpage("myapp", env!("OUT_DIR"));
+    /// }
+    /// ```
+    /// [`App::gen_completions`]: ./struct.App.html#method.gen_completions
+    /// [completions example]: ./struct.App.html#method.gen_completions
+    /// [`Hidden`]: ./enum.ArgSettings.html#variant.Hidden
+    /// [`Arg::display_order`]: ./struct.Arg.html#method.display_order
+    pub fn gen_manpage<T: Into<OsString>, S: Into<String>>(&mut self, bin_name: S, out_dir: T) {
+        self.p.meta.bin_name = Some(bin_name.into());
+        self.p.gen_manpage(out_dir.into());
+    }
+
+    /// Generate a man page (in roff `man(7)` format) for the application at runtime, and write
+    /// it to the given buffer. See [`App::gen_manpage`] for details on what the page contains.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// let mut app = App::new("myapp")
+    ///     .about("Does awesome things")
+    ///     .arg(Arg::with_name("config")
+    ///         .long("config")
+    ///         .takes_value(true)
+    ///         .help("Sets a custom config file"));
+    ///
+    /// let mut buf = vec![];
+    /// app.gen_manpage_to("myapp", &mut buf);
+    /// let page = String::from_utf8(buf).unwrap();
+    /// assert!(page.starts_with(".TH MYAPP 1"));
+    /// ```
+    /// [`App::gen_manpage`]: ./struct.App.html#method.gen_manpage
+    pub fn gen_manpage_to<W: Write, S: Into<String>>(&mut self, bin_name: S, buf: &mut W) {
+        self.p.meta.bin_name = Some(bin_name.into());
+        self.p.gen_manpage_to(buf);
+    }
+
     /// Starts the parsing process, upon a failed parse an error will be displayed to the user and
     /// the process will exit with the appropriate error code. By default this method gets all user
     /// provided arguments from [`env::args_os`] in order to allow for invalid UTF-8 code points,
@@ -1329,6 +1559,7 @@ impl<'a, 'b> App<'a, 'b> {
         self.p.derive_display_order();
 
         let mut matcher = ArgMatcher::new();
+        self.p.cur_idx.set(0);
 
         let mut it = itr.into_iter();
         // Get the name of the program (argument 1 of env::args()) and determine the
@@ -1353,13 +1584,52 @@ impl<'a, 'b> App<'a, 'b> {
         }
 
         // do the real parsing
-        if let Err(e) = self.p.get_matches_with(&mut matcher, &mut it.peekable()) {
+        if self.p.is_set(AppSettings::ResponseFiles) {
+            let args = try!(response_files::expand(it, self.p.color()));
+            try!(self.p.get_matches_with(&mut matcher, &mut args.into_iter().peekable()));
+        } else if let Err(e) = self.p.get_matches_with(&mut matcher, &mut it.peekable()) {
             return Err(e);
         }
 
         Ok(matcher.into())
     }
 
+    /// Deserializes [`ArgMatches`] previously produced by this `App` (requires the `serde` Cargo
+    /// feature). Unlike deserializing `ArgMatches` directly, which borrows the argument names
+    /// from the input, the names are taken from the `App`'s arguments. So this works with any
+    /// deserializer, such as one wrapping a reader, and with names which had to be escaped.
+    ///
+    /// Deserializing fails if the matches name an argument, group or subcommand which the `App`
+    /// doesn't have.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate clap;
+    /// # extern crate serde_json;
+    /// # use clap::{App, Arg};
+    /// # fn main() {
+    /// let app = App::new("myprog")
+    ///     .arg(Arg::with_name("out")
+    ///         .long("out")
+    ///         .takes_value(true));
+    /// let m = app.clone().get_matches_from(vec!["myprog", "--out", "file.txt"]);
+    /// let json = serde_json::to_vec(&m).unwrap();
+    ///
+    /// let mut de = serde_json::Deserializer::from_reader(&json[..]);
+    /// let m = app.deserialize_matches(&mut de).unwrap();
+    /// assert_eq!(m.value_of("out"), Some("file.txt"));
+    /// # }
+    /// ```
+    /// [`ArgMatches`]: ./struct.ArgMatches.html
+    #[cfg(feature = "serde")]
+    pub fn deserialize_matches<'de, D>(&self, deserializer: D) -> StdResult<ArgMatches<'a>, D::Error>
+        where D: Deserializer<'de>
+    {
+        let m = try!(OwnedArgMatches::deserialize(deserializer));
+        m.into_matches(&self.p, &[])
+    }
+
     // Re-implements ClapError::exit except it checks if we should wait for input before exiting
     // since ClapError doesn't have that info and the error message must be printed before exiting
     fn maybe_wait_for_exit(&self, e: Error) -> ! {
@@ -1407,6 +1677,7 @@ impl<'a> From<&'a Yaml> for App<'a, 'a> {
         yaml_str!(a, yaml, version);
         yaml_str!(a, yaml, bin_name);
         yaml_str!(a, yaml, about);
+        yaml_str!(a, yaml, long_about);
         yaml_str!(a, yaml, before_help);
         yaml_str!(a, yaml, after_help);
         yaml_str!(a, yaml, template);
@@ -1553,6 +1824,9 @@ impl<'n, 'e> AnyArg<'n, 'e> for App<'n, 'e> {
     fn validator(&self) -> Option<&Rc<Fn(String) -> StdResult<(), String>>> {
         None
     }
+    fn value_parser(&self) -> Option<&Rc<Fn(&OsStr) -> StdResult<Arc<Any + Send + Sync>, String>>> {
+        None
+    }
     fn min_vals(&self) -> Option<u64> {
         None
     }
@@ -1571,9 +1845,30 @@ impl<'n, 'e> AnyArg<'n, 'e> for App<'n, 'e> {
     fn help(&self) -> Option<&'e str> {
         self.p.meta.about
     }
+    fn long_help(&self) -> Option<&'e str> {
+        self.p.meta.long_about
+    }
+    fn val_terminator(&self) -> Option<&'e str> {
+        None
+    }
     fn default_val(&self) -> Option<&'n str> {
         None
     }
+    fn default_missing_val(&self) -> Option<&'n str> {
+        None
+    }
+    fn prompt(&self) -> Option<&'e str> {
+        None
+    }
+    fn action(&self) -> Option<&ArgAction> {
+        None
+    }
+    fn default_vals_ifs(&self) -> Option<vec_map::Values<(&'n str, Option<&'e str>, &'e str)>> {
+        None
+    }
+    fn env<'s>(&'s self) -> Option<(&'n OsStr, Option<&'s OsString>)> {
+        None
+    }
     fn longest_filter(&self) -> bool {
         true
     }
@@ -1,4 +1,6 @@
 // Std
+use std::ascii::AsciiExt;
+use std::cell::Cell;
 use std::collections::{BTreeMap, HashMap, VecDeque};
 use std::ffi::{OsStr, OsString};
 use std::fmt::Display;
@@ -18,14 +20,18 @@ use INTERNAL_ERROR_MSG;
 use INVALID_UTF8;
 use SubCommand;
 use app::App;
+#[cfg(feature = "config")]
+use app::config::{self, ConfigTable};
 use app::help::Help;
 use app::meta::AppMeta;
+use app::prompt;
 use app::settings::{AppFlags, AppSettings};
-use args::{AnyArg, ArgMatcher};
+use args::{AnyArg, ArgAction, ArgMatcher, ValueSource};
 use args::{Arg, ArgGroup, FlagBuilder, OptBuilder, PosBuilder};
 use args::MatchedArg;
 use args::settings::ArgSettings;
 use completions::ComplGen;
+use manpage::ManGen;
 use errors::{Error, ErrorKind};
 use errors::Result as ClapResult;
 use fmt::{Format, ColorWhen};
@@ -59,7 +65,17 @@ pub struct Parser<'a, 'b>
     settings: AppFlags,
     pub g_settings: Vec<AppSettings>,
     pub meta: AppMeta<'b>,
+    // The index of the argument currently being parsed, with the binary name being 0
+    pub cur_idx: Cell<usize>,
     trailing_vals: bool,
+    // With AppSettings::SubcommandsChain on the parent, the names and aliases of the sibling
+    // subcommands which end this one, and the one which did
+    chain_siblings: Vec<String>,
+    chained_next: Option<String>,
+    // The config file keys for this command, handed down by the parent command and layered with
+    // the files given by App::config_file
+    #[cfg(feature = "config")]
+    config: Option<ConfigTable>,
 }
 
 impl<'a, 'b> Default for Parser<'a, 'b> {
@@ -81,7 +97,12 @@ impl<'a, 'b> Default for Parser<'a, 'b> {
             g_settings: vec![],
             settings: AppFlags::new(),
             meta: AppMeta::new(),
+            cur_idx: Cell::new(0),
             trailing_vals: false,
+            chain_siblings: vec![],
+            chained_next: None,
+            #[cfg(feature = "config")]
+            config: None,
         }
     }
 }
@@ -132,6 +153,27 @@ impl<'a, 'b> Parser<'a, 'b>
         self.gen_completions_to(for_shell, &mut file)
     }
 
+    pub fn gen_manpage_to<W: Write>(&mut self, buf: &mut W) {
+
+        self.propogate_help_version();
+        self.build_bin_names();
+
+        ManGen::new(self).generate_to(buf)
+    }
+
+    pub fn gen_manpage(&mut self, od: OsString) {
+        use std::error::Error;
+
+        let out_dir = PathBuf::from(od);
+        let file_name = format!("{}.1", self.meta.bin_name.as_ref().unwrap());
+
+        let mut file = match File::create(out_dir.join(file_name)) {
+            Err(why) => panic!("couldn't create man page file: {}", why.description()),
+            Ok(file) => file,
+        };
+        self.gen_manpage_to(&mut file)
+    }
+
     // actually adds the arguments
     pub fn add_arg(&mut self, a: &Arg<'a, 'b>) {
         debug_assert!(!(self.flags.iter().any(|f| &f.name == &a.name) ||
@@ -378,7 +420,11 @@ impl<'a, 'b> Parser<'a, 'b>
         for &p in pmap.values() {
             let s = p.to_string();
             if args_in_groups.is_empty() || !args_in_groups.contains(&&*s) {
-                ret_val.push_back(s);
+                if p.is_set(ArgSettings::Last) {
+                    ret_val.push_back(format!("-- {}", s));
+                } else {
+                    ret_val.push_back(s);
+                }
             }
         }
         macro_rules! write_arg {
@@ -409,7 +455,9 @@ impl<'a, 'b> Parser<'a, 'b>
 
     pub fn get_args_tag(&self) -> Option<String> {
         let mut count = 0;
-        'outer: for p in self.positionals.values().filter(|p| !p.is_set(ArgSettings::Required)) {
+        'outer: for p in self.positionals
+            .values()
+            .filter(|p| !p.is_set(ArgSettings::Required) && !p.is_set(ArgSettings::Last)) {
             if let Some(g_vec) = self.groups_for_arg(p.name) {
                 for grp_s in &g_vec {
                     debugln!("iter;grp_s={};", grp_s);
@@ -430,11 +478,18 @@ impl<'a, 'b> Parser<'a, 'b>
                 count += 1;
             }
         }
-        if count > 1 || self.positionals.len() > 1 {
+        let num_not_last = self.positionals
+            .values()
+            .filter(|p| !p.is_set(ArgSettings::Last))
+            .count();
+        if count > 1 || num_not_last > 1 {
             return None;
         } else if count == 1 {
             let p = self.positionals.values().next().expect(INTERNAL_ERROR_MSG);
-            return Some(format!(" [{}]{}", p.name_no_brackets(), p.multiple_str()));
+            return Some(format!(" [{}]{}{}",
+                                p.name_no_brackets(),
+                                p.multiple_str(),
+                                p.terminator.map_or(String::new(), |t| format!(" {}", t))));
         }
         Some("".into())
     }
@@ -524,25 +579,42 @@ impl<'a, 'b> Parser<'a, 'b>
                             self.positionals.len()));
         }
 
+        // Next we verify that only the highest index has .last(true) set (if any)
+        debug_assert!(self.positionals
+                          .iter()
+                          .all(|(idx, p)| {
+                              !p.settings.is_set(ArgSettings::Last) || idx == self.positionals.len()
+                          }),
+                      "Only the last positional argument (i.e. the one with the highest index) \
+                      may be set to .last(true)");
+
         // Next we verify that only the highest index has a .multiple(true) (if any)
         if self.positionals()
             .any(|a| {
                 a.settings.is_set(ArgSettings::Multiple) &&
                 (a.index as usize != self.positionals.len())
             }) {
+            // A .last(true) positional is separated from the others by '--', so it may be
+            // .multiple(true) as well
             debug_assert!(self.positionals()
                 .filter(|p| p.settings.is_set(ArgSettings::Multiple)
+                    && !p.settings.is_set(ArgSettings::Last)
                     && p.num_vals.is_none()).map(|_| 1).sum::<u64>() <= 1,
                 "Only one positional argument with .multiple(true) set is allowed per command");
 
-            debug_assert!(self.positionals()
-                .rev()
-                .next()
-                .unwrap()
-                .is_set(ArgSettings::Required),
+            // When the last positional can only be reached via '--' there's no ambiguity about
+            // where the multiple values end, so it doesn't need to be required
+            let last_is_last = self.positionals().rev().next().unwrap().is_set(ArgSettings::Last);
+
+            debug_assert!(last_is_last ||
+                          self.positionals()
+                              .rev()
+                              .next()
+                              .unwrap()
+                              .is_set(ArgSettings::Required),
                 "When using a positional argument with .multiple(true) that is *not the last* \
                 positional argument, the last positional argument (i.e the one with the highest \
-                index) *must* have .required(true) set.");
+                index) *must* have .required(true) or .last(true) set.");
 
             debug_assert!({
                 let num = self.positionals.len() - 1;
@@ -550,11 +622,14 @@ impl<'a, 'b> Parser<'a, 'b>
             },
             "Only the last positional argument, or second to last positional argument may be set to .multiple(true)");
 
-            self.set(AppSettings::LowIndexMultiplePositional);
+            if !last_is_last {
+                self.set(AppSettings::LowIndexMultiplePositional);
+            }
         }
 
         debug_assert!(self.positionals()
             .filter(|p| p.settings.is_set(ArgSettings::Multiple)
+                && !p.settings.is_set(ArgSettings::Last)
                 && p.num_vals.is_none())
             .map(|_| 1)
             .sum::<u64>() <= 1,
@@ -570,7 +645,10 @@ impl<'a, 'b> Parser<'a, 'b>
                               than a required positional argument: {:?} index {}",
                               p.name,
                               p.index);
-            } else if p.settings.is_set(ArgSettings::Required) {
+            } else if p.settings.is_set(ArgSettings::Required) &&
+                      !p.settings.is_set(ArgSettings::Last) {
+                // A required .last(true) positional is supplied after '--', so the positionals
+                // before it may still be left off
                 found = true;
                 continue;
             }
@@ -590,13 +668,14 @@ impl<'a, 'b> Parser<'a, 'b>
         }
     }
 
-    // Checks if the arg matches a subcommand name, or any of it's aliases (if defined)
+    // Checks if the arg matches a subcommand name, or any of it's aliases (if defined), and
+    // returns the name of the subcommand. With InferSubcommands set a unique prefix matches too
     #[inline]
-    fn possible_subcommand(&self, arg_os: &OsStr) -> bool {
+    fn possible_subcommand(&self, arg_os: &OsStr) -> Option<&str> {
         debugln!("fn=possible_subcommand");
-        self.subcommands
+        if let Some(sc) = self.subcommands
             .iter()
-            .any(|s| {
+            .find(|s| {
                 &s.p.meta.name[..] == &*arg_os ||
                 (s.p.meta.aliases.is_some() &&
                  s.p
@@ -606,7 +685,38 @@ impl<'a, 'b> Parser<'a, 'b>
                     .unwrap()
                     .iter()
                     .any(|&(a, _)| a == &*arg_os))
+            }) {
+            return Some(&*sc.p.meta.name);
+        }
+        if self.is_set(AppSettings::InferSubcommands) {
+            let candidates = self.subcommands_starting_with(arg_os);
+            if candidates.len() == 1 {
+                return Some(candidates[0]);
+            }
+        }
+        None
+    }
+
+    // The names of all subcommands which have a name or alias starting with the arg, sorted
+    fn subcommands_starting_with(&self, arg_os: &OsStr) -> Vec<&str> {
+        let arg = match arg_os.to_str() {
+            Some(a) if !a.is_empty() => a,
+            _ => return vec![],
+        };
+        let mut candidates = self.subcommands
+            .iter()
+            .filter(|s| {
+                s.p.meta.name.starts_with(arg) ||
+                s.p
+                    .meta
+                    .aliases
+                    .as_ref()
+                    .map_or(false, |als| als.iter().any(|&(a, _)| a.starts_with(arg)))
             })
+            .map(|s| &*s.p.meta.name)
+            .collect::<Vec<_>>();
+        candidates.sort();
+        candidates
     }
 
     #[inline]
@@ -625,6 +735,74 @@ impl<'a, 'b> Parser<'a, 'b>
             })
     }
 
+    // Checks if the arg names a defined flag or option, i.e. `--long`, `--long=val` or `-s...`
+    fn is_defined_arg(&self, arg_os: &OsStr) -> bool {
+        debugln!("fn=is_defined_arg;");
+        if arg_os.starts_with(b"--") {
+            let long = arg_os.trim_left_matches(b'-').split_at_byte(b'=').0;
+            self.is_defined_long(long) ||
+            (self.is_set(AppSettings::InferLongArgs) && !self.longs_starting_with(long).is_empty())
+        } else if let Some(c) = arg_os.trim_left_matches(b'-').to_string_lossy().chars().next() {
+            self.opts.iter().any(|o| o.short == Some(c)) ||
+            self.flags.iter().any(|f| f.short == Some(c))
+        } else {
+            false
+        }
+    }
+
+    // Checks if the long name (without the leading `--`) exactly matches a long or alias of a flag
+    // or option, or the `no-` form of a negatable flag
+    fn is_defined_long(&self, long: &OsStr) -> bool {
+        let has_long = |l: Option<&str>, aliases: &Option<Vec<(&str, bool)>>| {
+            l.map_or(false, |l| long == OsStr::new(l)) ||
+            aliases.as_ref().map_or(false, |v| v.iter().any(|&(n, _)| long == OsStr::new(n)))
+        };
+        let negated = if long.starts_with(b"no-") {
+            Some(long.split_at(3).1)
+        } else {
+            None
+        };
+        self.opts.iter().any(|o| has_long(o.long, &o.aliases)) ||
+        self.flags.iter().any(|f| {
+            has_long(f.long, &f.aliases) ||
+            (f.is_set(ArgSettings::Negatable) &&
+             negated.map_or(false, |n| f.long.map_or(false, |l| n == OsStr::new(l))))
+        })
+    }
+
+    // The long names (without the leading `--`) of all flags and options which have a long or
+    // alias starting with the arg, one per argument and sorted. The `no-` form of a negatable flag
+    // counts as an argument of its own
+    fn longs_starting_with(&self, arg_os: &OsStr) -> Vec<String> {
+        let arg = match arg_os.to_str() {
+            Some(a) if !a.is_empty() => a,
+            _ => return vec![],
+        };
+        let matching = |l: Option<&str>, aliases: &Option<Vec<(&str, bool)>>| {
+            l.into_iter()
+                .chain(aliases.iter().flat_map(|v| v.iter().map(|&(n, _)| n)))
+                .find(|n| n.starts_with(arg))
+                .map(|n| n.to_owned())
+        };
+        let mut candidates = vec![];
+        for o in &self.opts {
+            candidates.extend(matching(o.long, &o.aliases));
+        }
+        for f in &self.flags {
+            candidates.extend(matching(f.long, &f.aliases));
+            if f.is_set(ArgSettings::Negatable) {
+                if let Some(l) = f.long {
+                    let neg = format!("no-{}", l);
+                    if neg.starts_with(arg) {
+                        candidates.push(neg);
+                    }
+                }
+            }
+        }
+        candidates.sort();
+        candidates
+    }
+
     fn parse_help_subcommand<I, T>(&self, it: &mut I) -> ClapResult<()>
         where I: Iterator<Item = T>,
               T: Into<OsString>
@@ -644,24 +822,8 @@ impl<'a, 'b> Parser<'a, 'b>
                     // cmd help help
                     help_help = true;
                 }
-                if let Some(c) = sc.subcommands
-                    .iter()
-                    .find(|s| &*s.p.meta.name == cmd)
-                    .map(|sc| &sc.p) {
-                    sc = c;
-                    if i == cmds.len() - 1 {
-                        break;
-                    }
-                } else if let Some(c) = sc.subcommands
-                    .iter()
-                    .find(|s| if let Some(ref als) = s.p
-                        .meta
-                        .aliases {
-                        als.iter()
-                            .any(|&(a, _)| &a == &&*cmd.to_string_lossy())
-                    } else {
-                        false
-                    })
+                if let Some(c) = sc.possible_subcommand(cmd)
+                    .and_then(|name| sc.subcommands.iter().find(|s| &*s.p.meta.name == name))
                     .map(|sc| &sc.p) {
                     sc = c;
                     if i == cmds.len() - 1 {
@@ -695,7 +857,7 @@ impl<'a, 'b> Parser<'a, 'b>
         if sc.meta.bin_name != self.meta.bin_name {
             sc.meta.bin_name = Some(format!("{} {}", bin_name, sc.meta.name));
         }
-        sc._help()
+        sc._help(true)
     }
 
     // The actual parsing function
@@ -720,37 +882,84 @@ impl<'a, 'b> Parser<'a, 'b>
         let mut pos_counter = 1;
         while let Some(arg) = it.next() {
             let arg_os = arg.into();
+            self.cur_idx.set(self.cur_idx.get() + 1);
             debugln!("Begin parsing '{:?}' ({:?})", arg_os, &*arg_os.as_bytes());
 
+            // Options with a value terminator take every arg as a value, even those starting
+            // with a hyphen, until the terminator is found
+            if let Some(opt) = needs_val_of.and_then(|n| self.get_opt(n)) {
+                if opt.terminator.is_some() {
+                    needs_val_of = try!(self.add_val_to_arg(opt, &arg_os, matcher, true));
+                    continue;
+                }
+            }
+
             // Is this a new argument, or values from a previous option?
             let starts_new_arg = is_new_arg(&arg_os);
+            let is_double_dash = &*arg_os == OsStr::new("--");
 
             // Has the user already passed '--'? Meaning only positional args follow
             if !self.trailing_vals {
                 // Does the arg match a subcommand name, or any of it's aliases (if defined)
-                let pos_sc = self.possible_subcommand(&arg_os);
+                let pos_sc = self.possible_subcommand(&arg_os).map(|s| s.to_owned());
+                // Or does it start the next of a chain of subcommands
+                let chained_sc = if pos_sc.is_none() {
+                    self.chain_siblings.iter().find(|s| &***s == &*arg_os).cloned()
+                } else {
+                    None
+                };
+
+                // Options which allow hyphen values take the next arg as a value, whatever it
+                // starts with (except a lone '--')
+                let opt_allows_hyphen = !is_double_dash &&
+                                        needs_val_of.and_then(|n| self.get_opt(n))
+                                            .map_or(false, |o| {
+                                                o.is_set(ArgSettings::AllowLeadingHyphen)
+                                            });
+
+                // Positionals which allow hyphen values take any arg which isn't a defined flag
+                // or option
+                let pos_allows_hyphen = starts_new_arg && !is_double_dash &&
+                                        needs_val_of.is_none() &&
+                                        self.positionals
+                                            .get(pos_counter)
+                                            .map_or(false, |p| {
+                                                p.is_set(ArgSettings::AllowLeadingHyphen)
+                                            }) &&
+                                        !self.is_defined_arg(&arg_os);
 
                 // If the arg doesn't start with a `-` (except numbers, or AllowLeadingHyphen) and
                 // isn't a subcommand
-                if (!starts_new_arg ||
+                if (!starts_new_arg || opt_allows_hyphen ||
                     (self.is_set(AppSettings::AllowLeadingHyphen) ||
                      self.is_set(AppSettings::AllowNegativeNumbers))) &&
-                   !pos_sc {
+                   pos_sc.is_none() && chained_sc.is_none() {
                     // Check to see if parsing a value from an option
                     if let Some(arg) = needs_val_of {
                         // get the OptBuilder so we can check the settings
                         if let Some(opt) = self.get_opt(arg) {
-                            needs_val_of = try!(self.add_val_to_arg(&*opt, &arg_os, matcher));
+                            needs_val_of = try!(self.add_val_to_arg(&*opt, &arg_os, matcher, true));
                             // get the next value from the iterator
                             continue;
                         }
                     }
                 }
-                if arg_os.starts_with(b"--") {
+                if pos_allows_hyphen {
+                    debugln!("Parsing '{:?}' as a positional value", arg_os);
+                } else if arg_os.starts_with(b"--") {
                     if arg_os.len_() == 2 {
                         // The user has passed '--' which means only positional args follow no
                         // matter what they start with
                         self.trailing_vals = true;
+                        // If the last positional may only be supplied after '--', everything
+                        // that follows belongs to it
+                        if self.positionals
+                            .values()
+                            .rev()
+                            .next()
+                            .map_or(false, |p| p.is_set(ArgSettings::Last)) {
+                            pos_counter = self.positionals.len();
+                        }
                         continue;
                     }
 
@@ -762,6 +971,7 @@ impl<'a, 'b> Parser<'a, 'b>
                     // Try to parse short args like normal, if AllowLeadingHyphen or
                     // AllowNegativeNumbers is set, parse_short_arg will *not* throw
                     // an error, and instead return Ok(None)
+                    let idx = self.cur_idx.get();
                     needs_val_of = try!(self.parse_short_arg(matcher, &arg_os));
                     // If it's None, we then check if one of those two AppSettings was set
                     debugln!("AllowLeadingHyphen set...{:?}", self.is_set(AppSettings::AllowLeadingHyphen));
@@ -773,24 +983,40 @@ impl<'a, 'b> Parser<'a, 'b>
                             if !(arg_os.to_string_lossy().parse::<i64>().is_ok() ||
                                  arg_os.to_string_lossy().parse::<f64>().is_ok()) {
                                 return Err(Error::unknown_argument(&*arg_os.to_string_lossy(),
-                                                            "",
+                                                            None,
                                                             &*self.create_current_usage(matcher),
                                                             self.color()));
                             }
                         } else if !self.is_set(AppSettings::AllowLeadingHyphen) {
                             continue;
                         }
+                        // It's going to be parsed as a value, which takes a single index
+                        self.cur_idx.set(idx);
                     } else {
                         continue;
                     }
                 }
 
-                if pos_sc {
-                    if &*arg_os == "help" && self.is_set(AppSettings::NeedsSubcommandHelp) {
+                if let Some(sc_name) = pos_sc {
+                    if sc_name == "help" && self.is_set(AppSettings::NeedsSubcommandHelp) {
                         try!(self.parse_help_subcommand(it));
                     }
-                    subcmd_name = Some(arg_os.to_str().expect(INVALID_UTF8).to_owned());
+                    subcmd_name = Some(sc_name);
                     break;
+                } else if chained_sc.is_some() {
+                    self.chained_next = chained_sc;
+                    break;
+                } else if self.is_set(AppSettings::InferSubcommands) &&
+                          self.subcommands_starting_with(&arg_os).len() > 1 {
+                    let candidates = self.subcommands_starting_with(&arg_os);
+                    return Err(Error::ambiguous_subcommand(arg_os.to_string_lossy().into_owned(),
+                                                           &*candidates,
+                                                           self.meta
+                                                               .bin_name
+                                                               .as_ref()
+                                                               .unwrap_or(&self.meta.name),
+                                                           &*self.create_current_usage(matcher),
+                                                           self.color()));
                 } else if let Some(cdate) =
                               suggestions::did_you_mean(&*arg_os.to_string_lossy(),
                                                         self.subcommands
@@ -814,7 +1040,7 @@ impl<'a, 'b> Parser<'a, 'b>
                 sdebugln!("Found");
                 if let Some(na) = it.peek() {
                     let n = (*na).clone().into();
-                    if is_new_arg(&n) || self.possible_subcommand(&n) ||
+                    if is_new_arg(&n) || self.possible_subcommand(&n).is_some() ||
                        suggestions::did_you_mean(&n.to_string_lossy(),
                                                  self.subcommands
                                                      .iter()
@@ -831,6 +1057,18 @@ impl<'a, 'b> Parser<'a, 'b>
                 sdebugln!("None");
             }
             if let Some(p) = self.positionals.get(pos_counter) {
+                if p.is_set(ArgSettings::Last) && !self.trailing_vals {
+                    return Err(Error::missing_last_escape(p,
+                                                          &*arg_os.to_string_lossy(),
+                                                          &*self.create_current_usage(matcher),
+                                                          self.color()));
+                }
+                if p.terminator.map_or(false, |t| &*arg_os == OsStr::new(t)) {
+                    // The terminator ends this positional's values, so the next arg belongs to
+                    // the next positional
+                    pos_counter += 1;
+                    continue;
+                }
                 parse_positional!(self, p, arg_os, pos_counter, matcher);
             } else if self.settings.is_set(AppSettings::AllowExternalSubcommands) {
                 // Get external subcommand name
@@ -853,7 +1091,9 @@ impl<'a, 'b> Parser<'a, 'b>
                             return Err(Error::invalid_utf8(&*self.create_current_usage(matcher),
                                                            self.color()));
                     }
+                    self.cur_idx.set(self.cur_idx.get() + 1);
                     sc_m.add_val_to("", &a);
+                    sc_m.add_index_to("", self.cur_idx.get());
                 }
 
                 matcher.subcommand(SubCommand {
@@ -863,7 +1103,7 @@ impl<'a, 'b> Parser<'a, 'b>
             } else if !(self.is_set(AppSettings::AllowLeadingHyphen) ||
                         self.is_set(AppSettings::AllowNegativeNumbers)) {
                 return Err(Error::unknown_argument(&*arg_os.to_string_lossy(),
-                                                   "",
+                                                   None,
                                                    &*self.create_current_usage(matcher),
                                                    self.color()));
             }
@@ -888,7 +1128,8 @@ impl<'a, 'b> Parser<'a, 'b>
                 try!(self.validate_required(matcher));
                 reqs_validated = true;
                 let should_err = if let Some(v) = matcher.0.args.get(&*o.name) {
-                    v.vals.is_empty() && !(o.min_vals.is_some() && o.min_vals.unwrap() == 0)
+                    v.vals.is_empty() && !(o.min_vals.is_some() && o.min_vals.unwrap() == 0) &&
+                    o.default_missing_val.is_none()
                 } else {
                     true
                 };
@@ -909,6 +1150,9 @@ impl<'a, 'b> Parser<'a, 'b>
             }
         }
 
+        try!(self.add_missing_vals(matcher));
+        try!(self.add_env(matcher));
+        try!(self.add_config(matcher));
         try!(self.add_defaults(matcher));
         try!(self.validate_blacklist(matcher));
         try!(self.validate_num_args(matcher));
@@ -918,28 +1162,8 @@ impl<'a, 'b> Parser<'a, 'b>
            !reqs_validated {
             try!(self.validate_required(matcher));
         }
-        if let Some(pos_sc_name) = subcmd_name {
-            // is this is a real subcommand, or an alias
-            let sc_name = if self.subcommands.iter().any(|sc| sc.p.meta.name == pos_sc_name) {
-                pos_sc_name
-            } else {
-                self.subcommands
-                    .iter()
-                    .filter(|sc| sc.p.meta.aliases.is_some())
-                    .filter_map(|sc| if sc.p
-                        .meta
-                        .aliases
-                        .as_ref()
-                        .unwrap()
-                        .iter()
-                        .any(|&(a, _)| &a == &&*pos_sc_name) {
-                        Some(sc.p.meta.name.clone())
-                    } else {
-                        None
-                    })
-                    .next()
-                    .expect(INTERNAL_ERROR_MSG)
-            };
+        if let Some(sc_name) = subcmd_name {
+            // possible_subcommand already resolved any alias or prefix to the real name
             try!(self.parse_subcommand(sc_name, matcher, it));
         } else if self.is_set(AppSettings::SubcommandRequired) {
             let bn = self.meta.bin_name.as_ref().unwrap_or(&self.meta.name);
@@ -949,21 +1173,20 @@ impl<'a, 'b> Parser<'a, 'b>
         } else if self.is_set(AppSettings::SubcommandRequiredElseHelp) {
             let mut out = vec![];
             try!(self.write_help_err(&mut out));
-            return Err(Error {
-                message: String::from_utf8_lossy(&*out).into_owned(),
-                kind: ErrorKind::MissingArgumentOrSubcommand,
-                info: None,
-            });
+            return Err(Error::raw(ErrorKind::MissingArgumentOrSubcommand,
+                                  String::from_utf8_lossy(&*out).into_owned()));
         }
         if matcher.is_empty() && matcher.subcommand_name().is_none() &&
            self.is_set(AppSettings::ArgRequiredElseHelp) {
             let mut out = vec![];
             try!(self.write_help_err(&mut out));
-            return Err(Error {
-                message: String::from_utf8_lossy(&*out).into_owned(),
-                kind: ErrorKind::MissingArgumentOrSubcommand,
-                info: None,
-            });
+            return Err(Error::raw(ErrorKind::MissingArgumentOrSubcommand,
+                                  String::from_utf8_lossy(&*out).into_owned()));
+        }
+        self.add_flag_vals(matcher);
+        if matcher.subcommand_name().is_some() && !self.global_args.is_empty() {
+            let globals: Vec<&'a str> = self.global_args.iter().map(|a| a.name).collect();
+            matcher.propagate_globals(&globals);
         }
         Ok(())
     }
@@ -1026,35 +1249,78 @@ impl<'a, 'b> Parser<'a, 'b>
             }
         }
         mid_string.push_str(" ");
-        if let Some(ref mut sc) = self.subcommands
-            .iter_mut()
-            .find(|s| &s.p.meta.name == &sc_name) {
-            let mut sc_matcher = ArgMatcher::new();
-            // bin_name should be parent's bin_name + [<reqs>] + the sc's name separated by
-            // a space
-            sc.p.meta.usage = Some(format!("{}{}{}",
-                                           self.meta.bin_name.as_ref().unwrap_or(&String::new()),
-                                           if self.meta.bin_name.is_some() {
-                                               &*mid_string
-                                           } else {
-                                               ""
-                                           },
-                                           &*sc.p.meta.name));
-            sc.p.meta.bin_name = Some(format!("{}{}{}",
-                                              self.meta
-                                                  .bin_name
-                                                  .as_ref()
-                                                  .unwrap_or(&String::new()),
-                                              if self.meta.bin_name.is_some() {
-                                                  " "
-                                              } else {
-                                                  ""
-                                              },
-                                              &*sc.p.meta.name));
-            try!(sc.p.get_matches_with(&mut sc_matcher, it));
-            matcher.subcommand(SubCommand {
-                name: sc.p.meta.name.clone(),
-                matches: sc_matcher.into(),
+        // With chaining, a subcommand ends at the name of any sibling, except the help subcommand
+        let siblings: Vec<String> = if self.is_set(AppSettings::SubcommandsChain) {
+            let help_sc = self.is_set(AppSettings::NeedsSubcommandHelp);
+            self.subcommands
+                .iter()
+                .filter(|s| !(help_sc && s.p.meta.name == "help"))
+                .flat_map(|s| {
+                    let mut names = vec![s.p.meta.name.clone()];
+                    if let Some(ref aliases) = s.p.meta.aliases {
+                        names.extend(aliases.iter().map(|&(a, _)| a.to_owned()));
+                    }
+                    names
+                })
+                .collect()
+        } else {
+            vec![]
+        };
+        let mut next_sc = Some(sc_name);
+        while let Some(sc_name) = next_sc.take() {
+            if let Some(ref mut sc) = self.subcommands
+                .iter_mut()
+                .find(|s| &s.p.meta.name == &sc_name) {
+                let mut sc_matcher = ArgMatcher::new();
+                // bin_name should be parent's bin_name + [<reqs>] + the sc's name separated by
+                // a space
+                sc.p.meta.usage = Some(format!("{}{}{}",
+                                               self.meta
+                                                   .bin_name
+                                                   .as_ref()
+                                                   .unwrap_or(&String::new()),
+                                               if self.meta.bin_name.is_some() {
+                                                   &*mid_string
+                                               } else {
+                                                   ""
+                                               },
+                                               &*sc.p.meta.name));
+                sc.p.meta.bin_name = Some(format!("{}{}{}",
+                                                  self.meta
+                                                      .bin_name
+                                                      .as_ref()
+                                                      .unwrap_or(&String::new()),
+                                                  if self.meta.bin_name.is_some() {
+                                                      " "
+                                                  } else {
+                                                      ""
+                                                  },
+                                                  &*sc.p.meta.name));
+                // Parsing changes the parser's state (requirements, conflicts, etc.), so each use
+                // of the subcommand, which may be used more than once when chaining, gets a copy
+                let mut p = sc.p.clone();
+                // Indices continue from the parent so they stay relative to the whole command line
+                p.cur_idx.set(self.cur_idx.get());
+                p.chain_siblings = siblings.clone();
+                p.chained_next = None;
+                if p.meta.prompt_streams.is_none() {
+                    p.meta.prompt_streams = self.meta.prompt_streams.clone();
+                }
+                #[cfg(feature = "config")]
+                {
+                    p.config = self.config.as_ref().and_then(|c| c.table(&p.meta.name)).cloned();
+                }
+                try!(p.get_matches_with(&mut sc_matcher, it));
+                self.cur_idx.set(p.cur_idx.get());
+                matcher.subcommand(SubCommand {
+                    name: p.meta.name.clone(),
+                    matches: sc_matcher.into(),
+                });
+                next_sc = p.chained_next.take();
+            }
+            // The next subcommand may have been given by an alias
+            next_sc = next_sc.and_then(|n| {
+                self.possible_subcommand(OsStr::new(&n)).map(|s| s.to_owned())
             });
         }
         Ok(())
@@ -1142,6 +1408,7 @@ impl<'a, 'b> Parser<'a, 'b>
                 short: self.help_short,
                 long: Some("help"),
                 help: Some("Prints help information"),
+                action: Some(ArgAction::Help),
                 ..Default::default()
             };
             if let Some(h) = self.help_short {
@@ -1162,6 +1429,7 @@ impl<'a, 'b> Parser<'a, 'b>
                 short: self.version_short,
                 long: Some("version"),
                 help: Some("Prints version information"),
+                action: Some(ArgAction::Version),
                 ..Default::default()
             };
             if let Some(v) = self.version_short {
@@ -1200,59 +1468,40 @@ impl<'a, 'b> Parser<'a, 'b>
             .collect::<Vec<_>>())
     }
 
-    fn check_for_help_and_version_str(&self, arg: &OsStr) -> ClapResult<()> {
-        debug!("Checking if --{} is help or version...",
-               arg.to_str().unwrap());
-        if arg == "help" && self.settings.is_set(AppSettings::NeedsLongHelp) {
-            sdebugln!("Help");
-            try!(self._help());
-        }
-        if arg == "version" && self.settings.is_set(AppSettings::NeedsLongVersion) {
-            sdebugln!("Version");
-            try!(self._version());
-        }
-        sdebugln!("Neither");
-
-        Ok(())
-    }
-
-    fn check_for_help_and_version_char(&self, arg: char) -> ClapResult<()> {
-        debug!("Checking if -{} is help or version...", arg);
-        if let Some(h) = self.help_short {
-            if arg == h && self.settings.is_set(AppSettings::NeedsLongHelp) {
+    // Flags with the help or version action, including the auto-generated -h/--help and
+    // -V/--version, display their message. The long help is used for a flag's long name
+    fn check_for_help_and_version(&self,
+                                  flag: &FlagBuilder<'a, 'b>,
+                                  use_long: bool)
+                                  -> ClapResult<()> {
+        debug!("Checking if {} is help or version...", flag);
+        match flag.action {
+            Some(ArgAction::Help) => {
                 sdebugln!("Help");
-                try!(self._help());
+                try!(self._help(use_long));
             }
-        }
-        if let Some(v) = self.version_short {
-            if arg == v && self.settings.is_set(AppSettings::NeedsLongVersion) {
+            Some(ArgAction::Version) => {
                 sdebugln!("Version");
                 try!(self._version());
             }
+            _ => {
+                sdebugln!("Neither");
+            }
         }
-        sdebugln!("Neither");
         Ok(())
     }
 
-    fn _help(&self) -> ClapResult<()> {
+    fn _help(&self, use_long: bool) -> ClapResult<()> {
         let mut buf = vec![];
-        try!(Help::write_parser_help(&mut buf, self));
-        Err(Error {
-            message: unsafe { String::from_utf8_unchecked(buf) },
-            kind: ErrorKind::HelpDisplayed,
-            info: None,
-        })
+        try!(Help::write_parser_help(&mut buf, self, use_long));
+        Err(Error::raw(ErrorKind::HelpDisplayed, unsafe { String::from_utf8_unchecked(buf) }))
     }
 
     fn _version(&self) -> ClapResult<()> {
         let out = io::stdout();
         let mut buf_w = BufWriter::new(out.lock());
         try!(self.print_version(&mut buf_w));
-        Err(Error {
-            message: String::new(),
-            kind: ErrorKind::VersionDisplayed,
-            info: None,
-        })
+        Err(Error::raw(ErrorKind::VersionDisplayed, String::new()))
     }
 
     fn parse_long_arg(&mut self,
@@ -1273,6 +1522,28 @@ impl<'a, 'b> Parser<'a, 'b>
             full_arg.trim_left_matches(b'-')
         };
 
+        // With InferLongArgs a unique prefix stands for the whole long name
+        let inferred;
+        let arg = if self.is_set(AppSettings::InferLongArgs) && !self.is_defined_long(arg) {
+            let candidates = self.longs_starting_with(arg);
+            if candidates.len() > 1 {
+                return Err(Error::ambiguous_argument(format!("--{}", arg.to_string_lossy()),
+                                                     &*candidates,
+                                                     &*self.create_current_usage(matcher),
+                                                     self.color()));
+            }
+            match candidates.into_iter().next() {
+                Some(long) => {
+                    debugln!("Inferred '--{}'", long);
+                    inferred = OsString::from(long);
+                    &*inferred
+                }
+                None => arg,
+            }
+        } else {
+            arg
+        };
+
         if let Some(opt) = self.opts
             .iter()
             .find(|v| {
@@ -1303,13 +1574,24 @@ impl<'a, 'b> Parser<'a, 'b>
             debugln!("Found valid flag '{}'", flag.to_string());
             // Only flags could be help or version, and we need to check the raw long
             // so this is the first point to check
-            try!(self.check_for_help_and_version_str(arg));
+            try!(self.check_for_help_and_version(flag, true));
 
             try!(self.parse_flag(flag, matcher));
 
             // Handle conflicts, requirements, etc.
             arg_post_processing!(self, flag, matcher);
 
+            return Ok(None);
+        } else if let Some(flag) = self.flags
+            .iter()
+            .find(|v| {
+                v.is_set(ArgSettings::Negatable) && arg.starts_with(b"no-") &&
+                v.long.map_or(false, |l| arg.split_at(3).1 == OsStr::new(l))
+            }) {
+            debugln!("Found negated flag '{}'", flag.to_string());
+            // The last of --flag and --no-flag wins, so this undoes any previous --flag
+            let name = flag.name;
+            self.negate_flag(name, matcher);
             return Ok(None);
         } else if self.is_set(AppSettings::AllowLeadingHyphen) {
             return Ok(None);
@@ -1332,7 +1614,11 @@ impl<'a, 'b> Parser<'a, 'b>
         let arg_os = full_arg.trim_left_matches(b'-');
         let arg = arg_os.to_string_lossy();
 
-        for c in arg.chars() {
+        for (i, c) in arg.chars().enumerate() {
+            // Each short arg in a group such as -abc gets its own index, as if it were -a -b -c
+            if i != 0 {
+                self.cur_idx.set(self.cur_idx.get() + 1);
+            }
             // Check for matching short options, and return the name if there is no trailing
             // concatenated value: -oval
             // Option: -o
@@ -1358,6 +1644,14 @@ impl<'a, 'b> Parser<'a, 'b>
                     None
                 };
 
+                // A concatenated value such as -oval doesn't count when an equals is required
+                if opt.is_set(ArgSettings::RequireEquals) &&
+                   val.map_or(false, |v| !v.starts_with(&[b'='])) {
+                    return Err(Error::empty_value(opt,
+                                                  &*self.create_current_usage(matcher),
+                                                  self.color()));
+                }
+
                 // Default to "we're expecting a value later"
                 let ret = try!(self.parse_opt(val, opt, matcher));
 
@@ -1369,7 +1663,7 @@ impl<'a, 'b> Parser<'a, 'b>
                 .find(|&v| v.short.is_some() && v.short.unwrap() == c) {
                 debugln!("Found valid short flag -{}", c);
                 // Only flags can be help or version
-                try!(self.check_for_help_and_version_char(c));
+                try!(self.check_for_help_and_version(flag, false));
                 try!(self.parse_flag(flag, matcher));
                 // Handle conflicts, requirements, overrides, etc.
                 // Must be called here due to mutablilty
@@ -1380,7 +1674,7 @@ impl<'a, 'b> Parser<'a, 'b>
                 arg.push('-');
                 arg.push(c);
                 return Err(Error::unknown_argument(&*arg,
-                                                   "",
+                                                   None,
                                                    &*self.create_current_usage(matcher),
                                                    self.color()));
             }
@@ -1395,7 +1689,12 @@ impl<'a, 'b> Parser<'a, 'b>
                  -> ClapResult<Option<&'a str>> {
         debugln!("fn=parse_opt;");
         validate_multiples!(self, opt, matcher);
+        if let Some(ArgAction::Set) = opt.action {
+            // The last use wins
+            matcher.clear_vals(opt.name);
+        }
         let mut has_eq = false;
+        let optional = opt.default_missing_val.is_some() || opt.min_vals == Some(0);
 
         debug!("Checking for val...");
         if let Some(fv) = val {
@@ -1409,7 +1708,14 @@ impl<'a, 'b> Parser<'a, 'b>
             }
             sdebugln!("Found - {:?}, len: {}", v, v.len_());
             debugln!("{:?} contains '='...{:?}", fv, fv.starts_with(&[b'=']));
-            try!(self.add_val_to_arg(opt, v, matcher));
+            // A value attached to the option (-oval or --opt=val) gets the next index
+            self.cur_idx.set(self.cur_idx.get() + 1);
+            try!(self.add_val_to_arg(opt, v, matcher, true));
+        } else if opt.is_set(ArgSettings::RequireEquals) && !optional {
+            sdebugln!("None, but '=' is required - Error");
+            return Err(Error::empty_value(opt,
+                                          &*self.create_current_usage(matcher),
+                                          self.color()));
         } else {
             sdebugln!("None");
         }
@@ -1418,7 +1724,12 @@ impl<'a, 'b> Parser<'a, 'b>
         // Increment or create the group "args"
         self.groups_for_arg(opt.name).and_then(|vec| Some(matcher.inc_occurrences_of(&*vec)));
 
-        if val.is_none() ||
+        // Without an '=' the value was left off, the following arg is never taken as the value
+        if val.is_none() && opt.is_set(ArgSettings::RequireEquals) {
+            return Ok(None);
+        }
+
+        if val.is_none() || opt.terminator.is_some() ||
            !has_eq &&
            (opt.is_set(ArgSettings::Multiple) && !opt.is_set(ArgSettings::RequireDelimiter) &&
             matcher.needs_more_vals(opt)) {
@@ -1427,22 +1738,35 @@ impl<'a, 'b> Parser<'a, 'b>
         Ok(None)
     }
 
+    // `track_idx` is false for values which didn't come from the command line (i.e. env vars and
+    // default values), these don't get an index
     fn add_val_to_arg<A>(&self,
                          arg: &A,
                          val: &OsStr,
-                         matcher: &mut ArgMatcher<'a>)
+                         matcher: &mut ArgMatcher<'a>,
+                         track_idx: bool)
                          -> ClapResult<Option<&'a str>>
         where A: AnyArg<'a, 'b> + Display
     {
         debugln!("fn=add_val_to_arg;");
+        if let Some(t) = arg.val_terminator() {
+            if val == OsStr::new(t) {
+                debugln!("found terminator, no more values");
+                return Ok(None);
+            }
+        }
         let mut ret = None;
         if !(self.trailing_vals && self.is_set(AppSettings::DontDelimitTrailingValues)) {
             if let Some(delim) = arg.val_delim() {
                 if val.is_empty_() {
-                    ret = try!(self.add_single_val_to_arg(arg, val, matcher));
+                    ret = try!(self.add_single_val_to_arg(arg, val, matcher, track_idx));
                 } else {
-                    for v in val.split(delim as u32 as u8) {
-                        ret = try!(self.add_single_val_to_arg(arg, v, matcher));
+                    for (i, v) in val.split(delim as u32 as u8).enumerate() {
+                        // Each delimited value gets its own index, as if it were a separate arg
+                        if track_idx && i != 0 {
+                            self.cur_idx.set(self.cur_idx.get() + 1);
+                        }
+                        ret = try!(self.add_single_val_to_arg(arg, v, matcher, track_idx));
                     }
                     // If there was a delimiter used, we're not looking for more values
                     if val.contains_byte(delim as u32 as u8) ||
@@ -1451,10 +1775,14 @@ impl<'a, 'b> Parser<'a, 'b>
                     }
                 }
             } else {
-                ret = try!(self.add_single_val_to_arg(arg, val, matcher));
+                ret = try!(self.add_single_val_to_arg(arg, val, matcher, track_idx));
             }
         } else {
-            ret = try!(self.add_single_val_to_arg(arg, val, matcher));
+            ret = try!(self.add_single_val_to_arg(arg, val, matcher, track_idx));
+        }
+        // Args with a value terminator keep taking values until the terminator is found
+        if arg.val_terminator().is_some() {
+            ret = Some(arg.name());
         }
         Ok(ret)
     }
@@ -1462,29 +1790,54 @@ impl<'a, 'b> Parser<'a, 'b>
     fn add_single_val_to_arg<A>(&self,
                                 arg: &A,
                                 v: &OsStr,
-                                matcher: &mut ArgMatcher<'a>)
+                                matcher: &mut ArgMatcher<'a>,
+                                track_idx: bool)
                                 -> ClapResult<Option<&'a str>>
         where A: AnyArg<'a, 'b> + Display
     {
         debugln!("adding val: {:?}", v);
+        // Case insensitive values are stored using the spelling of the matching possible value
+        let v = if arg.is_set(ArgSettings::CaseInsensitive) {
+            arg.possible_vals()
+                .and_then(|p_vals| {
+                    let val_str = v.to_string_lossy();
+                    p_vals.iter().find(|pv| pv.eq_ignore_ascii_case(&*val_str)).map(OsStr::new)
+                })
+                .unwrap_or(v)
+        } else {
+            v
+        };
         matcher.add_val_to(arg.name(), v);
+        if track_idx {
+            matcher.add_index_to(arg.name(), self.cur_idx.get());
+        }
 
         // Increment or create the group "args"
         if let Some(grps) = self.groups_for_arg(arg.name()) {
             for grp in grps {
                 matcher.add_val_to(&*grp, v);
+                if track_idx {
+                    matcher.add_index_to(&*grp, self.cur_idx.get());
+                }
             }
         }
 
         // The validation must come AFTER inserting into 'matcher' or the usage string
         // can't be built
-        self.validate_value(arg, v, matcher)
+        let needs_more = try!(self.validate_value(arg, v, matcher));
+        // Custom actions only ever see valid values
+        if track_idx {
+            if let Some(&ArgAction::Custom(ref f)) = arg.action() {
+                try!(f(Some(v)));
+            }
+        }
+        Ok(needs_more)
     }
 
     fn validate_value<A>(&self,
                          arg: &A,
                          val: &OsStr,
-                         matcher: &ArgMatcher<'a>)
+                         matcher: &mut ArgMatcher<'a>)
                          -> ClapResult<Option<&'a str>>
         where A: AnyArg<'a, 'b> + Display
     {
@@ -1508,7 +1861,22 @@ impl<'a, 'b> Parser<'a, 'b>
         }
         if let Some(vtor) = arg.validator() {
             if let Err(e) = vtor(val.to_string_lossy().into_owned()) {
-                return Err(Error::value_validation(e, self.color()));
+                return Err(Error::value_validation(Some(arg),
+                                                   Some(&*val.to_string_lossy()),
+                                                   e,
+                                                   self.color()));
+            }
+        }
+        // Valid values are parsed once, and kept for ArgMatches::get_one
+        if let Some(parser) = arg.value_parser() {
+            match parser(val) {
+                Ok(parsed) => matcher.add_parsed_val_to(arg.name(), parsed),
+                Err(e) => {
+                    return Err(Error::value_validation(Some(arg),
+                                                       Some(&*val.to_string_lossy()),
+                                                       e,
+                                                       self.color()));
+                }
             }
         }
         if matcher.needs_more_vals(arg) {
@@ -1517,6 +1885,50 @@ impl<'a, 'b> Parser<'a, 'b>
         Ok(None)
     }
 
+    // Turns off a negatable flag, undoing the requirements, conflicts and group occurrences added
+    // by any earlier use of it
+    fn negate_flag(&mut self, name: &'a str, matcher: &mut ArgMatcher<'a>) {
+        debugln!("fn=negate_flag; name={}", name);
+        let (occurs, indices) = match matcher.get(name) {
+            Some(ma) => (ma.occurs, ma.indices.clone()),
+            None => (0, vec![]),
+        };
+        matcher.negate(name);
+        if occurs == 0 {
+            return;
+        }
+        if let Some(f) = self.flags.iter().find(|f| f.name == name) {
+            remove_overriden!(@arg self, f);
+        }
+        if let Some(grps) = self.groups_for_arg(name) {
+            for grp in grps {
+                let unused = match matcher.get_mut(grp) {
+                    Some(ma) => {
+                        ma.occurs = ma.occurs.saturating_sub(occurs);
+                        ma.indices.retain(|i| !indices.contains(i));
+                        ma.occurs == 0 && ma.vals.is_empty()
+                    }
+                    None => false,
+                };
+                if !unused {
+                    continue;
+                }
+                debugln!("Group '{}' is no longer used", grp);
+                matcher.remove(grp);
+                let g = &self.groups[grp];
+                if let Some(ref reqs) = g.requires {
+                    vec_remove_all!(self.required, reqs);
+                }
+                if let Some(ref bl) = g.conflicts {
+                    vec_remove_all!(self.blacklist, bl);
+                }
+                if !g.multiple {
+                    vec_remove_all!(self.blacklist, &g.args);
+                }
+            }
+        }
+    }
+
     fn parse_flag(&self,
                   flag: &FlagBuilder<'a, 'b>,
                   matcher: &mut ArgMatcher<'a>)
@@ -1524,13 +1936,58 @@ impl<'a, 'b> Parser<'a, 'b>
         debugln!("fn=parse_flag;");
         validate_multiples!(self, flag, matcher);
 
+        matcher.remove_negation(flag.name);
         matcher.inc_occurrence_of(flag.name);
+        matcher.add_index_to(flag.name, self.cur_idx.get());
         // Increment or create the group "args"
-        self.groups_for_arg(flag.name).and_then(|vec| Some(matcher.inc_occurrences_of(&*vec)));
+        if let Some(grps) = self.groups_for_arg(flag.name) {
+            matcher.inc_occurrences_of(&*grps);
+            for grp in grps {
+                matcher.add_index_to(grp, self.cur_idx.get());
+            }
+        }
+
+        match flag.action {
+            Some(ArgAction::SetTrue) => Self::set_flag_val(flag.name, "true", matcher),
+            Some(ArgAction::SetFalse) => Self::set_flag_val(flag.name, "false", matcher),
+            Some(ArgAction::Custom(ref f)) => try!(f(None)),
+            _ => (),
+        }
 
         Ok(())
     }
 
+    // SetTrue and SetFalse flags have a single value, no matter how often they're used
+    fn set_flag_val(name: &'a str, val: &str, matcher: &mut ArgMatcher<'a>) {
+        if matcher.get(name).map_or(true, |ma| ma.vals.is_empty()) {
+            matcher.add_val_to(name, OsStr::new(val));
+        }
+    }
+
+    // SetTrue and SetFalse flags which weren't used get the opposite of the value they set
+    fn add_flag_vals(&self, matcher: &mut ArgMatcher<'a>) {
+        debugln!("fn=add_flag_vals;");
+        matcher.set_source(ValueSource::DefaultValue);
+        for f in &self.flags {
+            let val = match f.action {
+                Some(ArgAction::SetTrue) => "false",
+                Some(ArgAction::SetFalse) => "true",
+                _ => continue,
+            };
+            if !matcher.contains(f.name) {
+                debugln!("Adding value for unused flag {}...{:?}", f.name, val);
+                matcher.insert(f.name);
+                {
+                    let ma = matcher.get_mut(f.name).expect(INTERNAL_ERROR_MSG);
+                    ma.occurs = 0;
+                    ma.implicit = true;
+                }
+                Self::set_flag_val(f.name, val, matcher);
+            }
+        }
+        matcher.set_source(ValueSource::CommandLine);
+    }
+
     fn validate_blacklist(&self, matcher: &mut ArgMatcher) -> ClapResult<()> {
         debugln!("fn=validate_blacklist;blacklist={:?}", self.blacklist);
         macro_rules! build_err {
@@ -1584,12 +2041,12 @@ impl<'a, 'b> Parser<'a, 'b>
                 debugln!("groups contains it...");
                 for n in self.arg_names_in_group(name) {
                     debugln!("Checking arg '{}' in group...", n);
-                    if matcher.contains(n) {
+                    if self.is_used(n, matcher) {
                         debugln!("matcher contains it...");
                         return Err(build_err!(self, &n, matcher));
                     }
                 }
-            } else if matcher.contains(name) {
+            } else if self.is_used(name, matcher) {
                 debugln!("matcher contains it...");
                 return Err(build_err!(self, name, matcher));
             }
@@ -1597,6 +2054,17 @@ impl<'a, 'b> Parser<'a, 'b>
         Ok(())
     }
 
+    // Whether an arg was matched, as far as conflicts are concerned
+    fn is_used(&self, name: &str, matcher: &ArgMatcher) -> bool {
+        match matcher.get(name) {
+            Some(ma) => {
+                ma.source != ValueSource::DefaultValue ||
+                !self.is_set(AppSettings::IgnoreDefaultsInRules)
+            }
+            None => false,
+        }
+    }
+
     fn validate_num_args(&self, matcher: &mut ArgMatcher) -> ClapResult<()> {
         debugln!("fn=validate_num_args;");
         for (name, ma) in matcher.iter() {
@@ -1678,7 +2146,7 @@ impl<'a, 'b> Parser<'a, 'b>
         Ok(())
     }
 
-    fn validate_required(&self, matcher: &ArgMatcher) -> ClapResult<()> {
+    fn validate_required(&self, matcher: &mut ArgMatcher<'a>) -> ClapResult<()> {
         'outer: for name in &self.required {
             if matcher.contains(name) {
                 continue 'outer;
@@ -1698,34 +2166,131 @@ impl<'a, 'b> Parser<'a, 'b>
                     continue 'outer;
                 }
             } else if let Some(a) = self.opts.iter().find(|o| &o.name == name) {
-                if self.is_missing_required_ok(a, matcher) {
+                if self.is_missing_required_ok(a, matcher) || try!(self.prompt_for(a, matcher)) {
                     continue 'outer;
                 }
             } else if let Some(a) = self.positionals.values().find(|p| &p.name == name) {
-                if self.is_missing_required_ok(a, matcher) {
+                if self.is_missing_required_ok(a, matcher) || try!(self.prompt_for(a, matcher)) {
                     continue 'outer;
                 }
             }
             let err =
                 if self.settings.is_set(AppSettings::ArgRequiredElseHelp) && matcher.is_empty() {
-                    self._help().unwrap_err()
+                    self._help(false).unwrap_err()
                 } else {
                     let mut reqs = self.required.iter().map(|&r| &*r).collect::<Vec<_>>();
                     reqs.retain(|n| !matcher.contains(n));
                     reqs.dedup();
+                    let missing = self.get_required_from(&*reqs, Some(matcher));
                     Error::missing_required_argument(
-                &*self.get_required_from(&*reqs, Some(matcher))
-                      .iter()
+                &*missing.iter()
                       .fold(String::new(),
                           |acc, s| acc + &format!("\n    {}", Format::Error(s))[..]),
+                missing.iter().cloned().collect(),
                 &*self.create_current_usage(matcher),
                 self.color())
                 };
             return Err(err);
         }
+        self.validate_conditional_reqs(matcher)
+    }
+
+    // Asks for the value of a missing required arg with a prompt, returning whether it got one
+    fn prompt_for<A>(&self, arg: &A, matcher: &mut ArgMatcher<'a>) -> ClapResult<bool>
+        where A: AnyArg<'a, 'b> + Display
+    {
+        let text = match arg.prompt() {
+            Some(text) => text,
+            None => return Ok(false),
+        };
+        let val = match try!(prompt::ask(self.meta.prompt_streams.as_ref(),
+                                         text,
+                                         arg.possible_vals(),
+                                         arg.is_set(ArgSettings::HideInput))) {
+            Some(val) => val,
+            None => return Ok(false),
+        };
+        debugln!("Prompted for '{}', got a value", arg.name());
+        matcher.set_source(ValueSource::Prompt);
+        matcher.inc_occurrence_of(arg.name());
+        try!(self.add_val_to_arg(arg, OsStr::new(&val), matcher, false));
+        matcher.set_source(ValueSource::CommandLine);
+        Ok(true)
+    }
+
+    // Checks the value-conditioned requirements, i.e. Arg::required_if and Arg::requires_if
+    fn validate_conditional_reqs(&self, matcher: &ArgMatcher) -> ClapResult<()> {
+        debugln!("fn=validate_conditional_reqs;");
+        macro_rules! has_val {
+            ($name:expr, $val:expr) => {
+                matcher.get($name).map_or(false, |ma| ma.vals.values().any(|v| *v == *$val))
+            };
+        }
+        macro_rules! check_reqs {
+            ($a:ident) => {
+                if let Some(ref r_ifs) = $a.r_ifs {
+                    if !matcher.contains($a.name) {
+                        for &(other, val) in r_ifs {
+                            if has_val!(other, val) {
+                                return Err(self.missing_conditional_req($a.name,
+                                                                        other,
+                                                                        val,
+                                                                        matcher));
+                            }
+                        }
+                    }
+                }
+                if let Some(ref req_ifs) = $a.requires_ifs {
+                    for &(val, other) in req_ifs {
+                        if has_val!($a.name, val) && !self.is_present_or_grp(other, matcher) {
+                            return Err(self.missing_conditional_req(other, $a.name, val, matcher));
+                        }
+                    }
+                }
+            };
+        }
+        for o in &self.opts {
+            check_reqs!(o);
+        }
+        for p in self.positionals.values() {
+            check_reqs!(p);
+        }
         Ok(())
     }
 
+    fn is_present_or_grp(&self, name: &str, matcher: &ArgMatcher) -> bool {
+        matcher.contains(name) ||
+        self.groups.get(name).map_or(false, |g| g.args.iter().any(|a| matcher.contains(a)))
+    }
+
+    // Builds the error for an argument (`req`) which became required because `cond` was used with
+    // the value `val`
+    fn missing_conditional_req(&self,
+                               req: &str,
+                               cond: &str,
+                               val: &str,
+                               matcher: &ArgMatcher)
+                               -> Error {
+        debugln!("fn=missing_conditional_req;req={};cond={};val={}", req, cond, val);
+        let req_s = self.find_any_arg(req).map_or(req.to_owned(), |a| a.to_string());
+        let cond_s = self.find_any_arg(cond).map_or(cond.to_owned(), |a| {
+            if let Some(l) = a.long() {
+                format!("--{}", l)
+            } else if let Some(s) = a.short() {
+                format!("-{}", s)
+            } else {
+                a.to_string()
+            }
+        });
+        Error::missing_required_argument(format!("\n    {} (required because {} is '{}')",
+                                                 Format::Error(&*req_s),
+                                                 cond_s,
+                                                 val),
+                                         vec![req_s.clone()],
+                                         &*self.create_current_usage(matcher),
+                                         self.color())
+    }
+
     fn is_missing_required_ok<A>(&self, a: &A, matcher: &ArgMatcher) -> bool
         where A: AnyArg<'a, 'b>
     {
@@ -1784,7 +2349,7 @@ impl<'a, 'b> Parser<'a, 'b>
 
         let used_arg = format!("--{}", arg);
         Err(Error::unknown_argument(&*used_arg,
-                                    &*suffix.0,
+                                    suffix.1,
                                     &*self.create_current_usage(matcher),
                                     self.color()))
     }
@@ -1817,7 +2382,13 @@ impl<'a, 'b> Parser<'a, 'b>
                     .bin_name
                     .as_ref()
                     .unwrap_or(&self.meta.name)));
-            let mut reqs: Vec<&str> = self.required().map(|r| &**r).collect();
+            // A required .last(true) positional is placed after all other positionals below
+            let mut reqs: Vec<&str> = self.required()
+                .map(|r| &**r)
+                .filter(|r| {
+                    !self.positionals.values().any(|p| &p.name == r && p.is_set(ArgSettings::Last))
+                })
+                .collect();
             reqs.dedup();
             let req_string = self.get_required_from(&reqs, None)
                 .iter()
@@ -1837,21 +2408,36 @@ impl<'a, 'b> Parser<'a, 'b>
             usage.push_str(&req_string[..]);
 
             // places a '--' in the usage string if there are args and options
-            // supporting multiple values
+            // supporting multiple values (which aren't ended by a value terminator)
             if self.has_positionals() &&
-               self.opts.iter().any(|a| a.settings.is_set(ArgSettings::Multiple)) &&
-               self.positionals.values().any(|a| !a.settings.is_set(ArgSettings::Required)) &&
+               self.opts
+                   .iter()
+                   .any(|a| a.settings.is_set(ArgSettings::Multiple) && a.terminator.is_none()) &&
+               self.positionals.values().any(|a| {
+                   !a.is_set(ArgSettings::Required) && !a.is_set(ArgSettings::Last)
+               }) &&
                !self.has_subcommands() {
                 usage.push_str(" [--]")
             }
             if self.has_positionals() &&
-               self.positionals.values().any(|a| !a.settings.is_set(ArgSettings::Required)) {
+               self.positionals.values().any(|a| {
+                   !a.is_set(ArgSettings::Required) && !a.is_set(ArgSettings::Last)
+               }) {
                 if let Some(args_tag) = self.get_args_tag() {
                     usage.push_str(&*args_tag);
                 } else {
                     usage.push_str(" [ARGS]");
                 }
             }
+            // A .last(true) positional can only be supplied after '--', so it's always shown
+            // after the other positionals
+            if let Some(p) = self.positionals.values().find(|p| p.is_set(ArgSettings::Last)) {
+                if p.is_set(ArgSettings::Required) {
+                    usage.push_str(&*format!(" -- {}", p));
+                } else {
+                    usage.push_str(&*format!(" [-- {}]", p));
+                }
+            }
 
 
             if self.has_subcommands() && !self.is_set(AppSettings::SubcommandRequired) {
@@ -1859,6 +2445,9 @@ impl<'a, 'b> Parser<'a, 'b>
             } else if self.is_set(AppSettings::SubcommandRequired) && self.has_subcommands() {
                 usage.push_str(" <SUBCOMMAND>");
             }
+            if self.has_subcommands() && self.is_set(AppSettings::SubcommandsChain) {
+                usage.push_str("...");
+            }
         } else {
             self.smart_usage(&mut usage, used);
         }
@@ -1889,6 +2478,9 @@ impl<'a, 'b> Parser<'a, 'b>
         usage.push_str(&*r_string);
         if self.is_set(AppSettings::SubcommandRequired) {
             usage.push_str(" <SUBCOMMAND>");
+            if self.is_set(AppSettings::SubcommandsChain) {
+                usage.push_str("...");
+            }
         }
     }
 
@@ -1927,7 +2519,7 @@ impl<'a, 'b> Parser<'a, 'b>
     }
 
     pub fn write_help<W: Write>(&self, w: &mut W) -> ClapResult<()> {
-        Help::write_parser_help(w, self)
+        Help::write_parser_help(w, self, false)
     }
 
     pub fn write_help_err<W: Write>(&self, w: &mut W) -> ClapResult<()> {
@@ -1938,19 +2530,203 @@ impl<'a, 'b> Parser<'a, 'b>
         macro_rules! add_val {
             ($_self:ident, $a:ident, $m:ident) => {
                 if $m.get($a.name).is_none() {
-                    try!($_self.add_val_to_arg($a, OsStr::new($a.default_val
-                                                                .as_ref()
-                                                                .unwrap()), $m));
-                    arg_post_processing!($_self, $a, $m);
+                    // Conditional defaults are evaluated in order, the first match wins. Both
+                    // kinds of condition share their keys, so the lowest key met wins.
+                    let mut default: Option<(usize, &str)> = None;
+                    if let Some(ref vm) = $a.default_vals_ifs {
+                        default = vm.iter()
+                            .find(|&(_, &(arg, val, _))| match $m.get(arg) {
+                                Some(ma) => {
+                                    val.map_or(true, |v| ma.vals.values().any(|mv| *mv == *v))
+                                }
+                                None => false,
+                            })
+                            .map(|(i, &(_, _, def))| (i, def));
+                    }
+                    if let Some(ref vm) = $a.default_vals_if_fns {
+                        let found = vm.iter()
+                            .find(|&(_, &(arg, ref f, _))| {
+                                $m.get(arg).map_or(false, |ma| ma.vals.values().any(|mv| f(mv)))
+                            })
+                            .map(|(i, &(_, _, def))| (i, def));
+                        if let Some((i, def)) = found {
+                            if default.map_or(true, |(j, _)| i < j) {
+                                default = Some((i, def));
+                            }
+                        }
+                    }
+                    if let Some(val) = default.map(|(_, def)| def).or($a.default_val) {
+                        debugln!("Found default for {}...{}", $a.name, val);
+                        try!($_self.add_val_to_arg($a, OsStr::new(val), $m, false));
+                        if !$_self.is_set(AppSettings::IgnoreDefaultsInRules) {
+                            arg_post_processing!($_self, $a, $m);
+                        }
+                    }
+                }
+            };
+        }
+        matcher.set_source(ValueSource::DefaultValue);
+        for o in self.opts
+            .iter()
+            .filter(|o| {
+                o.default_val.is_some() || o.default_vals_ifs.is_some() ||
+                o.default_vals_if_fns.is_some()
+            }) {
+            add_val!(self, o, matcher);
+        }
+        for p in self.positionals
+            .values()
+            .filter(|p| {
+                p.default_val.is_some() || p.default_vals_ifs.is_some() ||
+                p.default_vals_if_fns.is_some()
+            }) {
+            add_val!(self, p, matcher);
+        }
+        matcher.set_source(ValueSource::CommandLine);
+        Ok(())
+    }
+
+    // Options which were used without a value get their "missing value" default
+    fn add_missing_vals(&mut self, matcher: &mut ArgMatcher<'a>) -> ClapResult<()> {
+        debugln!("fn=add_missing_vals;");
+        for o in self.opts.iter().filter(|o| o.default_missing_val.is_some()) {
+            if matcher.get(o.name).map_or(false, |ma| ma.vals.is_empty()) {
+                let val = o.default_missing_val.unwrap();
+                debugln!("Adding missing value for {}...{:?}", o.name, val);
+                try!(self.add_val_to_arg(o, OsStr::new(val), matcher, false));
+            }
+        }
+        Ok(())
+    }
+
+    fn add_env(&mut self, matcher: &mut ArgMatcher<'a>) -> ClapResult<()> {
+        macro_rules! add_val {
+            ($_self:ident, $a:ident, $m:ident) => {
+                if let Some(&(_, Some(ref val))) = $a.env.as_ref() {
+                    if $m.get($a.name).is_none() {
+                        debugln!("Adding env value for {}...{:?}", $a.name, val);
+                        try!($_self.add_val_to_arg($a, OsStr::new(val), $m, false));
+                        arg_post_processing!($_self, $a, $m);
+                    }
                 }
             };
         }
-        for o in self.opts.iter().filter(|o| o.default_val.is_some()) {
+        matcher.set_source(ValueSource::EnvVariable);
+        for o in self.opts.iter().filter(|o| o.env.is_some()) {
             add_val!(self, o, matcher);
         }
-        for p in self.positionals.values().filter(|p| p.default_val.is_some()) {
+        for p in self.positionals.values().filter(|p| p.env.is_some()) {
             add_val!(self, p, matcher);
         }
+        matcher.set_source(ValueSource::CommandLine);
+        Ok(())
+    }
+
+    #[cfg(feature = "config")]
+    fn add_config(&mut self, matcher: &mut ArgMatcher<'a>) -> ClapResult<()> {
+        debugln!("fn=add_config;");
+        let mut table = self.config.take().unwrap_or_default();
+        for path in &self.meta.config_files {
+            if let Some(layer) = try!(config::read(path, self.color())) {
+                table.merge(layer);
+            }
+        }
+        matcher.set_source(ValueSource::ConfigFile);
+        for (key, vals) in table.values() {
+            // The command line and the environment come first
+            if matcher.contains(key) || matcher.0.negated.iter().any(|n| n == key) {
+                continue;
+            }
+            if let Some(f) = self.flags.iter().find(|f| f.name == key) {
+                debugln!("Adding config value for flag {}...{:?}", f.name, vals);
+                try!(self.add_config_flag(f, vals, matcher));
+                if matcher.contains(f.name) {
+                    arg_post_processing!(self, f, matcher);
+                }
+            } else if let Some(o) = self.opts.iter().find(|o| o.name == key) {
+                debugln!("Adding config values for {}...{:?}", o.name, vals);
+                for val in vals {
+                    try!(self.add_val_to_arg(o, OsStr::new(val), matcher, false));
+                }
+                arg_post_processing!(self, o, matcher);
+            } else if let Some(p) = self.positionals.values().find(|p| p.name == key) {
+                debugln!("Adding config values for {}...{:?}", p.name, vals);
+                for val in vals {
+                    try!(self.add_val_to_arg(p, OsStr::new(val), matcher, false));
+                }
+                arg_post_processing!(self, p, matcher);
+            } else {
+                debugln!("No arg named {}, ignoring its config value", key);
+            }
+        }
+        matcher.set_source(ValueSource::CommandLine);
+        self.config = Some(table);
+        Ok(())
+    }
+
+    #[cfg(not(feature = "config"))]
+    fn add_config(&mut self, _: &mut ArgMatcher<'a>) -> ClapResult<()> {
+        Ok(())
+    }
+
+    // A flag's config value is either a boolean or how many times it occurs
+    #[cfg(feature = "config")]
+    fn add_config_flag(&self,
+                       flag: &FlagBuilder<'a, 'b>,
+                       vals: &[String],
+                       matcher: &mut ArgMatcher<'a>)
+                       -> ClapResult<()> {
+        if vals.len() != 1 {
+            return Err(Error::value_validation(Some(flag),
+                                               None,
+                                               format!("Only one config value is allowed for \
+                                                        '{}'",
+                                                       flag),
+                                               self.color()));
+        }
+        let val = &*vals[0];
+        let occurs = match val {
+            "true" => 1,
+            "false" => {
+                // Config values are only added for args which weren't used, so unlike on the
+                // command line there's nothing for the negation to undo
+                if flag.is_set(ArgSettings::Negatable) {
+                    matcher.negate(flag.name);
+                }
+                0
+            }
+            _ => match val.parse::<u64>() {
+                Ok(n) => n,
+                Err(_) => {
+                    return Err(Error::value_validation(Some(flag),
+                                                       Some(val),
+                                                       format!("Invalid config value '{}' for \
+                                                                '{}': expected true, false or a \
+                                                                number of occurrences",
+                                                               val,
+                                                               flag),
+                                                       self.color()))
+                }
+            },
+        };
+        if occurs > 1 && !flag.is_set(ArgSettings::Multiple) && flag.action.is_none() {
+            return Err(Error::unexpected_multiple_usage(flag,
+                                                        &*self.create_current_usage(matcher),
+                                                        self.color()));
+        }
+        for _ in 0..occurs {
+            matcher.inc_occurrence_of(flag.name);
+            if let Some(grps) = self.groups_for_arg(flag.name) {
+                matcher.inc_occurrences_of(&*grps);
+            }
+        }
+        if occurs > 0 {
+            match flag.action {
+                Some(ArgAction::SetTrue) => Self::set_flag_val(flag.name, "true", matcher),
+                Some(ArgAction::SetFalse) => Self::set_flag_val(flag.name, "false", matcher),
+                _ => (),
+            }
+        }
         Ok(())
     }
 
@@ -1970,6 +2746,24 @@ impl<'a, 'b> Parser<'a, 'b>
         self.subcommands.iter()
     }
 
+    // The name of the arg or group called `name`, which lives as long as the parser's args
+    #[cfg(feature = "serde")]
+    pub fn arg_or_group_name(&self, name: &str) -> Option<&'a str> {
+        self.flags
+            .iter()
+            .map(|f| f.name)
+            .chain(self.opts.iter().map(|o| o.name))
+            .chain(self.positionals.values().map(|p| p.name))
+            .chain(self.groups.keys().cloned())
+            .chain(self.global_args.iter().map(|a| a.name))
+            .find(|&n| n == name)
+    }
+
+    #[cfg(feature = "serde")]
+    pub fn global_arg_names(&self) -> Vec<&'a str> {
+        self.global_args.iter().map(|a| a.name).collect()
+    }
+
     // Should we color the output? None=determined by output location, true=yes, false=no
     #[doc(hidden)]
     pub fn color(&self) -> ColorWhen {
@@ -2075,7 +2869,12 @@ impl<'a, 'b> Clone for Parser<'a, 'b>
             settings: self.settings.clone(),
             g_settings: self.g_settings.clone(),
             meta: self.meta.clone(),
+            cur_idx: Cell::new(self.cur_idx.get()),
             trailing_vals: self.trailing_vals,
+            chain_siblings: self.chain_siblings.clone(),
+            chained_next: self.chained_next.clone(),
+            #[cfg(feature = "config")]
+            config: self.config.clone(),
         }
     }
 }
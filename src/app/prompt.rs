@@ -0,0 +1,149 @@
+// Std
+use std::cell::RefCell;
+use std::io::{self, BufRead, Write};
+use std::rc::Rc;
+
+// Internal
+use fmt;
+
+#[cfg(all(feature = "color", unix))]
+use libc;
+
+// The streams used to prompt instead of stdin and stderr, set with App::prompt_streams
+#[doc(hidden)]
+#[allow(missing_debug_implementations)]
+pub struct PromptStreams {
+    pub input: Box<BufRead>,
+    pub output: Box<Write>,
+}
+
+// Asks the user for a value, returning `None` when there is no one to ask (stdin isn't a terminal
+// and no streams were given), or the input ends before a valid answer
+pub fn ask(streams: Option<&Rc<RefCell<PromptStreams>>>,
+           text: &str,
+           choices: Option<&[&str]>,
+           hidden: bool)
+           -> io::Result<Option<String>> {
+    debugln!("fn=prompt::ask; text={}", text);
+    if let Some(streams) = streams {
+        let mut streams = streams.borrow_mut();
+        let PromptStreams { ref mut input, ref mut output } = *streams;
+        // Given streams are never echoed, so there's nothing to hide
+        return ask_with(&mut **input, &mut **output, text, choices, false);
+    }
+    if !fmt::is_a_tty_stdin() {
+        debugln!("stdin isn't a terminal, not prompting");
+        return Ok(None);
+    }
+    let stdin = io::stdin();
+    let stderr = io::stderr();
+    let mut input = stdin.lock();
+    let mut output = stderr.lock();
+    ask_with(&mut input, &mut output, text, choices, hidden)
+}
+
+fn ask_with(input: &mut BufRead,
+            output: &mut Write,
+            text: &str,
+            choices: Option<&[&str]>,
+            hide_echo: bool)
+            -> io::Result<Option<String>> {
+    if let Some(choices) = choices {
+        try!(writeln!(output, "{}:", text));
+        for (i, c) in choices.iter().enumerate() {
+            try!(writeln!(output, "  {}) {}", i + 1, c));
+        }
+    }
+    loop {
+        match choices {
+            Some(choices) => try!(write!(output, "Select 1-{}: ", choices.len())),
+            None => try!(write!(output, "{}: ", text)),
+        }
+        try!(output.flush());
+        let line = match try!(read_line(input, hide_echo)) {
+            Some(line) => line,
+            None => return Ok(None),
+        };
+        if hide_echo {
+            // The user's newline wasn't echoed either
+            try!(writeln!(output, ""));
+        }
+        let answer = line.trim_right_matches(|c| c == '\n' || c == '\r');
+        if answer.is_empty() {
+            continue;
+        }
+        let choices = match choices {
+            Some(choices) => choices,
+            None => return Ok(Some(answer.to_owned())),
+        };
+        let choice = answer.parse::<usize>()
+            .ok()
+            .and_then(|n| if n > 0 { choices.get(n - 1) } else { None })
+            .or_else(|| choices.iter().find(|&&c| c == answer));
+        if let Some(c) = choice {
+            return Ok(Some((*c).to_owned()));
+        }
+        try!(writeln!(output, "Invalid choice '{}'", answer));
+    }
+}
+
+fn read_line(input: &mut BufRead, hide_echo: bool) -> io::Result<Option<String>> {
+    let _echo_off = if hide_echo {
+        // Better to fail than to show what was meant to be hidden
+        match EchoOff::new() {
+            Some(e) => Some(e),
+            None => {
+                return Err(io::Error::new(io::ErrorKind::Other,
+                                          "the input can't be hidden on this terminal"))
+            }
+        }
+    } else {
+        None
+    };
+    let mut line = String::new();
+    if try!(input.read_line(&mut line)) == 0 {
+        return Ok(None);
+    }
+    Ok(Some(line))
+}
+
+// Turns off the terminal's echo of stdin for as long as it lives
+#[cfg(all(feature = "color", unix))]
+struct EchoOff(libc::termios);
+
+#[cfg(all(feature = "color", unix))]
+impl EchoOff {
+    fn new() -> Option<Self> {
+        unsafe {
+            let mut term: libc::termios = ::std::mem::zeroed();
+            if libc::tcgetattr(libc::STDIN_FILENO, &mut term) != 0 {
+                return None;
+            }
+            let orig = term;
+            term.c_lflag &= !libc::ECHO;
+            if libc::tcsetattr(libc::STDIN_FILENO, libc::TCSANOW, &term) != 0 {
+                return None;
+            }
+            Some(EchoOff(orig))
+        }
+    }
+}
+
+#[cfg(all(feature = "color", unix))]
+impl Drop for EchoOff {
+    fn drop(&mut self) {
+        unsafe {
+            libc::tcsetattr(libc::STDIN_FILENO, libc::TCSANOW, &self.0);
+        }
+    }
+}
+
+#[cfg(not(all(feature = "color", unix)))]
+struct EchoOff;
+
+#[cfg(not(all(feature = "color", unix)))]
+impl EchoOff {
+    fn new() -> Option<Self> {
+        None
+    }
+}
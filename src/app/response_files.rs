@@ -0,0 +1,174 @@
+// Std
+use std::ffi::OsString;
+use std::fs::File;
+use std::io::Read;
+use std::mem;
+
+// Internal
+use errors::Error;
+use errors::Result as ClapResult;
+use fmt::ColorWhen;
+use osstringext::OsStrExt2;
+
+// Response files may include other response files, but only this deep. This also catches
+// response files which (directly or indirectly) include themselves
+const MAX_DEPTH: usize = 10;
+
+// Replaces each `@path` argument with the arguments contained in the file at `path`, and each
+// `@@arg` with the literal `@arg`. Everything after the first `--`, whether it's on the command
+// line or in a response file, is passed through untouched
+pub fn expand<I, T>(args: I, color: ColorWhen) -> ClapResult<Vec<OsString>>
+    where I: IntoIterator<Item = T>,
+          T: Into<OsString>
+{
+    debugln!("fn=response_files::expand;");
+    let mut expanded = vec![];
+    let mut trailing = false;
+    for arg in args {
+        try!(expand_arg(arg.into(), 0, &mut expanded, &mut trailing, color));
+    }
+    Ok(expanded)
+}
+
+fn expand_arg(arg: OsString,
+              depth: usize,
+              expanded: &mut Vec<OsString>,
+              trailing: &mut bool,
+              color: ColorWhen)
+              -> ClapResult<()> {
+    if *trailing {
+        expanded.push(arg);
+        return Ok(());
+    }
+    if arg == "--" {
+        debugln!("Found '--', not expanding any further arguments");
+        *trailing = true;
+        expanded.push(arg);
+        return Ok(());
+    }
+    // A lone '@' is just a value
+    if !arg.starts_with(b"@") || arg.len_() == 1 {
+        expanded.push(arg);
+        return Ok(());
+    }
+    let (_, path) = arg.split_at(1);
+    if path.starts_with(b"@") {
+        debugln!("Unescaping {:?}", arg);
+        expanded.push(path.to_os_string());
+        return Ok(());
+    }
+
+    debugln!("Expanding response file {:?} at depth {}", path, depth);
+    let name = path.to_string_lossy().into_owned();
+    if depth >= MAX_DEPTH {
+        return Err(Error::response_file(name,
+                                        format!("response files may only be nested {} deep",
+                                                MAX_DEPTH),
+                                        color));
+    }
+    let mut contents = String::new();
+    if let Err(e) = File::open(path).and_then(|mut f| f.read_to_string(&mut contents)) {
+        return Err(Error::response_file(name, e, color));
+    }
+    let args = match split(&contents) {
+        Ok(args) => args,
+        Err(e) => return Err(Error::response_file(name, e, color)),
+    };
+    for a in args {
+        try!(expand_arg(a.into(), depth + 1, expanded, trailing, color));
+    }
+    Ok(())
+}
+
+// Splits the contents of a response file into arguments using shell like quoting rules
+fn split(contents: &str) -> Result<Vec<String>, &'static str> {
+    let mut args = vec![];
+    let mut cur = String::new();
+    // Tracks whether anything was seen for the current arg, so that '' and "" are empty args
+    let mut in_arg = false;
+    let mut chars = contents.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\'' => {
+                in_arg = true;
+                loop {
+                    match chars.next() {
+                        Some('\'') => break,
+                        Some(c) => cur.push(c),
+                        None => return Err("unterminated single quote"),
+                    }
+                }
+            }
+            '"' => {
+                in_arg = true;
+                loop {
+                    match chars.next() {
+                        Some('"') => break,
+                        Some('\\') => {
+                            match chars.next() {
+                                Some(c) if c == '"' || c == '\\' => cur.push(c),
+                                Some(c) => {
+                                    cur.push('\\');
+                                    cur.push(c);
+                                }
+                                None => return Err("unterminated double quote"),
+                            }
+                        }
+                        Some(c) => cur.push(c),
+                        None => return Err("unterminated double quote"),
+                    }
+                }
+            }
+            '\\' => {
+                in_arg = true;
+                match chars.next() {
+                    Some(c) => cur.push(c),
+                    None => return Err("trailing backslash"),
+                }
+            }
+            c if c.is_whitespace() => {
+                if in_arg {
+                    args.push(mem::replace(&mut cur, String::new()));
+                    in_arg = false;
+                }
+            }
+            c => {
+                in_arg = true;
+                cur.push(c);
+            }
+        }
+    }
+    if in_arg {
+        args.push(cur);
+    }
+    Ok(args)
+}
+
+#[cfg(test)]
+mod test {
+    use super::split;
+
+    #[test]
+    fn split_whitespace() {
+        assert_eq!(split("  -a\tfoo\n\n--bar=baz \r\n").unwrap(),
+                   ["-a", "foo", "--bar=baz"]);
+    }
+
+    #[test]
+    fn split_quotes() {
+        assert_eq!(split(r#"'a b' "c \"d\" \e" f'g'"h" '' """#).unwrap(),
+                   ["a b", r#"c "d" \e"#, "fgh", "", ""]);
+    }
+
+    #[test]
+    fn split_backslash() {
+        assert_eq!(split(r"a\ b \'c \\").unwrap(), ["a b", "'c", "\\"]);
+    }
+
+    #[test]
+    fn split_unterminated() {
+        assert!(split("'a").is_err());
+        assert!(split("\"a").is_err());
+        assert!(split("a\\").is_err());
+    }
+}
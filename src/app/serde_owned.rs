@@ -0,0 +1,85 @@
+// Owned counterparts of ArgMatches and SubCommand, in the same serialized format, for input which
+// the argument names can't be borrowed from (readers, or names with escapes). The names are then
+// taken from the App's args instead
+
+// Std
+use std::collections::HashMap;
+
+// Third Party
+use serde::Deserialize;
+use serde::de::Error;
+
+// Internal
+use app::parser::Parser;
+use args::{ArgMatches, MatchedArg, SubCommand};
+
+#[derive(Debug, Deserialize)]
+pub struct OwnedArgMatches {
+    args: HashMap<String, MatchedArg>,
+    subcommand: Option<Box<OwnedSubCommand>>,
+    chained: Vec<OwnedSubCommand>,
+    usage: Option<String>,
+    negated: Vec<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct OwnedSubCommand {
+    name: String,
+    matches: OwnedArgMatches,
+}
+
+impl OwnedArgMatches {
+    // Global args are only added to subcommands while parsing, so `globals` are those of the
+    // parents of `p`
+    pub fn into_matches<'a, 'b, E: Error>(self,
+                                          p: &Parser<'a, 'b>,
+                                          globals: &[&'a str])
+                                          -> Result<ArgMatches<'a>, E> {
+        let find_name = |name: &str| -> Result<&'a str, E> {
+            p.arg_or_group_name(name)
+                .or_else(|| globals.iter().find(|&&g| g == name).cloned())
+                .ok_or_else(|| E::custom(format!("no argument or group named '{}'", name)))
+        };
+        let mut args = HashMap::with_capacity(self.args.len());
+        for (name, ma) in self.args {
+            args.insert(try!(find_name(&*name)), ma);
+        }
+        let mut negated = Vec::with_capacity(self.negated.len());
+        for name in &self.negated {
+            negated.push(try!(find_name(&**name)));
+        }
+        let mut sc_globals = globals.to_vec();
+        sc_globals.extend(p.global_arg_names());
+        let subcommand = match self.subcommand {
+            Some(sc) => Some(Box::new(try!(sc.into_subcommand(p, &*sc_globals)))),
+            None => None,
+        };
+        let mut chained = Vec::with_capacity(self.chained.len());
+        for sc in self.chained {
+            chained.push(try!(sc.into_subcommand(p, &*sc_globals)));
+        }
+        Ok(ArgMatches {
+            args: args,
+            subcommand: subcommand,
+            chained: chained,
+            usage: self.usage,
+            negated: negated,
+        })
+    }
+}
+
+impl OwnedSubCommand {
+    fn into_subcommand<'a, 'b, E: Error>(self,
+                                         parent: &Parser<'a, 'b>,
+                                         globals: &[&'a str])
+                                         -> Result<SubCommand<'a>, E> {
+        let sc = match parent.subcommands.iter().find(|s| s.p.meta.name == self.name) {
+            Some(sc) => sc,
+            None => return Err(E::custom(format!("no subcommand named '{}'", self.name))),
+        };
+        Ok(SubCommand {
+            matches: try!(self.matches.into_matches(&sc.p, globals)),
+            name: self.name,
+        })
+    }
+}
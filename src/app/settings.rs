@@ -3,36 +3,41 @@ use std::ascii::AsciiExt;
 use std::str::FromStr;
 
 bitflags! {
-    flags Flags: u32 {
-        const SC_NEGATE_REQS       = 0b00000000000000000000000000001,
-        const SC_REQUIRED          = 0b00000000000000000000000000010,
-        const A_REQUIRED_ELSE_HELP = 0b00000000000000000000000000100,
-        const GLOBAL_VERSION       = 0b00000000000000000000000001000,
-        const VERSIONLESS_SC       = 0b00000000000000000000000010000,
-        const UNIFIED_HELP         = 0b00000000000000000000000100000,
-        const WAIT_ON_ERROR        = 0b00000000000000000000001000000,
-        const SC_REQUIRED_ELSE_HELP= 0b00000000000000000000010000000,
-        const NEEDS_LONG_HELP      = 0b00000000000000000000100000000,
-        const NEEDS_LONG_VERSION   = 0b00000000000000000001000000000,
-        const NEEDS_SC_HELP        = 0b00000000000000000010000000000,
-        const DISABLE_VERSION      = 0b00000000000000000100000000000,
-        const HIDDEN               = 0b00000000000000001000000000000,
-        const TRAILING_VARARG      = 0b00000000000000010000000000000,
-        const NO_BIN_NAME          = 0b00000000000000100000000000000,
-        const ALLOW_UNK_SC         = 0b00000000000001000000000000000,
-        const UTF8_STRICT          = 0b00000000000010000000000000000,
-        const UTF8_NONE            = 0b00000000000100000000000000000,
-        const LEADING_HYPHEN       = 0b00000000001000000000000000000,
-        const NO_POS_VALUES        = 0b00000000010000000000000000000,
-        const NEXT_LINE_HELP       = 0b00000000100000000000000000000,
-        const DERIVE_DISP_ORDER    = 0b00000001000000000000000000000,
-        const COLORED_HELP         = 0b00000010000000000000000000000,
-        const COLOR_ALWAYS         = 0b00000100000000000000000000000,
-        const COLOR_AUTO           = 0b00001000000000000000000000000,
-        const COLOR_NEVER          = 0b00010000000000000000000000000,
-        const DONT_DELIM_TRAIL     = 0b00100000000000000000000000000,
-        const ALLOW_NEG_NUMS       = 0b01000000000000000000000000000,
-        const LOW_INDEX_MUL_POS    = 0b10000000000000000000000000000,
+    flags Flags: u64 {
+        const SC_NEGATE_REQS       = 0b0000000000000000000000000000000000000001,
+        const SC_REQUIRED          = 0b0000000000000000000000000000000000000010,
+        const A_REQUIRED_ELSE_HELP = 0b0000000000000000000000000000000000000100,
+        const GLOBAL_VERSION       = 0b0000000000000000000000000000000000001000,
+        const VERSIONLESS_SC       = 0b0000000000000000000000000000000000010000,
+        const UNIFIED_HELP         = 0b0000000000000000000000000000000000100000,
+        const WAIT_ON_ERROR        = 0b0000000000000000000000000000000001000000,
+        const SC_REQUIRED_ELSE_HELP= 0b0000000000000000000000000000000010000000,
+        const NEEDS_LONG_HELP      = 0b0000000000000000000000000000000100000000,
+        const NEEDS_LONG_VERSION   = 0b0000000000000000000000000000001000000000,
+        const NEEDS_SC_HELP        = 0b0000000000000000000000000000010000000000,
+        const DISABLE_VERSION      = 0b0000000000000000000000000000100000000000,
+        const HIDDEN               = 0b0000000000000000000000000001000000000000,
+        const TRAILING_VARARG      = 0b0000000000000000000000000010000000000000,
+        const NO_BIN_NAME          = 0b0000000000000000000000000100000000000000,
+        const ALLOW_UNK_SC         = 0b0000000000000000000000001000000000000000,
+        const UTF8_STRICT          = 0b0000000000000000000000010000000000000000,
+        const UTF8_NONE            = 0b0000000000000000000000100000000000000000,
+        const LEADING_HYPHEN       = 0b0000000000000000000001000000000000000000,
+        const NO_POS_VALUES        = 0b0000000000000000000010000000000000000000,
+        const NEXT_LINE_HELP       = 0b0000000000000000000100000000000000000000,
+        const DERIVE_DISP_ORDER    = 0b0000000000000000001000000000000000000000,
+        const COLORED_HELP         = 0b0000000000000000010000000000000000000000,
+        const COLOR_ALWAYS         = 0b0000000000000000100000000000000000000000,
+        const COLOR_AUTO           = 0b0000000000000001000000000000000000000000,
+        const COLOR_NEVER          = 0b0000000000000010000000000000000000000000,
+        const DONT_DELIM_TRAIL     = 0b0000000000000100000000000000000000000000,
+        const ALLOW_NEG_NUMS       = 0b0000000000001000000000000000000000000000,
+        const LOW_INDEX_MUL_POS    = 0b0000000000010000000000000000000000000000,
+        const INFER_SUBCOMMANDS    = 0b0000000000100000000000000000000000000000,
+        const RESPONSE_FILES       = 0b0000000001000000000000000000000000000000,
+        const INFER_LONG_ARGS      = 0b0000000010000000000000000000000000000000,
+        const SC_CHAIN             = 0b0000000100000000000000000000000000000000,
+        const IGNORE_DEFAULTS      = 0b0000001000000000000000000000000000000000,
     }
 }
 
@@ -73,12 +78,17 @@ impl AppFlags {
         GlobalVersion => GLOBAL_VERSION,
         HidePossibleValuesInHelp => NO_POS_VALUES,
         Hidden => HIDDEN,
+        IgnoreDefaultsInRules => IGNORE_DEFAULTS,
+        InferSubcommands => INFER_SUBCOMMANDS,
+        InferLongArgs => INFER_LONG_ARGS,
         LowIndexMultiplePositional => LOW_INDEX_MUL_POS,
         NeedsLongHelp => NEEDS_LONG_HELP,
         NeedsLongVersion => NEEDS_LONG_VERSION,
         NeedsSubcommandHelp => NEEDS_SC_HELP,
         NoBinaryName => NO_BIN_NAME,
+        ResponseFiles => RESPONSE_FILES,
         StrictUtf8 => UTF8_STRICT,
+        SubcommandsChain => SC_CHAIN,
         SubcommandsNegateReqs => SC_NEGATE_REQS,
         SubcommandRequired => SC_REQUIRED,
         SubcommandRequiredElseHelp => SC_REQUIRED_ELSE_HELP,
@@ -142,7 +152,8 @@ pub enum AppSettings {
     /// Specifies that leading hyphens are allowed in argument *values*, such as negative numbers
     /// like `-10`. (which would otherwise be parsed as another flag or option)
     ///
-    /// **NOTE:** This can only be set application wide and not on a per argument basis.
+    /// **NOTE:** This setting applies application wide, to allow leading hyphens for a single
+    /// argument only see [`Arg::allow_hyphen_values`]
     ///
     /// **NOTE:** Use this setting with caution as it silences certain circumstances which would
     /// otherwise be an error (such as accidentally forgetting to specify a value for leading
@@ -163,6 +174,7 @@ pub enum AppSettings {
     /// assert_eq!(m.value_of("neg"), Some("-20"));
     /// # ;
     /// ```
+    /// [`Arg::allow_hyphen_values`]: ./struct.Arg.html#method.allow_hyphen_values
     AllowLeadingHyphen,
 
     /// Allows negative numbers to pass as values. This is similar to
@@ -417,6 +429,133 @@ pub enum AppSettings {
     /// This can be useful if there are many values, or they are explained elsewhere.
     HidePossibleValuesInHelp,
 
+    /// Arguments which only got their [default value] don't trigger the [conflicts] and
+    /// [requirements] of any argument. Without this setting, an argument with a default value
+    /// counts as used, so using an argument which conflicts with it is an error, even though the
+    /// user never gave it.
+    ///
+    /// **NOTE:** A default value still satisfies the requirement of another argument which was
+    /// used. Use [`ArgMatches::is_explicitly_present`] to tell defaults apart after parsing.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg, AppSettings, ValueSource};
+    /// let m = App::new("prog")
+    ///     .setting(AppSettings::IgnoreDefaultsInRules)
+    ///     .arg(Arg::with_name("jobs")
+    ///         .long("jobs")
+    ///         .default_value("1"))
+    ///     .arg(Arg::with_name("serial")
+    ///         .long("serial")
+    ///         .conflicts_with("jobs"))
+    ///     .get_matches_from(vec![
+    ///         "prog", "--serial"
+    ///     ]);
+    ///
+    /// assert!(m.is_present("serial"));
+    /// assert_eq!(m.value_source("jobs"), Some(ValueSource::DefaultValue));
+    /// ```
+    /// [default value]: ./struct.Arg.html#method.default_value
+    /// [conflicts]: ./struct.Arg.html#method.conflicts_with
+    /// [requirements]: ./struct.Arg.html#method.requires
+    /// [`ArgMatches::is_explicitly_present`]: ./struct.ArgMatches.html#method.is_explicitly_present
+    IgnoreDefaultsInRules,
+
+    /// Tries to match unknown args to partial [`subcommands`] or their [aliases]. For example to
+    /// match a subcommand named `test`, one could use `t`, `te`, `tes`, and `test`.
+    ///
+    /// **NOTE:** The match *must not* be ambiguous at all in order to succeed. i.e. to match `te`
+    /// to `test` there could not also be a subcommand or alias `temp` because both start with
+    /// `te`. An ambiguous prefix is an [`ErrorKind::InvalidSubcommand`] error listing all the
+    /// subcommands it could refer to.
+    ///
+    /// **NOTE:** This setting is **not** recursive with [`SubCommand`]s, meaning if you wish this
+    /// behavior for all subcommands, you must set this on each command or use
+    /// [`App::global_setting`]
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg, SubCommand, AppSettings};
+    /// let m = App::new("prog")
+    ///     .setting(AppSettings::InferSubcommands)
+    ///     .subcommand(SubCommand::with_name("test"))
+    ///     .get_matches_from(vec![
+    ///         "prog", "te"
+    ///     ]);
+    /// assert_eq!(m.subcommand_name(), Some("test"));
+    /// ```
+    ///
+    /// An ambiguous prefix is an error
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg, SubCommand, AppSettings, ErrorKind};
+    /// let res = App::new("prog")
+    ///     .setting(AppSettings::InferSubcommands)
+    ///     .subcommand(SubCommand::with_name("test"))
+    ///     .subcommand(SubCommand::with_name("temp"))
+    ///     .get_matches_from_safe(vec![
+    ///         "prog", "te"
+    ///     ]);
+    /// assert!(res.is_err());
+    /// assert_eq!(res.unwrap_err().kind, ErrorKind::InvalidSubcommand);
+    /// ```
+    /// [`subcommands`]: ./struct.SubCommand.html
+    /// [aliases]: ./struct.App.html#method.alias
+    /// [`ErrorKind::InvalidSubcommand`]: ./enum.ErrorKind.html#variant.InvalidSubcommand
+    /// [`SubCommand`]: ./struct.SubCommand.html
+    /// [`App::global_setting`]: ./struct.App.html#method.global_setting
+    InferSubcommands,
+
+    /// Tries to match unknown long arguments to partial long names or [aliases] of flags and
+    /// options, the way `getopt_long` does. For example `--verb` is accepted as `--verbose`.
+    ///
+    /// **NOTE:** The match *must not* be ambiguous at all in order to succeed. i.e. to match
+    /// `--verb` to `--verbose` there could not also be a `--verbatim` argument. An ambiguous
+    /// prefix is an [`ErrorKind::UnknownArgument`] error listing all the arguments it could refer
+    /// to. An exact match always wins over a longer name starting with it.
+    ///
+    /// **NOTE:** This setting is **not** recursive with [`SubCommand`]s, meaning if you wish this
+    /// behavior for all subcommands, you must set this on each command or use
+    /// [`App::global_setting`]
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg, AppSettings};
+    /// let m = App::new("prog")
+    ///     .setting(AppSettings::InferLongArgs)
+    ///     .arg(Arg::with_name("verbose")
+    ///         .long("verbose"))
+    ///     .get_matches_from(vec![
+    ///         "prog", "--verb"
+    ///     ]);
+    /// assert!(m.is_present("verbose"));
+    /// ```
+    ///
+    /// An ambiguous prefix is an error
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg, AppSettings, ErrorKind};
+    /// let res = App::new("prog")
+    ///     .setting(AppSettings::InferLongArgs)
+    ///     .arg(Arg::with_name("verbose")
+    ///         .long("verbose"))
+    ///     .arg(Arg::with_name("verbatim")
+    ///         .long("verbatim"))
+    ///     .get_matches_from_safe(vec![
+    ///         "prog", "--verb"
+    ///     ]);
+    /// assert!(res.is_err());
+    /// assert_eq!(res.unwrap_err().kind, ErrorKind::UnknownArgument);
+    /// ```
+    /// [aliases]: ./struct.Arg.html#method.alias
+    /// [`ErrorKind::UnknownArgument`]: ./enum.ErrorKind.html#variant.UnknownArgument
+    /// [`SubCommand`]: ./struct.SubCommand.html
+    /// [`App::global_setting`]: ./struct.App.html#method.global_setting
+    InferLongArgs,
+
     /// Specifies that the parser should not assume the first argument passed is the binary name.
     /// This is normally the case when using a "daemon" style mode, or an interactive CLI where one
     /// one would not normally type the binary or program name for each command.
@@ -447,6 +586,49 @@ pub enum AppSettings {
     /// ```
     NextLineHelp,
 
+    /// Expands arguments of the form `@path` into the arguments contained in the file at `path`
+    /// before parsing, which is useful for command lines too long for the operating system (such
+    /// as those generated by build tools). This mirrors the response files supported by tools
+    /// like `rustc` and `gcc`.
+    ///
+    /// Arguments in a response file are separated by whitespace (including newlines) and may be
+    /// quoted shell style: everything between single quotes is taken literally, while inside
+    /// double quotes a backslash escapes `"` and `\`. Outside of quotes a backslash escapes any
+    /// character. Response files may themselves contain `@path` arguments, up to a nesting depth
+    /// of 10.
+    ///
+    /// To pass an argument which starts with a literal `@` use `@@`, i.e. `@@foo` is passed to
+    /// the parser as `@foo`.
+    ///
+    /// **NOTE:** Arguments after the first `--` (including those read from a response file) are
+    /// never expanded, so `@path` can be passed through as a value.
+    ///
+    /// **NOTE:** Unlike most settings, this one is app-wide: it must be set on the top level
+    /// [`App`], and then response files are expanded for the entire command line, including
+    /// arguments to [`SubCommand`]s. Setting it on a [`SubCommand`] has no effect.
+    ///
+    /// **NOTE:** A response file which can't be read, has unbalanced quotes, or is nested too
+    /// deeply results in an [`ErrorKind::Io`] error naming the file.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use clap::{App, Arg, AppSettings};
+    /// // Where args.txt contains: --opt "some value" input.txt
+    /// let m = App::new("myprog")
+    ///     .setting(AppSettings::ResponseFiles)
+    ///     .arg(Arg::from_usage("--opt [val] 'some option'"))
+    ///     .arg(Arg::from_usage("[input] 'some input'"))
+    ///     .get_matches_from(vec!["myprog", "@args.txt"]);
+    ///
+    /// assert_eq!(m.value_of("opt"), Some("some value"));
+    /// assert_eq!(m.value_of("input"), Some("input.txt"));
+    /// ```
+    /// [`SubCommand`]: ./struct.SubCommand.html
+    /// [`App`]: ./struct.App.html
+    /// [`ErrorKind::Io`]: ./enum.ErrorKind.html#variant.Io
+    ResponseFiles,
+
     /// Allows [`SubCommand`]s to override all requirements of the parent command.
     /// For example if you had a subcommand or top level application with a required argument
     /// that is only required as long as there is no subcommand present,
@@ -492,6 +674,43 @@ pub enum AppSettings {
     /// [`SubCommand`]: ./struct.SubCommand.html
     SubcommandsNegateReqs,
 
+    /// Allows more than one [`SubCommand`] to be used in a single run, one after another, the way
+    /// some build tools chain tasks (i.e. `$ prog fetch --all build --release test`). When an
+    /// argument of a subcommand is the name or an [alias] of another subcommand of this command,
+    /// the first subcommand ends and the next one starts. The subcommands used are retrieved in
+    /// order with [`ArgMatches::subcommands`].
+    ///
+    /// **NOTE:** The name of a sibling subcommand always starts the next subcommand, even where
+    /// the current subcommand would accept it as a value. Values which are also subcommand names
+    /// can only be passed after `--`, which ends the chain.
+    ///
+    /// **NOTE:** This setting is **not** recursive with [`SubCommand`]s, it only allows chaining
+    /// the subcommands of the command it's set on.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg, AppSettings, SubCommand};
+    /// let m = App::new("tool")
+    ///     .setting(AppSettings::SubcommandsChain)
+    ///     .subcommand(SubCommand::with_name("fetch")
+    ///         .arg(Arg::with_name("all").long("all")))
+    ///     .subcommand(SubCommand::with_name("build")
+    ///         .arg(Arg::with_name("release").long("release")))
+    ///     .subcommand(SubCommand::with_name("test"))
+    ///     .get_matches_from(vec![
+    ///         "tool", "fetch", "--all", "build", "--release", "test"
+    ///     ]);
+    ///
+    /// let used: Vec<_> = m.subcommands().into_iter().map(|(name, _)| name).collect();
+    /// assert_eq!(used, ["fetch", "build", "test"]);
+    /// assert!(m.subcommand_matches("build").unwrap().is_present("release"));
+    /// ```
+    /// [`SubCommand`]: ./struct.SubCommand.html
+    /// [alias]: ./struct.App.html#method.alias
+    /// [`ArgMatches::subcommands`]: ./struct.ArgMatches.html#method.subcommands
+    SubcommandsChain,
+
     /// Specifies that the help text should be displayed (before exiting gracefully) if no
     /// [`SubCommand`]s are present at runtime (i.e. an empty run such as `$ myprog`).
     ///
@@ -689,10 +908,15 @@ impl FromStr for AppSettings {
             "globalversion" => Ok(AppSettings::GlobalVersion),
             "hidden" => Ok(AppSettings::Hidden),
             "hidepossiblevaluesinhelp" => Ok(AppSettings::HidePossibleValuesInHelp),
+            "ignoredefaultsinrules" => Ok(AppSettings::IgnoreDefaultsInRules),
+            "infersubcommands" => Ok(AppSettings::InferSubcommands),
+            "inferlongargs" => Ok(AppSettings::InferLongArgs),
             "lowindexmultiplepositional" => Ok(AppSettings::LowIndexMultiplePositional),
             "nobinaryname" => Ok(AppSettings::NoBinaryName),
             "nextlinehelp" => Ok(AppSettings::NextLineHelp),
+            "responsefiles" => Ok(AppSettings::ResponseFiles),
             "strictutf8" => Ok(AppSettings::StrictUtf8),
+            "subcommandschain" => Ok(AppSettings::SubcommandsChain),
             "subcommandsnegatereqs" => Ok(AppSettings::SubcommandsNegateReqs),
             "subcommandrequired" => Ok(AppSettings::SubcommandRequired),
             "subcommandrequiredelsehelp" => Ok(AppSettings::SubcommandRequiredElseHelp),
@@ -741,12 +965,22 @@ mod test {
                    AppSettings::Hidden);
         assert_eq!("hidepossiblevaluesinhelp".parse::<AppSettings>().unwrap(),
                    AppSettings::HidePossibleValuesInHelp);
+        assert_eq!("ignoredefaultsinrules".parse::<AppSettings>().unwrap(),
+                   AppSettings::IgnoreDefaultsInRules);
+        assert_eq!("infersubcommands".parse::<AppSettings>().unwrap(),
+                   AppSettings::InferSubcommands);
+        assert_eq!("inferlongargs".parse::<AppSettings>().unwrap(),
+                   AppSettings::InferLongArgs);
         assert_eq!("lowindexmultiplePositional".parse::<AppSettings>().unwrap(),
                    AppSettings::LowIndexMultiplePositional);
         assert_eq!("nobinaryname".parse::<AppSettings>().unwrap(),
                    AppSettings::NoBinaryName);
         assert_eq!("nextlinehelp".parse::<AppSettings>().unwrap(),
                    AppSettings::NextLineHelp);
+        assert_eq!("responsefiles".parse::<AppSettings>().unwrap(),
+                   AppSettings::ResponseFiles);
+        assert_eq!("subcommandschain".parse::<AppSettings>().unwrap(),
+                   AppSettings::SubcommandsChain);
         assert_eq!("subcommandsnegatereqs".parse::<AppSettings>().unwrap(),
                    AppSettings::SubcommandsNegateReqs);
         assert_eq!("subcommandrequired".parse::<AppSettings>().unwrap(),
@@ -0,0 +1,130 @@
+// Std
+use std::ascii::AsciiExt;
+use std::ffi::OsStr;
+use std::fmt;
+use std::rc::Rc;
+use std::str::FromStr;
+
+// Internal
+use errors::Error;
+
+/// What happens when an argument is used at runtime, set with [`Arg::action`]. Arguments without
+/// an action behave as determined by their settings, such as [`Arg::takes_value`] and
+/// [`Arg::multiple`].
+///
+/// Any action other than [`ArgAction::Help`] and [`ArgAction::Version`] allows the argument to be
+/// used more than once.
+///
+/// [`Arg::action`]: ./struct.Arg.html#method.action
+/// [`Arg::takes_value`]: ./struct.Arg.html#method.takes_value
+/// [`Arg::multiple`]: ./struct.Arg.html#method.multiple
+/// [`ArgAction::Help`]: ./enum.ArgAction.html#variant.Help
+/// [`ArgAction::Version`]: ./enum.ArgAction.html#variant.Version
+#[derive(Clone)]
+pub enum ArgAction {
+    /// Stores the value of an option, each use replacing the value of any previous use, i.e. the
+    /// last one wins. Implies [`Arg::takes_value(true)`]
+    ///
+    /// [`Arg::takes_value(true)`]: ./struct.Arg.html#method.takes_value
+    Set,
+    /// Stores the value of an option, each use adding its value to those of any previous uses.
+    /// Implies [`Arg::takes_value(true)`]
+    ///
+    /// [`Arg::takes_value(true)`]: ./struct.Arg.html#method.takes_value
+    Append,
+    /// Counts the uses of a flag, which are retrieved with [`ArgMatches::occurrences_of`]
+    ///
+    /// [`ArgMatches::occurrences_of`]: ./struct.ArgMatches.html#method.occurrences_of
+    Count,
+    /// Stores the value `"true"` when the flag is used, and `"false"` when it isn't
+    ///
+    /// **NOTE:** When the flag isn't used, [`ArgMatches::is_present`] is still `false` even though
+    /// [`ArgMatches::value_of`] returns `"false"`
+    ///
+    /// [`ArgMatches::is_present`]: ./struct.ArgMatches.html#method.is_present
+    /// [`ArgMatches::value_of`]: ./struct.ArgMatches.html#method.value_of
+    SetTrue,
+    /// Stores the value `"false"` when the flag is used, and `"true"` when it isn't
+    ///
+    /// **NOTE:** When the flag isn't used, [`ArgMatches::is_present`] is still `false` even though
+    /// [`ArgMatches::value_of`] returns `"true"`
+    ///
+    /// [`ArgMatches::is_present`]: ./struct.ArgMatches.html#method.is_present
+    /// [`ArgMatches::value_of`]: ./struct.ArgMatches.html#method.value_of
+    SetFalse,
+    /// Displays the help message and exits, like the auto-generated `-h`/`--help` flag. The long
+    /// help message is displayed when the flag is used by its long name
+    Help,
+    /// Displays the version and exits, like the auto-generated `-V`/`--version` flag
+    Version,
+    /// Records the use of the argument like any other, then calls the function with the value
+    /// (`None` for flags). Returning an `Err` stops parsing with that error, i.e. an error of
+    /// kind [`ErrorKind::HelpDisplayed`] displays its message and exits successfully. Use
+    /// [`Error::raw`] to display the message exactly as is.
+    ///
+    /// **NOTE:** The function is only called for values from the command line, not for
+    /// [default values] or values from the [environment]
+    ///
+    /// [`ErrorKind::HelpDisplayed`]: ./enum.ErrorKind.html#variant.HelpDisplayed
+    /// [`Error::raw`]: ./struct.Error.html#method.raw
+    /// [default values]: ./struct.Arg.html#method.default_value
+    /// [environment]: ./struct.Arg.html#method.env
+    Custom(Rc<Fn(Option<&OsStr>) -> Result<(), Error>>),
+}
+
+impl ArgAction {
+    /// A list of possible variants in `&'static str` form, except [`ArgAction::Custom`]
+    ///
+    /// [`ArgAction::Custom`]: ./enum.ArgAction.html#variant.Custom
+    pub fn variants() -> [&'static str; 7] {
+        ["set", "append", "count", "settrue", "setfalse", "help", "version"]
+    }
+}
+
+impl FromStr for ArgAction {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, <Self as FromStr>::Err> {
+        match &*s.to_ascii_lowercase() {
+            "set" => Ok(ArgAction::Set),
+            "append" => Ok(ArgAction::Append),
+            "count" => Ok(ArgAction::Count),
+            "settrue" => Ok(ArgAction::SetTrue),
+            "setfalse" => Ok(ArgAction::SetFalse),
+            "help" => Ok(ArgAction::Help),
+            "version" => Ok(ArgAction::Version),
+            _ => Err("unknown ArgAction, cannot convert from str".to_owned()),
+        }
+    }
+}
+
+impl fmt::Debug for ArgAction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ArgAction::Set => write!(f, "Set"),
+            ArgAction::Append => write!(f, "Append"),
+            ArgAction::Count => write!(f, "Count"),
+            ArgAction::SetTrue => write!(f, "SetTrue"),
+            ArgAction::SetFalse => write!(f, "SetFalse"),
+            ArgAction::Help => write!(f, "Help"),
+            ArgAction::Version => write!(f, "Version"),
+            ArgAction::Custom(_) => write!(f, "Custom(..)"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::ArgAction;
+
+    #[test]
+    fn arg_action_fromstr() {
+        for v in &ArgAction::variants() {
+            assert!(v.parse::<ArgAction>().is_ok());
+        }
+        match "SetTrue".parse::<ArgAction>() {
+            Ok(ArgAction::SetTrue) => (),
+            other => panic!("expected SetTrue, got {:?}", other),
+        }
+        assert!("other".parse::<ArgAction>().is_err());
+    }
+}
@@ -1,11 +1,15 @@
 // Std
+use std::any::Any;
+use std::ffi::{OsStr, OsString};
 use std::rc::Rc;
+use std::sync::Arc;
 use std::fmt as std_fmt; 
 
 // Third Party
-use vec_map::VecMap;
+use vec_map::{self, VecMap};
 
 // Internal
+use args::ArgAction;
 use args::settings::ArgSettings;
 
 #[doc(hidden)]
@@ -24,13 +28,21 @@ pub trait AnyArg<'n, 'e>: std_fmt::Display {
     fn num_vals(&self) -> Option<u64>;
     fn possible_vals(&self) -> Option<&[&'e str]>;
     fn validator(&self) -> Option<&Rc<Fn(String) -> Result<(), String>>>;
+    fn value_parser(&self) -> Option<&Rc<Fn(&OsStr) -> Result<Arc<Any + Send + Sync>, String>>>;
     fn short(&self) -> Option<char>;
     fn long(&self) -> Option<&'e str>;
     fn val_delim(&self) -> Option<char>;
     fn takes_value(&self) -> bool;
     fn val_names(&self) -> Option<&VecMap<&'e str>>;
     fn help(&self) -> Option<&'e str>;
+    fn long_help(&self) -> Option<&'e str>;
+    fn val_terminator(&self) -> Option<&'e str>;
     fn default_val(&self) -> Option<&'n str>;
+    fn default_missing_val(&self) -> Option<&'n str>;
+    fn prompt(&self) -> Option<&'e str>;
+    fn action(&self) -> Option<&ArgAction>;
+    fn default_vals_ifs(&self) -> Option<vec_map::Values<(&'n str, Option<&'e str>, &'e str)>>;
+    fn env<'s>(&'s self) -> Option<(&'n OsStr, Option<&'s OsString>)>;
     fn longest_filter(&self) -> bool;
 }
 
@@ -1,12 +1,20 @@
 #[cfg(feature = "yaml")]
 use std::collections::BTreeMap;
+use std::any::Any;
+use std::env;
+use std::ffi::{OsStr, OsString};
+use std::fmt::Display;
 use std::rc::Rc;
+use std::str::FromStr;
+use std::sync::Arc;
 
 #[cfg(feature = "yaml")]
 use yaml_rust::Yaml;
 use vec_map::VecMap;
 
+use INVALID_UTF8;
 use usage_parser::UsageParser;
+use args::ArgAction;
 use args::settings::{ArgFlags, ArgSettings};
 
 /// The abstract representation of a command line argument. Used to set all the options and
@@ -46,6 +54,8 @@ pub struct Arg<'a, 'b>
     #[doc(hidden)]
     pub help: Option<&'b str>,
     #[doc(hidden)]
+    pub long_help: Option<&'b str>,
+    #[doc(hidden)]
     pub index: Option<u64>,
     #[doc(hidden)]
     pub blacklist: Option<Vec<&'a str>>,
@@ -66,6 +76,8 @@ pub struct Arg<'a, 'b>
     #[doc(hidden)]
     pub validator: Option<Rc<Fn(String) -> Result<(), String>>>,
     #[doc(hidden)]
+    pub value_parser: Option<Rc<Fn(&OsStr) -> Result<Arc<Any + Send + Sync>, String>>>,
+    #[doc(hidden)]
     pub overrides: Option<Vec<&'a str>>,
     #[doc(hidden)]
     pub settings: ArgFlags,
@@ -74,9 +86,29 @@ pub struct Arg<'a, 'b>
     #[doc(hidden)]
     pub default_val: Option<&'a str>,
     #[doc(hidden)]
+    pub default_vals_ifs: Option<VecMap<(&'a str, Option<&'b str>, &'b str)>>,
+    // Shares its keys with default_vals_ifs, so the conditions of both keep the order they were
+    // added in
+    #[doc(hidden)]
+    pub default_vals_if_fns: Option<VecMap<(&'a str, Rc<Fn(&OsStr) -> bool>, &'b str)>>,
+    #[doc(hidden)]
+    pub default_missing_val: Option<&'a str>,
+    #[doc(hidden)]
+    pub env: Option<(&'a OsStr, Option<OsString>)>,
+    #[doc(hidden)]
     pub disp_ord: usize,
     #[doc(hidden)]
     pub r_unless: Option<Vec<&'a str>>,
+    #[doc(hidden)]
+    pub r_ifs: Option<Vec<(&'a str, &'b str)>>,
+    #[doc(hidden)]
+    pub requires_ifs: Option<Vec<(&'b str, &'a str)>>,
+    #[doc(hidden)]
+    pub terminator: Option<&'b str>,
+    #[doc(hidden)]
+    pub action: Option<ArgAction>,
+    #[doc(hidden)]
+    pub prompt: Option<&'b str>,
 }
 
 impl<'a, 'b> Default for Arg<'a, 'b> {
@@ -87,6 +119,7 @@ impl<'a, 'b> Default for Arg<'a, 'b> {
             long: None,
             aliases: None,
             help: None,
+            long_help: None,
             index: None,
             blacklist: None,
             possible_vals: None,
@@ -97,12 +130,22 @@ impl<'a, 'b> Default for Arg<'a, 'b> {
             max_vals: None,
             min_vals: None,
             validator: None,
+            value_parser: None,
             overrides: None,
             settings: ArgFlags::new(),
             val_delim: None,
             default_val: None,
+            default_vals_ifs: None,
+            default_vals_if_fns: None,
+            default_missing_val: None,
+            env: None,
             disp_ord: 999,
             r_unless: None,
+            r_ifs: None,
+            requires_ifs: None,
+            terminator: None,
+            action: None,
+            prompt: None,
         }
     }
 }
@@ -158,12 +201,24 @@ impl<'a, 'b> Arg<'a, 'b> {
                 "long" => yaml_to_str!(a, v, long),
                 "aliases" => yaml_vec_or_str!(v, a, alias),
                 "help" => yaml_to_str!(a, v, help),
+                "long_help" => yaml_to_str!(a, v, long_help),
                 "required" => yaml_to_bool!(a, v, required),
                 "takes_value" => yaml_to_bool!(a, v, takes_value),
                 "index" => yaml_to_u64!(a, v, index),
                 "global" => yaml_to_bool!(a, v, global),
                 "multiple" => yaml_to_bool!(a, v, multiple),
                 "hidden" => yaml_to_bool!(a, v, hidden),
+                "case_insensitive" => yaml_to_bool!(a, v, case_insensitive),
+                "last" => yaml_to_bool!(a, v, last),
+                "allow_hyphen_values" => yaml_to_bool!(a, v, allow_hyphen_values),
+                "negatable" => yaml_to_bool!(a, v, negatable),
+                "action" => {
+                    a.action(v.as_str()
+                        .unwrap_or_else(|| panic!("Failed to convert YAML value {:?} to a string", v))
+                        .parse()
+                        .unwrap_or_else(|e| panic!("{} in YAML file for arg '{}'", e, name_str)))
+                }
+                "require_equals" => yaml_to_bool!(a, v, require_equals),
                 "next_line_help" => yaml_to_bool!(a, v, next_line_help),
                 "empty_values" => yaml_to_bool!(a, v, empty_values),
                 "group" => yaml_to_str!(a, v, group),
@@ -174,9 +229,16 @@ impl<'a, 'b> Arg<'a, 'b> {
                 "use_delimiter" => yaml_to_bool!(a, v, use_delimiter),
                 "require_delimiter" => yaml_to_bool!(a, v, require_delimiter),
                 "value_delimiter" => yaml_to_str!(a, v, value_delimiter),
+                "value_terminator" => yaml_to_str!(a, v, value_terminator),
                 "required_unless" => yaml_to_str!(a, v, required_unless),
                 "display_order" => yaml_to_usize!(a, v, display_order),
                 "default_value" => yaml_to_str!(a, v, default_value),
+                "default_missing_value" => yaml_to_str!(a, v, default_missing_value),
+                "default_value_if" => yaml_tuple3!(a, v, default_value_if),
+                "env" => yaml_to_str!(a, v, env),
+                "prompt" => yaml_to_str!(a, v, prompt),
+                "hide_input" => yaml_to_bool!(a, v, hide_input),
+                "hide_env_values" => yaml_to_bool!(a, v, hide_env_values),
                 "value_names" => yaml_vec_or_str!(v, a, value_name),
                 "groups" => yaml_vec_or_str!(v, a, group),
                 "requires" => yaml_vec_or_str!(v, a, requires),
@@ -184,6 +246,8 @@ impl<'a, 'b> Arg<'a, 'b> {
                 "overrides_with" => yaml_vec_or_str!(v, a, overrides_with),
                 "possible_values" => yaml_vec_or_str!(v, a, possible_value),
                 "required_unless_one" => yaml_vec_or_str!(v, a, required_unless),
+                "required_if" => yaml_tuple2!(a, v, required_if),
+                "requires_if" => yaml_tuple2!(a, v, requires_if),
                 "required_unless_all" => {
                     a = yaml_vec_or_str!(v, a, required_unless);
                     a.setb(ArgSettings::RequiredUnlessAll);
@@ -317,6 +381,10 @@ impl<'a, 'b> Arg<'a, 'b> {
     /// `--option val1 val2` is a single occurrence with multiple values. `--flag --flag` is
     /// multiple occurrences (and then you can obviously have instances of both as well)
     ///
+    /// A word (no spaces) directly after the multiple notation of a value or positional argument
+    /// sets the [value terminator], e.g. `--exec <cmd>... ; 'run a command'`. Terminators starting
+    /// with `-`, `[`, `<`, `.` or `'` must be set with [`Arg::value_terminator`] instead.
+    ///
     /// # Examples
     ///
     /// ```rust
@@ -331,6 +399,8 @@ impl<'a, 'b> Arg<'a, 'b> {
     /// ```
     /// [`Arg`]: ./struct.Arg.html
     /// [`Arg::from_usage`]: ./struct.Arg.html#method.from_usage
+    /// [value terminator]: ./struct.Arg.html#method.value_terminator
+    /// [`Arg::value_terminator`]: ./struct.Arg.html#method.value_terminator
     pub fn from_usage(u: &'a str) -> Self {
         let parser = UsageParser::from_usage(u);
         parser.parse()
@@ -577,6 +647,70 @@ impl<'a, 'b> Arg<'a, 'b> {
         self
     }
 
+    /// Sets the long help text of the argument that will be displayed to the user when they print
+    /// the help information with `--help`. Typically this is a more detailed (multi-paragraph)
+    /// explanation of the argument than the one set with [`Arg::help`], which is still used when
+    /// the user passes `-h`.
+    ///
+    /// If only one of [`Arg::help`] or `Arg::long_help` is set, it is used for both `-h` and
+    /// `--help`.
+    ///
+    /// **NOTE:** The `help` subcommand also displays the long help.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// Arg::with_name("config")
+    ///     .long_help(
+    /// "The config file used by the myprog must be in JSON format
+    /// with only valid keys and may not contain other nonsense
+    /// that cannot be read by this program. Obviously I'm going on
+    /// and on, so I'll stop now.")
+    /// # ;
+    /// ```
+    ///
+    /// Setting both `help` and `long_help` displays the short message when the user passes `-h`
+    /// and the long message for `--help`.
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// let m = App::new("prog")
+    ///     .arg(Arg::with_name("cfg")
+    ///         .long("config")
+    ///         .help("Some help text describing the --config arg")
+    ///         .long_help(
+    /// "The config file used by the myprog must be in JSON format
+    /// with only valid keys and may not contain other nonsense
+    /// that cannot be read by this program. Obviously I'm going on
+    /// and on, so I'll stop now."))
+    ///     .get_matches_from(vec![
+    ///         "prog", "--help"
+    ///     ]);
+    /// ```
+    ///
+    /// The above example displays
+    ///
+    /// ```notrust
+    /// prog
+    ///
+    /// USAGE:
+    ///    prog [FLAGS]
+    ///
+    /// FLAGS:
+    ///     --config     The config file used by the myprog must be in JSON format
+    ///                  with only valid keys and may not contain other nonsense
+    ///                  that cannot be read by this program. Obviously I'm going on
+    ///                  and on, so I'll stop now.
+    /// -h, --help       Prints help information
+    /// -V, --version    Prints version information
+    /// ```
+    /// [`Arg::help`]: ./struct.Arg.html#method.help
+    pub fn long_help(mut self, h: &'b str) -> Self {
+        self.long_help = Some(h);
+        self
+    }
+
     /// Sets whether or not the argument is required by default. Required by default means it is
     /// required, when no other conflicting rules have been evaluated. Conflicting rules take
     /// precedence over being required. **Default:** `false`
@@ -851,6 +985,137 @@ impl<'a, 'b> Arg<'a, 'b> {
         self.required(true)
     }
 
+    /// Allows specifying that an argument is [required] conditionally. The requirement will only
+    /// become valid if the specified `arg`'s value equals `val`.
+    ///
+    /// **NOTE:** If using YAML the values should be laid out as follows (`arg` first, then `val`)
+    ///
+    /// ```yaml
+    /// required_if:
+    ///     - [arg, val]
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::Arg;
+    /// Arg::with_name("config")
+    ///     .required_if("other_arg", "value")
+    /// # ;
+    /// ```
+    ///
+    /// Setting [`Arg::required_if(arg, val)`] makes this arg required if the `arg` is used at
+    /// runtime and its value is equal to `val`. If the `arg`'s value is anything other than `val`,
+    /// this argument isn't required.
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// let res = App::new("reqtest")
+    ///     .arg(Arg::with_name("cfg")
+    ///         .takes_value(true)
+    ///         .required_if("other", "special")
+    ///         .long("config"))
+    ///     .arg(Arg::with_name("other")
+    ///         .long("other")
+    ///         .takes_value(true))
+    ///     .get_matches_from_safe(vec![
+    ///         "reqtest", "--other", "not-special"
+    ///     ]);
+    ///
+    /// assert!(res.is_ok()); // We didn't use --other=special, so "cfg" wasn't required
+    /// ```
+    ///
+    /// Setting [`Arg::required_if(arg, val)`] and having `arg` used with a value of `val` but *not*
+    /// using this arg is an error.
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg, ErrorKind};
+    /// let res = App::new("reqtest")
+    ///     .arg(Arg::with_name("cfg")
+    ///         .takes_value(true)
+    ///         .required_if("other", "special")
+    ///         .long("config"))
+    ///     .arg(Arg::with_name("other")
+    ///         .long("other")
+    ///         .takes_value(true))
+    ///     .get_matches_from_safe(vec![
+    ///         "reqtest", "--other", "special"
+    ///     ]);
+    ///
+    /// assert!(res.is_err());
+    /// assert_eq!(res.unwrap_err().kind, ErrorKind::MissingRequiredArgument);
+    /// ```
+    /// [`Arg::required_if(arg, val)`]: ./struct.Arg.html#method.required_if
+    /// [required]: ./struct.Arg.html#method.required
+    pub fn required_if(mut self, arg: &'a str, val: &'b str) -> Self {
+        if let Some(ref mut vec) = self.r_ifs {
+            vec.push((arg, val));
+        } else {
+            self.r_ifs = Some(vec![(arg, val)]);
+        }
+        self
+    }
+
+    /// Allows specifying that an argument is [required] based on multiple conditions. The
+    /// conditions are set up in a `(arg, val)` style tuple. The requirement will only become valid
+    /// if one of the specified `arg`'s value equals its corresponding `val`.
+    ///
+    /// **NOTE:** If using YAML the values should be laid out as follows
+    ///
+    /// ```yaml
+    /// required_if:
+    ///     - [arg, val]
+    ///     - [arg2, val2]
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::Arg;
+    /// Arg::with_name("config")
+    ///     .required_ifs(&[
+    ///         ("extra", "val"),
+    ///         ("option", "spec")
+    ///     ])
+    /// # ;
+    /// ```
+    ///
+    /// Setting [`Arg::required_ifs(&[(arg, val)])`] makes this arg required if any of the `arg`s
+    /// are used at runtime and its corresponding value is equal to `val`. If the `arg`'s value is
+    /// anything other than `val`, this argument isn't required.
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg, ErrorKind};
+    /// let res = App::new("ri")
+    ///     .arg(Arg::with_name("cfg")
+    ///         .required_ifs(&[
+    ///             ("extra", "val"),
+    ///             ("option", "spec")
+    ///         ])
+    ///         .takes_value(true)
+    ///         .long("config"))
+    ///     .arg(Arg::with_name("extra")
+    ///         .takes_value(true)
+    ///         .long("extra"))
+    ///     .arg(Arg::with_name("option")
+    ///         .takes_value(true)
+    ///         .long("option"))
+    ///     .get_matches_from_safe(vec![
+    ///         "ri", "--option", "spec"
+    ///     ]);
+    ///
+    /// assert!(res.is_err());
+    /// assert_eq!(res.unwrap_err().kind, ErrorKind::MissingRequiredArgument);
+    /// ```
+    /// [`Arg::required_ifs(&[(arg, val)])`]: ./struct.Arg.html#method.required_ifs
+    /// [required]: ./struct.Arg.html#method.required
+    pub fn required_ifs(mut self, ifs: &[(&'a str, &'b str)]) -> Self {
+        for &(arg, val) in ifs {
+            self = self.required_if(arg, val);
+        }
+        self
+    }
+
     /// Sets a conflicting argument by name. I.e. when using this argument,
     /// the following argument can't be present and vice versa.
     ///
@@ -1157,6 +1422,128 @@ impl<'a, 'b> Arg<'a, 'b> {
         self
     }
 
+    /// Allows a conditional requirement. The requirement will only become valid if this arg's value
+    /// equals `val`.
+    ///
+    /// **NOTE:** If using YAML the values should be laid out as follows (`val` first, then `arg`)
+    ///
+    /// ```yaml
+    /// requires_if:
+    ///     - [val, arg]
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::Arg;
+    /// Arg::with_name("config")
+    ///     .requires_if("val", "arg")
+    /// # ;
+    /// ```
+    ///
+    /// Setting [`Arg::requires_if(val, arg)`] requires that the `arg` be used at runtime if the
+    /// defining argument's value is equal to `val`. If the defining argument is anything other than
+    /// `val`, the other argument isn't required.
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// let res = App::new("reqtest")
+    ///     .arg(Arg::with_name("cfg")
+    ///         .takes_value(true)
+    ///         .requires_if("my.cfg", "other")
+    ///         .long("config"))
+    ///     .arg(Arg::with_name("other"))
+    ///     .get_matches_from_safe(vec![
+    ///         "reqtest", "--config", "some.cfg"
+    ///     ]);
+    ///
+    /// assert!(res.is_ok()); // We didn't use --config=my.cfg, so other wasn't required
+    /// ```
+    ///
+    /// Setting [`Arg::requires_if(val, arg)`] and setting the value to `val` but *not* supplying
+    /// `arg` is an error.
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg, ErrorKind};
+    /// let res = App::new("reqtest")
+    ///     .arg(Arg::with_name("cfg")
+    ///         .takes_value(true)
+    ///         .requires_if("my.cfg", "input")
+    ///         .long("config"))
+    ///     .arg(Arg::with_name("input"))
+    ///     .get_matches_from_safe(vec![
+    ///         "reqtest", "--config", "my.cfg"
+    ///     ]);
+    ///
+    /// assert!(res.is_err());
+    /// assert_eq!(res.unwrap_err().kind, ErrorKind::MissingRequiredArgument);
+    /// ```
+    /// [`Arg::requires_if(val, arg)`]: ./struct.Arg.html#method.requires_if
+    pub fn requires_if(mut self, val: &'b str, arg: &'a str) -> Self {
+        if let Some(ref mut vec) = self.requires_ifs {
+            vec.push((val, arg));
+        } else {
+            self.requires_ifs = Some(vec![(val, arg)]);
+        }
+        self
+    }
+
+    /// Allows multiple conditional requirements. The requirement will only become valid if this
+    /// arg's value equals `val`. The conditions are set up in a `(val, arg)` style tuple.
+    ///
+    /// **NOTE:** If using YAML the values should be laid out as follows
+    ///
+    /// ```yaml
+    /// requires_if:
+    ///     - [val, arg]
+    ///     - [val2, arg2]
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::Arg;
+    /// Arg::with_name("config")
+    ///     .requires_ifs(&[
+    ///         ("val", "arg"),
+    ///         ("other_val", "arg2"),
+    ///     ])
+    /// # ;
+    /// ```
+    ///
+    /// Setting [`Arg::requires_ifs(&[(val, arg)])`] requires that the `arg` be used at runtime if
+    /// the defining argument's value is equal to `val`. If the defining argument's value is
+    /// anything other than `val`, `arg` isn't required.
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg, ErrorKind};
+    /// let res = App::new("reqtest")
+    ///     .arg(Arg::with_name("cfg")
+    ///         .takes_value(true)
+    ///         .requires_ifs(&[
+    ///             ("special.conf", "opt"),
+    ///             ("other.conf", "other"),
+    ///         ])
+    ///         .long("config"))
+    ///     .arg(Arg::with_name("opt")
+    ///         .long("option")
+    ///         .takes_value(true))
+    ///     .arg(Arg::with_name("other"))
+    ///     .get_matches_from_safe(vec![
+    ///         "reqtest", "--config", "special.conf"
+    ///     ]);
+    ///
+    /// assert!(res.is_err()); // We used --config=special.conf so --option <val> is required
+    /// assert_eq!(res.unwrap_err().kind, ErrorKind::MissingRequiredArgument);
+    /// ```
+    /// [`Arg::requires_ifs(&[(val, arg)])`]: ./struct.Arg.html#method.requires_ifs
+    pub fn requires_ifs(mut self, ifs: &[(&'b str, &'a str)]) -> Self {
+        for &(val, arg) in ifs {
+            self = self.requires_if(val, arg);
+        }
+        self
+    }
+
     /// Specifies that the argument takes a value at run time.
     ///
     /// **NOTE:** values for arguments may be specified in any of the following methods
@@ -1237,6 +1624,59 @@ impl<'a, 'b> Arg<'a, 'b> {
         }
     }
 
+    /// When used with [`Arg::possible_values`] it allows the argument value to pass validation
+    /// even if the case differs from that of the specified `possible_value`. The value stored in
+    /// the [`ArgMatches`] is the spelling given to [`Arg::possible_values`], not the one used on
+    /// the command line. Defaults to `false` (i.e. values must match exactly)
+    ///
+    /// **NOTE:** Only ASCII case is ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// let m = App::new("pv")
+    ///     .arg(Arg::with_name("option")
+    ///         .long("--option")
+    ///         .takes_value(true)
+    ///         .possible_values(&["test123"])
+    ///         .case_insensitive(true))
+    ///     .get_matches_from(vec![
+    ///         "pv", "--option", "TeSt123",
+    ///     ]);
+    ///
+    /// assert_eq!(m.value_of("option"), Some("test123"));
+    /// ```
+    ///
+    /// This setting also works when multiple values can be defined:
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// let m = App::new("pv")
+    ///     .arg(Arg::with_name("option")
+    ///         .short("o")
+    ///         .long("--option")
+    ///         .takes_value(true)
+    ///         .possible_values(&["test123", "test321"])
+    ///         .multiple(true)
+    ///         .case_insensitive(true))
+    ///     .get_matches_from(vec![
+    ///         "pv", "--option", "TeSt123", "teST123", "tESt321"
+    ///     ]);
+    ///
+    /// let matched_vals = m.values_of("option").unwrap().collect::<Vec<_>>();
+    /// assert_eq!(&*matched_vals, &["test123", "test123", "test321"]);
+    /// ```
+    /// [`Arg::possible_values`]: ./struct.Arg.html#method.possible_values
+    /// [`ArgMatches`]: ./struct.ArgMatches.html
+    pub fn case_insensitive(self, ci: bool) -> Self {
+        if ci {
+            self.set(ArgSettings::CaseInsensitive)
+        } else {
+            self.unset(ArgSettings::CaseInsensitive)
+        }
+    }
+
     /// Specifies the index of a positional argument **starting at** 1.
     ///
     /// **NOTE:** The index refers to position according to **other positional argument**. It does
@@ -1287,44 +1727,274 @@ impl<'a, 'b> Arg<'a, 'b> {
         self
     }
 
-    /// Specifies that the argument may appear more than once. For flags, this results
-    /// in the number of occurrences of the flag being recorded. For example `-ddd` or `-d -d -d`
-    /// would count as three occurrences. For options there is a distinct difference in multiple
-    /// occurrences vs multiple values.
+    /// Specifies that this positional argument may only be supplied by arguments which appear
+    /// after a `--` on the command line (i.e. `prog [args] -- <last>`). Unlike
+    /// [`AppSettings::TrailingVarArg`] this makes it possible to tell `prog a -- b c` apart from
+    /// `prog a b c`. Supplying a value for this argument without the `--` is an error.
     ///
-    /// For example, `--opt val1 val2` is one occurrence, but two values. Whereas
-    /// `--opt val1 --opt val2` is two occurrences.
+    /// Once `--` has been found, any remaining arguments go straight to this positional, even if
+    /// positionals with a lower index haven't been supplied yet.
     ///
-    /// **WARNING:**
+    /// **NOTE:** This setting only applies to [positional arguments], and the argument must be
+    /// the last positional argument (i.e. the one with the highest index)
     ///
-    /// Setting `multiple(true)` for an [option] with no other details, allows multiple values
-    /// **and** multiple occurrences because it isn't possible to have more occurrences than values for
-    /// options. Because multiple values are allowed, `--option val1 val2 val3` is perfectly valid,
-    /// be careful when designing a CLI where positional arguments are expected after a option which
-    /// accepts multiple values, as `clap` will continue parsing *values* until it reaches the max
-    /// or specific number of values defined, or another flag or option.
+    /// **NOTE:** When the last positional argument has this setting, a positional argument with
+    /// a lower index may also be set to [`Arg::multiple(true)`] without making the last one
+    /// required, since `--` marks where one ends and the other begins
     ///
-    /// **Pro Tip**:
+    /// # Panics
     ///
-    /// It's possible to define an option which allows multiple occurrences, but only one value per
-    /// occurrence. To do this use [`Arg::number_of_values(1)`] in coordination with
-    /// [`Arg::multiple(true)`].
+    /// Although not in this method directly, [`App`] will [`panic!`] if this setting is used on
+    /// a positional argument which doesn't have the highest index
     ///
     /// # Examples
     ///
     /// ```rust
     /// # use clap::{App, Arg};
-    /// Arg::with_name("debug")
-    ///     .short("d")
-    ///     .multiple(true)
-    /// # ;
+    /// let m = App::new("prog")
+    ///     .arg(Arg::with_name("first"))
+    ///     .arg(Arg::with_name("args")
+    ///         .multiple(true)
+    ///         .last(true))
+    ///     .get_matches_from(vec![
+    ///         "prog", "one", "--", "-a", "two"
+    ///     ]);
+    ///
+    /// assert_eq!(m.value_of("first"), Some("one"));
+    /// assert_eq!(m.values_of("args").unwrap().collect::<Vec<_>>(), ["-a", "two"]);
     /// ```
-    /// An example with flags
+    ///
+    /// Supplying the argument without `--` is an error
     ///
     /// ```rust
-    /// # use clap::{App, Arg};
-    /// let m = App::new("mults")
-    ///     .arg(Arg::with_name("verbose")
+    /// # use clap::{App, Arg, ErrorKind};
+    /// let res = App::new("prog")
+    ///     .arg(Arg::with_name("first"))
+    ///     .arg(Arg::with_name("args")
+    ///         .multiple(true)
+    ///         .last(true))
+    ///     .get_matches_from_safe(vec![
+    ///         "prog", "one", "two"
+    ///     ]);
+    ///
+    /// assert!(res.is_err());
+    /// assert_eq!(res.unwrap_err().kind, ErrorKind::UnknownArgument);
+    /// ```
+    /// [positional arguments]: ./struct.Arg.html#method.index
+    /// [`AppSettings::TrailingVarArg`]: ./enum.AppSettings.html#variant.TrailingVarArg
+    /// [`Arg::multiple(true)`]: ./struct.Arg.html#method.multiple
+    /// [`App`]: ./struct.App.html
+    /// [`panic!`]: https://doc.rust-lang.org/std/macro.panic!.html
+    pub fn last(self, l: bool) -> Self {
+        if l {
+            self.set(ArgSettings::Last)
+        } else {
+            self.unset(ArgSettings::Last)
+        }
+    }
+
+    /// Allows values which start with a leading hyphen (`-`) for this argument only, such as
+    /// negative numbers like `-10`. Unlike [`AppSettings::AllowLeadingHyphen`] all other
+    /// arguments still report undefined flags and options as errors.
+    ///
+    /// For [options] the argument following the option is always taken as its value (except for
+    /// a lone `--`). For [positional arguments] an argument starting with a hyphen is only taken
+    /// as a value if it doesn't match a defined flag or option.
+    ///
+    /// **WARNING**: Take caution when using this setting combined with [`Arg::multiple(true)`]
+    /// on an option, as *every* following argument which starts with a hyphen will be consumed
+    /// as a value until `--` or the option has enough values.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// let m = App::new("prog")
+    ///     .arg(Arg::with_name("offset")
+    ///         .long("offset")
+    ///         .takes_value(true)
+    ///         .allow_hyphen_values(true))
+    ///     .arg(Arg::with_name("verbose")
+    ///         .long("verbose"))
+    ///     .get_matches_from(vec![
+    ///         "prog", "--offset", "-5"
+    ///     ]);
+    ///
+    /// assert_eq!(m.value_of("offset"), Some("-5"));
+    /// ```
+    ///
+    /// Undefined flags are still an error everywhere else
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg, ErrorKind};
+    /// let res = App::new("prog")
+    ///     .arg(Arg::with_name("offset")
+    ///         .long("offset")
+    ///         .takes_value(true)
+    ///         .allow_hyphen_values(true))
+    ///     .arg(Arg::with_name("verbose")
+    ///         .long("verbose"))
+    ///     .get_matches_from_safe(vec![
+    ///         "prog", "--verbos", "--offset", "-5"
+    ///     ]);
+    ///
+    /// assert!(res.is_err());
+    /// assert_eq!(res.unwrap_err().kind, ErrorKind::UnknownArgument);
+    /// ```
+    /// [`AppSettings::AllowLeadingHyphen`]: ./enum.AppSettings.html#variant.AllowLeadingHyphen
+    /// [options]: ./struct.Arg.html#method.takes_value
+    /// [positional arguments]: ./struct.Arg.html#method.index
+    /// [`Arg::multiple(true)`]: ./struct.Arg.html#method.multiple
+    pub fn allow_hyphen_values(self, a: bool) -> Self {
+        if a {
+            self.set(ArgSettings::AllowLeadingHyphen)
+        } else {
+            self.unset(ArgSettings::AllowLeadingHyphen)
+        }
+    }
+
+    /// Specifies that a flag may also be turned off with `--no-<long>`, i.e. a flag with
+    /// `.long("color")` may be used as both `--color` and `--no-color`. When both are used the
+    /// last one wins, so `--color --no-color` turns the flag off. Help messages display such a
+    /// flag as `--[no-]color`.
+    ///
+    /// When the flag was turned off [`ArgMatches::is_present`] returns `false` and
+    /// [`ArgMatches::is_negated`] returns `true`, which allows telling an explicit `--no-color`
+    /// apart from the flag not being used at all.
+    ///
+    /// **NOTE:** This setting only applies to flags, and requires a [`Arg::long`]
+    ///
+    /// # Panics
+    ///
+    /// Although not in this method directly, [`App`] will [`panic!`] if this setting is used on a
+    /// flag without a [`Arg::long`]
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// let m = App::new("prog")
+    ///     .arg(Arg::with_name("color")
+    ///         .long("color")
+    ///         .negatable(true))
+    ///     .get_matches_from(vec![
+    ///         "prog", "--color", "--no-color"
+    ///     ]);
+    ///
+    /// assert!(!m.is_present("color"));
+    /// assert!(m.is_negated("color"));
+    /// ```
+    /// [`ArgMatches::is_present`]: ./struct.ArgMatches.html#method.is_present
+    /// [`ArgMatches::is_negated`]: ./struct.ArgMatches.html#method.is_negated
+    /// [`Arg::long`]: ./struct.Arg.html#method.long
+    /// [`App`]: ./struct.App.html
+    /// [`panic!`]: https://doc.rust-lang.org/std/macro.panic!.html
+    pub fn negatable(self, n: bool) -> Self {
+        if n {
+            self.set(ArgSettings::Negatable)
+        } else {
+            self.unset(ArgSettings::Negatable)
+        }
+    }
+
+    /// Specifies what happens when the argument is used at runtime, such as a flag which turns
+    /// something off with [`ArgAction::SetFalse`], an option whose last value wins with
+    /// [`ArgAction::Set`], or a flag which displays help like `--help` with [`ArgAction::Help`].
+    /// See [`ArgAction`] for all of the actions.
+    ///
+    /// **NOTE:** Actions only apply to flags and options. [`ArgAction::Set`] and
+    /// [`ArgAction::Append`] implicitly set [`Arg::takes_value(true)`]
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg, ArgAction};
+    /// let m = App::new("prog")
+    ///     .arg(Arg::with_name("cache")
+    ///         .long("no-cache")
+    ///         .action(ArgAction::SetFalse))
+    ///     .arg(Arg::with_name("level")
+    ///         .long("level")
+    ///         .action(ArgAction::Set))
+    ///     .get_matches_from(vec![
+    ///         "prog", "--no-cache", "--level", "1", "--level", "2"
+    ///     ]);
+    ///
+    /// assert_eq!(m.value_of("cache"), Some("false"));
+    /// assert_eq!(m.values_of("level").unwrap().collect::<Vec<_>>(), ["2"]);
+    /// ```
+    ///
+    /// Attaching help like behavior to a flag of your own
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg, ArgAction, Error, ErrorKind};
+    /// # use std::rc::Rc;
+    /// let res = App::new("prog")
+    ///     .arg(Arg::with_name("plugins")
+    ///         .long("list-plugins")
+    ///         .action(ArgAction::Custom(Rc::new(|_| {
+    ///             Err(Error::raw(ErrorKind::HelpDisplayed, "git\nhg"))
+    ///         }))))
+    ///     .get_matches_from_safe(vec![
+    ///         "prog", "--list-plugins"
+    ///     ]);
+    ///
+    /// assert!(res.is_err());
+    /// assert_eq!(res.unwrap_err().kind, ErrorKind::HelpDisplayed);
+    /// ```
+    /// [`ArgAction`]: ./enum.ArgAction.html
+    /// [`ArgAction::SetFalse`]: ./enum.ArgAction.html#variant.SetFalse
+    /// [`ArgAction::Set`]: ./enum.ArgAction.html#variant.Set
+    /// [`ArgAction::Append`]: ./enum.ArgAction.html#variant.Append
+    /// [`ArgAction::Help`]: ./enum.ArgAction.html#variant.Help
+    /// [`Arg::takes_value(true)`]: ./struct.Arg.html#method.takes_value
+    pub fn action(mut self, action: ArgAction) -> Self {
+        match action {
+            ArgAction::Set | ArgAction::Append => self.setb(ArgSettings::TakesValue),
+            _ => (),
+        }
+        self.action = Some(action);
+        self
+    }
+
+    /// Specifies that the argument may appear more than once. For flags, this results
+    /// in the number of occurrences of the flag being recorded. For example `-ddd` or `-d -d -d`
+    /// would count as three occurrences. For options there is a distinct difference in multiple
+    /// occurrences vs multiple values.
+    ///
+    /// For example, `--opt val1 val2` is one occurrence, but two values. Whereas
+    /// `--opt val1 --opt val2` is two occurrences.
+    ///
+    /// **WARNING:**
+    ///
+    /// Setting `multiple(true)` for an [option] with no other details, allows multiple values
+    /// **and** multiple occurrences because it isn't possible to have more occurrences than values for
+    /// options. Because multiple values are allowed, `--option val1 val2 val3` is perfectly valid,
+    /// be careful when designing a CLI where positional arguments are expected after a option which
+    /// accepts multiple values, as `clap` will continue parsing *values* until it reaches the max
+    /// or specific number of values defined, or another flag or option.
+    ///
+    /// **Pro Tip**:
+    ///
+    /// It's possible to define an option which allows multiple occurrences, but only one value per
+    /// occurrence. To do this use [`Arg::number_of_values(1)`] in coordination with
+    /// [`Arg::multiple(true)`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// Arg::with_name("debug")
+    ///     .short("d")
+    ///     .multiple(true)
+    /// # ;
+    /// ```
+    /// An example with flags
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// let m = App::new("mults")
+    ///     .arg(Arg::with_name("verbose")
     ///         .multiple(true)
     ///         .short("v"))
     ///     .get_matches_from(vec!["mults", "-v", "-v", "-v"]); // note, -vvv would have same result
@@ -1443,14 +2113,16 @@ impl<'a, 'b> Arg<'a, 'b> {
 
     /// Specifies that an argument can be matched to all child [`SubCommand`]s.
     ///
-    /// **NOTE:** Global arguments *only* propagate down, **not** up (to parent commands)
+    /// **NOTE:** Global argument definitions *only* propagate down, **not** up (to parent commands)
     ///
     /// **NOTE:** Global arguments *cannot* be [required].
     ///
-    /// **NOTE:** Global arguments, when matched, *only* exist in the command's matches that they
-    /// were matched to. For example, if you defined a `--flag` global argument in the top most
-    /// parent command, but the user supplied the arguments `top cmd1 cmd2 --flag` *only* `cmd2`'s
-    /// [`ArgMatches`] would return `true` if tested for [`ArgMatches::is_present("flag")`].
+    /// **NOTE:** Global arguments, when matched, exist in the [`ArgMatches`] of every command from
+    /// the one defining them down to the last subcommand used. For example, if you defined a
+    /// `--flag` global argument in the top most parent command, and the user supplied the
+    /// arguments `top cmd1 cmd2 --flag`, the matches of `top`, `cmd1` and `cmd2` would all return
+    /// `true` if tested for [`ArgMatches::is_present("flag")`]. If the argument is used at more
+    /// than one level, the values of the deepest use are the ones seen at every level.
     ///
     /// # Examples
     ///
@@ -1480,6 +2152,7 @@ impl<'a, 'b> Arg<'a, 'b> {
     /// assert_eq!(m.subcommand_name(), Some("do-stuff"));
     /// let sub_m = m.subcommand_matches("do-stuff").unwrap();
     /// assert!(sub_m.is_present("verb"));
+    /// assert!(m.is_present("verb"));
     /// ```
     /// [`SubCommand`]: ./struct.SubCommand.html
     /// [required]: ./struct.Arg.html#method.required
@@ -1851,6 +2524,73 @@ impl<'a, 'b> Arg<'a, 'b> {
         self
     }
 
+    /// Parses each value of the argument into type `T` using its [`FromStr`] implementation, and
+    /// stores the result in the [`ArgMatches`], where it's retrieved with
+    /// [`ArgMatches::get_one`] or [`ArgMatches::get_many`]. This removes the need to parse values
+    /// twice, once in a [`Arg::validator`] and again with [`value_t!`].
+    ///
+    /// A value which fails to parse is an [`ErrorKind::ValueValidation`] error, displaying the
+    /// message of the [`FromStr::Err`]. Parsing happens after any [`Arg::validator`] passed.
+    ///
+    /// **NOTE:** Only applies to arguments which take values, i.e. options and positionals.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// let m = App::new("prog")
+    ///     .arg(Arg::with_name("port")
+    ///         .long("port")
+    ///         .takes_value(true)
+    ///         .value_parser::<u16>())
+    ///     .get_matches_from(vec![
+    ///         "prog", "--port", "8080"
+    ///     ]);
+    ///
+    /// assert_eq!(m.get_one::<u16>("port").unwrap(), Some(&8080));
+    /// ```
+    ///
+    /// Values which don't parse are an error
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg, ErrorKind};
+    /// let res = App::new("prog")
+    ///     .arg(Arg::with_name("port")
+    ///         .long("port")
+    ///         .takes_value(true)
+    ///         .value_parser::<u16>())
+    ///     .get_matches_from_safe(vec![
+    ///         "prog", "--port", "http"
+    ///     ]);
+    ///
+    /// assert!(res.is_err());
+    /// assert_eq!(res.unwrap_err().kind, ErrorKind::ValueValidation);
+    /// ```
+    /// [`FromStr`]: https://doc.rust-lang.org/std/str/trait.FromStr.html
+    /// [`FromStr::Err`]: https://doc.rust-lang.org/std/str/trait.FromStr.html#associatedtype.Err
+    /// [`ArgMatches`]: ./struct.ArgMatches.html
+    /// [`ArgMatches::get_one`]: ./struct.ArgMatches.html#method.get_one
+    /// [`ArgMatches::get_many`]: ./struct.ArgMatches.html#method.get_many
+    /// [`Arg::validator`]: ./struct.Arg.html#method.validator
+    /// [`value_t!`]: ./macro.value_t!.html
+    /// [`ErrorKind::ValueValidation`]: ./enum.ErrorKind.html#variant.ValueValidation
+    pub fn value_parser<T>(mut self) -> Self
+        where T: FromStr + Any + Send + Sync,
+              <T as FromStr>::Err: Display
+    {
+        self.value_parser = Some(Rc::new(|v: &OsStr| {
+            let s = try!(v.to_str().ok_or_else(|| String::from(INVALID_UTF8)));
+            match s.parse::<T>() {
+                Ok(t) => {
+                    let parsed: Arc<Any + Send + Sync> = Arc::new(t);
+                    Ok(parsed)
+                }
+                Err(e) => Err(e.to_string()),
+            }
+        }));
+        self
+    }
+
     /// Specifies the *maximum* number of values are for this argument. For example, if you had a
     /// `-f <file>` argument where you wanted up to 3 'files' you would set `.max_values(3)`, and
     /// this argument would be satisfied if the user provided, 1, 2, or 3 values.
@@ -2118,6 +2858,63 @@ impl<'a, 'b> Arg<'a, 'b> {
         }
     }
 
+    /// Requires that the value of an option is attached with an equals, i.e. `--opt=val` or
+    /// `-o=val`. An argument following the option, such as `--opt val`, is then never taken as
+    /// its value.
+    ///
+    /// Using the option without an `=value` is an [`ErrorKind::EmptyValue`] error, unless the
+    /// value is optional because of [`Arg::default_missing_value`] or [`Arg::min_values(0)`].
+    /// Usage and help messages display the option as `--opt=<val>`, or `--opt[=<val>]` when the
+    /// value is optional.
+    ///
+    /// **NOTE:** This setting only applies to [options]
+    ///
+    /// **NOTE:** This implicitly sets [`Arg::takes_value(true)`]
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// let m = App::new("prog")
+    ///     .arg(Arg::with_name("cfg")
+    ///         .long("config")
+    ///         .require_equals(true))
+    ///     .get_matches_from(vec![
+    ///         "prog", "--config=file.conf"
+    ///     ]);
+    ///
+    /// assert_eq!(m.value_of("cfg"), Some("file.conf"));
+    /// ```
+    ///
+    /// Leaving off the equals is an error
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg, ErrorKind};
+    /// let res = App::new("prog")
+    ///     .arg(Arg::with_name("cfg")
+    ///         .long("config")
+    ///         .require_equals(true))
+    ///     .get_matches_from_safe(vec![
+    ///         "prog", "--config", "file.conf"
+    ///     ]);
+    ///
+    /// assert!(res.is_err());
+    /// assert_eq!(res.unwrap_err().kind, ErrorKind::EmptyValue);
+    /// ```
+    /// [`ErrorKind::EmptyValue`]: ./enum.ErrorKind.html#variant.EmptyValue
+    /// [`Arg::default_missing_value`]: ./struct.Arg.html#method.default_missing_value
+    /// [`Arg::min_values(0)`]: ./struct.Arg.html#method.min_values
+    /// [options]: ./struct.Arg.html#method.takes_value
+    /// [`Arg::takes_value(true)`]: ./struct.Arg.html#method.takes_value
+    pub fn require_equals(mut self, r: bool) -> Self {
+        if r {
+            self.setb(ArgSettings::TakesValue);
+            self.set(ArgSettings::RequireEquals)
+        } else {
+            self.unset(ArgSettings::RequireEquals)
+        }
+    }
+
     /// Specifies the separator to use when values are clumped together, defaults to `,` (comma).
     ///
     /// **NOTE:** implicitly sets [`Arg::use_delimiter(true)`]
@@ -2152,6 +2949,64 @@ impl<'a, 'b> Arg<'a, 'b> {
         self
     }
 
+    /// Specifies a value that *stops* parsing multiple values of a given argument. By default when
+    /// one sets [`multiple(true)`] on an argument, clap will continue parsing values for that
+    /// argument until it reaches another valid argument, or one of the other more specific settings
+    /// for multiple values is used (such as [`min_values`], [`max_values`] or
+    /// [`number_of_values`]).
+    ///
+    /// For options, every argument between the option and the terminator is taken as a value,
+    /// even those which start with a hyphen or match a subcommand, much like `find -exec`. The
+    /// terminator itself is not stored as a value.
+    ///
+    /// **NOTE:** This setting only applies to [options] and [positional arguments]
+    ///
+    /// **NOTE:** implicitly sets [`Arg::takes_value(true)`] and [`Arg::multiple(true)`]
+    ///
+    /// **NOTE:** When the terminator is passed in on the command line, it is **not** stored as one
+    /// of the values
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// Arg::with_name("exec")
+    ///     .long("exec")
+    ///     .value_terminator(";")
+    /// # ;
+    /// ```
+    /// The following example uses two arguments, a sequence of commands, and the location in which
+    /// to perform them
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// let m = App::new("prog")
+    ///     .arg(Arg::with_name("exec")
+    ///         .long("exec")
+    ///         .value_terminator(";"))
+    ///     .arg(Arg::with_name("location"))
+    ///     .get_matches_from(vec![
+    ///         "prog", "--exec", "ls", "-l", "{}", ";", "some_location"
+    ///     ]);
+    /// let cmds: Vec<_> = m.values_of("exec").unwrap().collect();
+    /// assert_eq!(&cmds, &["ls", "-l", "{}"]);
+    /// assert_eq!(m.value_of("location"), Some("some_location"));
+    /// ```
+    /// [options]: ./struct.Arg.html#method.takes_value
+    /// [positional arguments]: ./struct.Arg.html#method.index
+    /// [`multiple(true)`]: ./struct.Arg.html#method.multiple
+    /// [`min_values`]: ./struct.Arg.html#method.min_values
+    /// [`number_of_values`]: ./struct.Arg.html#method.number_of_values
+    /// [`max_values`]: ./struct.Arg.html#method.max_values
+    /// [`Arg::takes_value(true)`]: ./struct.Arg.html#method.takes_value
+    /// [`Arg::multiple(true)`]: ./struct.Arg.html#method.multiple
+    pub fn value_terminator(mut self, term: &'b str) -> Self {
+        self.setb(ArgSettings::TakesValue);
+        self.setb(ArgSettings::Multiple);
+        self.terminator = Some(term);
+        self
+    }
+
     /// Specify multiple names for values of option arguments. These names are cosmetic only, used
     /// for help and usage strings only. The names are **not** used to access arguments. The values
     /// of the arguments are accessed in numeric order (i.e. if you specify two names `one` and
@@ -2299,8 +3154,7 @@ impl<'a, 'b> Arg<'a, 'b> {
     ///
     /// **NOTE:** If the user *does not* use this argument at runtime [`ArgMatches::is_present`] will
     /// still return `true`. If you wish to determine whether the argument was used at runtime or
-    /// not, consider [`ArgMatches::occurrences_of`] which will return `0` if the argument was *not*
-    /// used at runtmie.
+    /// not, consider [`ArgMatches::is_explicitly_present`] or [`ArgMatches::value_source`].
     ///
     /// **NOTE:** This implicitly sets [`Arg::takes_value(true)`].
     ///
@@ -2343,12 +3197,542 @@ impl<'a, 'b> Arg<'a, 'b> {
     /// [`ArgMatches::value_of`]: ./struct.ArgMatches.html#method.value_of
     /// [`Arg::takes_value(true)`]: /struct.Arg.html#method.takes_value
     /// [`ArgMatches::is_present`]: /struct.ArgMatches.html#method.is_present
+    /// [`ArgMatches::is_explicitly_present`]: ./struct.ArgMatches.html#method.is_explicitly_present
+    /// [`ArgMatches::value_source`]: ./struct.ArgMatches.html#method.value_source
     pub fn default_value(mut self, val: &'a str) -> Self {
         self.setb(ArgSettings::TakesValue);
         self.default_val = Some(val);
         self
     }
 
+    /// Specifies the value of an option when it's used at runtime *without* a value, such as
+    /// `--color` meaning `--color=always`. This is distinct from [`Arg::default_value`], which
+    /// only applies when the option isn't used at all.
+    ///
+    /// Setting this makes the value of the option optional. Combined with
+    /// [`Arg::require_equals(true)`] the option only takes a value when one is attached with `=`,
+    /// so it never consumes the following argument.
+    ///
+    /// **NOTE:** This setting only applies to [options]
+    ///
+    /// **NOTE:** This implicitly sets [`Arg::takes_value(true)`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// let app = App::new("prog")
+    ///     .arg(Arg::with_name("color")
+    ///         .long("color")
+    ///         .value_name("WHEN")
+    ///         .possible_values(&["always", "auto", "never"])
+    ///         .default_value("auto")
+    ///         .default_missing_value("always")
+    ///         .require_equals(true))
+    ///     .arg(Arg::with_name("file"));
+    ///
+    /// let m = app.clone().get_matches_from(vec!["prog", "--color", "file.txt"]);
+    /// assert_eq!(m.value_of("color"), Some("always"));
+    /// assert_eq!(m.value_of("file"), Some("file.txt"));
+    ///
+    /// let m = app.clone().get_matches_from(vec!["prog", "--color=never"]);
+    /// assert_eq!(m.value_of("color"), Some("never"));
+    ///
+    /// let m = app.get_matches_from(vec!["prog"]);
+    /// assert_eq!(m.value_of("color"), Some("auto"));
+    /// ```
+    /// [`Arg::default_value`]: ./struct.Arg.html#method.default_value
+    /// [`Arg::require_equals(true)`]: ./struct.Arg.html#method.require_equals
+    /// [options]: ./struct.Arg.html#method.takes_value
+    /// [`Arg::takes_value(true)`]: ./struct.Arg.html#method.takes_value
+    pub fn default_missing_value(mut self, val: &'a str) -> Self {
+        self.setb(ArgSettings::TakesValue);
+        self.default_missing_val = Some(val);
+        self
+    }
+
+    /// Specifies the value of the argument if `arg` has been used at runtime. If `val` is set to
+    /// `None`, `arg` only needs to be present. If `val` is set to `"some-val"` then `arg` must be
+    /// present at runtime **and** have the value `val`. To use any other condition on the value,
+    /// see [`Arg::default_value_if_fn`].
+    ///
+    /// **NOTE:** This setting is perfectly compatible with [`Arg::default_value`] but slightly
+    /// different. [`Arg::default_value`] *only* takes effect when the user has not provided this
+    /// arg at runtime. This setting however only takes effect when the user has not provided a
+    /// value at runtime **and** these other conditions are met as well. If you have set both
+    /// [`Arg::default_value`] and [`Arg::default_value_if`], and the user **did not** provide this
+    /// arg at runtime, nor were the conditions met for `Arg::default_value_if`, the
+    /// [`Arg::default_value`] will be applied.
+    ///
+    /// **NOTE:** This implicitly sets [`Arg::takes_value(true)`].
+    ///
+    /// # Examples
+    ///
+    /// First we use the default value only if another arg is present at runtime.
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// let m = App::new("dvif")
+    ///     .arg(Arg::with_name("flag")
+    ///         .long("flag"))
+    ///     .arg(Arg::with_name("other")
+    ///         .long("other")
+    ///         .default_value_if("flag", None, "default"))
+    ///     .get_matches_from(vec![
+    ///         "dvif", "--flag"
+    ///     ]);
+    ///
+    /// assert_eq!(m.value_of("other"), Some("default"));
+    /// ```
+    ///
+    /// Next we run the same test, but without providing `--flag`.
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// let m = App::new("dvif")
+    ///     .arg(Arg::with_name("flag")
+    ///         .long("flag"))
+    ///     .arg(Arg::with_name("other")
+    ///         .long("other")
+    ///         .default_value_if("flag", None, "default"))
+    ///     .get_matches_from(vec![
+    ///         "dvif"
+    ///     ]);
+    ///
+    /// assert_eq!(m.value_of("other"), None);
+    /// ```
+    ///
+    /// Now lets only use the default value if `--opt` contains the value `special`.
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// let m = App::new("dvif")
+    ///     .arg(Arg::with_name("opt")
+    ///         .takes_value(true)
+    ///         .long("opt"))
+    ///     .arg(Arg::with_name("other")
+    ///         .long("other")
+    ///         .default_value_if("opt", Some("special"), "default"))
+    ///     .get_matches_from(vec![
+    ///         "dvif", "--opt", "special"
+    ///     ]);
+    ///
+    /// assert_eq!(m.value_of("other"), Some("default"));
+    /// ```
+    ///
+    /// We can run the same test and provide any value *other than* `special` and we won't get a
+    /// default value.
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// let m = App::new("dvif")
+    ///     .arg(Arg::with_name("opt")
+    ///         .takes_value(true)
+    ///         .long("opt"))
+    ///     .arg(Arg::with_name("other")
+    ///         .long("other")
+    ///         .default_value_if("opt", Some("special"), "default"))
+    ///     .get_matches_from(vec![
+    ///         "dvif", "--opt", "hahaha"
+    ///     ]);
+    ///
+    /// assert_eq!(m.value_of("other"), None);
+    /// ```
+    ///
+    /// If we want to conditionally set the default value, but still use the regular default
+    /// value if the condition isn't met we can combine [`Arg::default_value_if`] with
+    /// [`Arg::default_value`].
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// let m = App::new("dvif")
+    ///     .arg(Arg::with_name("flag")
+    ///         .long("flag"))
+    ///     .arg(Arg::with_name("other")
+    ///         .long("other")
+    ///         .default_value("default")
+    ///         .default_value_if("flag", None, "conditional"))
+    ///     .get_matches_from(vec![
+    ///         "dvif"
+    ///     ]);
+    ///
+    /// assert_eq!(m.value_of("other"), Some("default"));
+    /// ```
+    /// [`Arg::takes_value(true)`]: ./struct.Arg.html#method.takes_value
+    /// [`Arg::default_value`]: ./struct.Arg.html#method.default_value
+    /// [`Arg::default_value_if`]: ./struct.Arg.html#method.default_value_if
+    /// [`Arg::default_value_if_fn`]: ./struct.Arg.html#method.default_value_if_fn
+    pub fn default_value_if(mut self,
+                            arg: &'a str,
+                            val: Option<&'b str>,
+                            default: &'b str)
+                            -> Self {
+        self.setb(ArgSettings::TakesValue);
+        // Keys are shared with Arg::default_value_if_fn, so conditions keep the order they were
+        // added in
+        let i = self.default_vals_if_count();
+        if let Some(ref mut vm) = self.default_vals_ifs {
+            vm.insert(i, (arg, val, default));
+        } else {
+            let mut vm = VecMap::new();
+            vm.insert(i, (arg, val, default));
+            self.default_vals_ifs = Some(vm);
+        }
+        self
+    }
+
+    /// Specifies the value of the argument if `arg` has been used at runtime with a value for
+    /// which `f` returns `true`, i.e. the same as [`Arg::default_value_if`] but with any condition
+    /// on the value instead of only equality. Conditions added with either method are evaluated in
+    /// the order they were added, and the first one met sets the default.
+    ///
+    /// **NOTE:** Unlike [`Arg::default_value_if`], these conditional defaults aren't shown in the
+    /// help message, since there's no way to describe the condition, and they can't be set from
+    /// YAML.
+    ///
+    /// **NOTE:** This implicitly sets [`Arg::takes_value(true)`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// let m = App::new("dvif")
+    ///     .arg(Arg::with_name("output")
+    ///         .long("output")
+    ///         .takes_value(true))
+    ///     .arg(Arg::with_name("format")
+    ///         .long("format")
+    ///         .default_value("text")
+    ///         .default_value_if_fn("output",
+    ///                              |v| v.to_string_lossy().ends_with(".json"),
+    ///                              "json"))
+    ///     .get_matches_from(vec![
+    ///         "dvif", "--output", "report.json"
+    ///     ]);
+    ///
+    /// assert_eq!(m.value_of("format"), Some("json"));
+    /// ```
+    /// [`Arg::default_value_if`]: ./struct.Arg.html#method.default_value_if
+    /// [`Arg::takes_value(true)`]: ./struct.Arg.html#method.takes_value
+    pub fn default_value_if_fn<F>(mut self, arg: &'a str, f: F, default: &'b str) -> Self
+        where F: Fn(&OsStr) -> bool + 'static
+    {
+        self.setb(ArgSettings::TakesValue);
+        let i = self.default_vals_if_count();
+        let f: Rc<Fn(&OsStr) -> bool> = Rc::new(f);
+        if let Some(ref mut vm) = self.default_vals_if_fns {
+            vm.insert(i, (arg, f, default));
+        } else {
+            let mut vm = VecMap::new();
+            vm.insert(i, (arg, f, default));
+            self.default_vals_if_fns = Some(vm);
+        }
+        self
+    }
+
+    fn default_vals_if_count(&self) -> usize {
+        self.default_vals_ifs.as_ref().map_or(0, |vm| vm.len()) +
+        self.default_vals_if_fns.as_ref().map_or(0, |vm| vm.len())
+    }
+
+    /// Specifies multiple values and conditions in the same manner as [`Arg::default_value_if`].
+    /// The method takes a slice of tuples in the `(arg, Option<val>, default)` format.
+    ///
+    /// **NOTE**: The conditions are stored in order and evaluated in the same order. I.e. if
+    /// multiple conditions are true, the first one found will be applied as the ultimate value.
+    ///
+    /// **NOTE:** This implicitly sets [`Arg::takes_value(true)`].
+    ///
+    /// # Examples
+    ///
+    /// First we use the default value only if another arg is present at runtime.
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// let m = App::new("dvif")
+    ///     .arg(Arg::with_name("flag")
+    ///         .long("flag"))
+    ///     .arg(Arg::with_name("opt")
+    ///         .long("opt")
+    ///         .takes_value(true))
+    ///     .arg(Arg::with_name("other")
+    ///         .long("other")
+    ///         .default_value_ifs(&[
+    ///             ("flag", None, "default"),
+    ///             ("opt", Some("channal"), "chan"),
+    ///         ]))
+    ///     .get_matches_from(vec![
+    ///         "dvif", "--opt", "channal"
+    ///     ]);
+    ///
+    /// assert_eq!(m.value_of("other"), Some("chan"));
+    /// ```
+    ///
+    /// Next we run the same test, but without providing `--flag`.
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// let m = App::new("dvif")
+    ///     .arg(Arg::with_name("flag")
+    ///         .long("flag"))
+    ///     .arg(Arg::with_name("other")
+    ///         .long("other")
+    ///         .default_value_ifs(&[
+    ///             ("flag", None, "default"),
+    ///             ("opt", Some("channal"), "chan"),
+    ///         ]))
+    ///     .get_matches_from(vec![
+    ///         "dvif"
+    ///     ]);
+    ///
+    /// assert_eq!(m.value_of("other"), None);
+    /// ```
+    ///
+    /// We can also see that these values are applied in order, and if more than one condition is
+    /// true, only the first evaluated "wins"
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// let m = App::new("dvif")
+    ///     .arg(Arg::with_name("flag")
+    ///         .long("flag"))
+    ///     .arg(Arg::with_name("opt")
+    ///         .long("opt")
+    ///         .takes_value(true))
+    ///     .arg(Arg::with_name("other")
+    ///         .long("other")
+    ///         .default_value_ifs(&[
+    ///             ("flag", None, "default"),
+    ///             ("opt", Some("channal"), "chan"),
+    ///         ]))
+    ///     .get_matches_from(vec![
+    ///         "dvif", "--opt", "channal", "--flag"
+    ///     ]);
+    ///
+    /// assert_eq!(m.value_of("other"), Some("default"));
+    /// ```
+    /// [`Arg::takes_value(true)`]: ./struct.Arg.html#method.takes_value
+    /// [`Arg::default_value_if`]: ./struct.Arg.html#method.default_value_if
+    pub fn default_value_ifs(mut self, ifs: &[(&'a str, Option<&'b str>, &'b str)]) -> Self {
+        for &(arg, val, default) in ifs {
+            self = self.default_value_if(arg, val, default);
+        }
+        self
+    }
+
+    /// Specifies that if the value is not passed in as an argument, that it should be retrieved
+    /// from the environment, if available. If it is not present in the environment, then default
+    /// rules will apply.
+    ///
+    /// **NOTE:** Values taken from the environment are resolved *before* any
+    /// [`Arg::default_value`] and are subject to the same validation as values passed at runtime
+    /// (i.e. [`Arg::possible_values`], [`Arg::validator`], conflicts and requirements).
+    ///
+    /// **NOTE:** If the value is taken from the environment [`ArgMatches::occurrences_of`] will
+    /// return `0` just like it does for [`Arg::default_value`].
+    ///
+    /// **NOTE:** This implicitly sets [`Arg::takes_value(true)`].
+    ///
+    /// # Examples
+    ///
+    /// In this example, we show the variable coming from the environment:
+    ///
+    /// ```rust
+    /// # use std::env;
+    /// # use clap::{App, Arg};
+    ///
+    /// env::set_var("MY_FLAG", "env");
+    ///
+    /// let m = App::new("prog")
+    ///     .arg(Arg::with_name("flag")
+    ///         .long("flag")
+    ///         .env("MY_FLAG"))
+    ///     .get_matches_from(vec![
+    ///         "prog"
+    ///     ]);
+    ///
+    /// assert_eq!(m.value_of("flag"), Some("env"));
+    /// assert_eq!(m.occurrences_of("flag"), 0);
+    /// ```
+    ///
+    /// In this example, we show the variable coming from an option on the CLI:
+    ///
+    /// ```rust
+    /// # use std::env;
+    /// # use clap::{App, Arg};
+    ///
+    /// env::set_var("MY_FLAG_OPT", "env");
+    ///
+    /// let m = App::new("prog")
+    ///     .arg(Arg::with_name("flag")
+    ///         .long("flag")
+    ///         .env("MY_FLAG_OPT"))
+    ///     .get_matches_from(vec![
+    ///         "prog", "--flag", "opt"
+    ///     ]);
+    ///
+    /// assert_eq!(m.value_of("flag"), Some("opt"));
+    /// ```
+    ///
+    /// In this example, we show the variable coming from the environment even with the
+    /// presence of a default:
+    ///
+    /// ```rust
+    /// # use std::env;
+    /// # use clap::{App, Arg};
+    ///
+    /// env::set_var("MY_FLAG_DEF", "env");
+    ///
+    /// let m = App::new("prog")
+    ///     .arg(Arg::with_name("flag")
+    ///         .long("flag")
+    ///         .env("MY_FLAG_DEF")
+    ///         .default_value("default"))
+    ///     .get_matches_from(vec![
+    ///         "prog"
+    ///     ]);
+    ///
+    /// assert_eq!(m.value_of("flag"), Some("env"));
+    /// ```
+    /// [`Arg::default_value`]: ./struct.Arg.html#method.default_value
+    /// [`Arg::possible_values`]: ./struct.Arg.html#method.possible_values
+    /// [`Arg::validator`]: ./struct.Arg.html#method.validator
+    /// [`ArgMatches::occurrences_of`]: ./struct.ArgMatches.html#method.occurrences_of
+    /// [`Arg::takes_value(true)`]: ./struct.Arg.html#method.takes_value
+    pub fn env(self, name: &'a str) -> Self {
+        self.env_os(OsStr::new(name))
+    }
+
+    /// Specifies that if the value is not passed in as an argument, that it should be retrieved
+    /// from the environment if available in the exact same manner as [`Arg::env`] only using
+    /// [`OsStr`]s instead.
+    /// [`Arg::env`]: ./struct.Arg.html#method.env
+    /// [`OsStr`]: https://doc.rust-lang.org/std/ffi/struct.OsStr.html
+    pub fn env_os(mut self, name: &'a OsStr) -> Self {
+        self.setb(ArgSettings::TakesValue);
+        self.env = Some((name, env::var_os(name)));
+        self
+    }
+
+    /// Specifies if the current value of the argument's [environment variable] should be hidden
+    /// in the help text, i.e. `[env: NAME]` instead of `[env: NAME=value]`. Defaults to `false`
+    /// (i.e. show the value)
+    ///
+    /// This is useful for variables holding secrets such as tokens or passwords, which would
+    /// otherwise be shown by `--help` and end up in any logs capturing its output.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// let m = App::new("prog")
+    ///     .arg(Arg::with_name("token")
+    ///         .long("token")
+    ///         .env("API_TOKEN")
+    ///         .hide_env_values(true));
+    /// ```
+    ///
+    /// If we were to run the above program with `--help` the value of `API_TOKEN` would be
+    /// omitted from the `[env: API_TOKEN]` portion of the help text.
+    /// [environment variable]: ./struct.Arg.html#method.env
+    pub fn hide_env_values(self, hide: bool) -> Self {
+        if hide {
+            self.set(ArgSettings::HideEnvValues)
+        } else {
+            self.unset(ArgSettings::HideEnvValues)
+        }
+    }
+
+    /// Asks the user for the value of a [required] argument which wasn't used, instead of failing
+    /// with an [`ErrorKind::MissingRequiredArgument`] error. The prompt is written to `stderr`
+    /// and the value read from `stdin`, but only when `stdin` is a terminal, or when other
+    /// streams were given with [`App::prompt_streams`]. Otherwise the error is reported as usual.
+    ///
+    /// If the argument has [possible values], they are listed as a numbered menu and the user may
+    /// answer with either the number or the value. An empty or invalid answer asks again, and the
+    /// end of the input gives up with the usual error.
+    ///
+    /// **NOTE:** The value goes through the same validation as values from the command line, and
+    /// counts as one occurrence of the argument.
+    ///
+    /// **NOTE:** This setting implies [`Arg::takes_value(true)`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// # use std::io::{self, Cursor};
+    /// let m = App::new("prog")
+    ///     .prompt_streams(Cursor::new("prod\n"), io::sink())
+    ///     .arg(Arg::with_name("cluster")
+    ///         .long("cluster")
+    ///         .required(true)
+    ///         .prompt("Cluster name"))
+    ///     .get_matches_from(vec!["prog"]);
+    ///
+    /// assert_eq!(m.value_of("cluster"), Some("prod"));
+    /// ```
+    ///
+    /// With possible values, the answer can be the number of a choice
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// # use std::io::{self, Cursor};
+    /// let m = App::new("prog")
+    ///     .prompt_streams(Cursor::new("2\n"), io::sink())
+    ///     .arg(Arg::with_name("cluster")
+    ///         .long("cluster")
+    ///         .required(true)
+    ///         .possible_values(&["prod", "staging"])
+    ///         .prompt("Cluster name"))
+    ///     .get_matches_from(vec!["prog"]);
+    ///
+    /// assert_eq!(m.value_of("cluster"), Some("staging"));
+    /// ```
+    /// [required]: ./struct.Arg.html#method.required
+    /// [`ErrorKind::MissingRequiredArgument`]: ./enum.ErrorKind.html#variant.MissingRequiredArgument
+    /// [`App::prompt_streams`]: ./struct.App.html#method.prompt_streams
+    /// [possible values]: ./struct.Arg.html#method.possible_values
+    /// [`Arg::takes_value(true)`]: ./struct.Arg.html#method.takes_value
+    pub fn prompt(mut self, text: &'b str) -> Self {
+        self.setb(ArgSettings::TakesValue);
+        self.prompt = Some(text);
+        self
+    }
+
+    /// Doesn't echo what the user types when [prompted] for the value of this argument, i.e. for
+    /// passwords and other secrets.
+    ///
+    /// **NOTE:** Hiding the input requires the `color` Cargo feature (which is on by default) and a
+    /// Unix-like OS. When the terminal's echo can't be turned off, the user isn't prompted and
+    /// parsing fails with an [`ErrorKind::Io`] error instead of showing the secret.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// # use std::io::{self, Cursor};
+    /// let m = App::new("prog")
+    ///     .prompt_streams(Cursor::new("hunter2\n"), io::sink())
+    ///     .arg(Arg::with_name("password")
+    ///         .long("password")
+    ///         .required(true)
+    ///         .prompt("Password")
+    ///         .hide_input(true))
+    ///     .get_matches_from(vec!["prog"]);
+    ///
+    /// assert_eq!(m.value_of("password"), Some("hunter2"));
+    /// ```
+    /// [prompted]: ./struct.Arg.html#method.prompt
+    /// [`ErrorKind::Io`]: ./enum.ErrorKind.html#variant.Io
+    pub fn hide_input(self, h: bool) -> Self {
+        if h {
+            self.set(ArgSettings::HideInput)
+        } else {
+            self.unset(ArgSettings::HideInput)
+        }
+    }
+
     /// When set to `true` the help string will be displayed on the line after the argument and
     /// indented once. This can be helpful for arguments with very long or complex help messages.
     /// This can also be helpful for arguments with very long flag names, or many/long value names.
@@ -2502,6 +3886,7 @@ impl<'a, 'b, 'z> From<&'z Arg<'a, 'b>> for Arg<'a, 'b> {
             long: a.long,
             aliases: a.aliases.clone(),
             help: a.help,
+            long_help: a.long_help,
             index: a.index,
             possible_vals: a.possible_vals.clone(),
             blacklist: a.blacklist.clone(),
@@ -2512,12 +3897,22 @@ impl<'a, 'b, 'z> From<&'z Arg<'a, 'b>> for Arg<'a, 'b> {
             val_names: a.val_names.clone(),
             group: a.group.clone(),
             validator: a.validator.clone(),
+            value_parser: a.value_parser.clone(),
             overrides: a.overrides.clone(),
             settings: a.settings,
             val_delim: a.val_delim,
             default_val: a.default_val,
+            default_missing_val: a.default_missing_val,
+            default_vals_ifs: a.default_vals_ifs.clone(),
+            default_vals_if_fns: a.default_vals_if_fns.clone(),
+            env: a.env.clone(),
             disp_ord: a.disp_ord,
             r_unless: a.r_unless.clone(),
+            r_ifs: a.r_ifs.clone(),
+            requires_ifs: a.requires_ifs.clone(),
+            terminator: a.terminator,
+            action: a.action.clone(),
+            prompt: a.prompt,
         }
     }
 }
@@ -2530,6 +3925,7 @@ impl<'a, 'b> Clone for Arg<'a, 'b> {
             long: self.long,
             aliases: self.aliases.clone(),
             help: self.help,
+            long_help: self.long_help,
             index: self.index,
             possible_vals: self.possible_vals.clone(),
             blacklist: self.blacklist.clone(),
@@ -2540,12 +3936,22 @@ impl<'a, 'b> Clone for Arg<'a, 'b> {
             val_names: self.val_names.clone(),
             group: self.group.clone(),
             validator: self.validator.clone(),
+            value_parser: self.value_parser.clone(),
             overrides: self.overrides.clone(),
             settings: self.settings,
             val_delim: self.val_delim,
             default_val: self.default_val,
+            default_missing_val: self.default_missing_val,
+            default_vals_ifs: self.default_vals_ifs.clone(),
+            default_vals_if_fns: self.default_vals_if_fns.clone(),
+            env: self.env.clone(),
             disp_ord: self.disp_ord,
             r_unless: self.r_unless.clone(),
+            r_ifs: self.r_ifs.clone(),
+            requires_ifs: self.requires_ifs.clone(),
+            terminator: self.terminator,
+            action: self.action.clone(),
+            prompt: self.prompt,
         }
     }
 }
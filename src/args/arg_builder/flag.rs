@@ -1,15 +1,18 @@
 // Std
 use std::convert::From;
+use std::any::Any;
+use std::ffi::{OsStr, OsString};
 use std::fmt::{Display, Formatter, Result};
 use std::rc::Rc;
+use std::sync::Arc;
 use std::result::Result as StdResult;
 
 // Third Party
-use vec_map::VecMap;
+use vec_map::{self, VecMap};
 
 // Internal
 use Arg;
-use args::{AnyArg, DispOrder};
+use args::{AnyArg, ArgAction, DispOrder};
 use args::settings::{ArgFlags, ArgSettings};
 
 #[derive(Debug)]
@@ -19,6 +22,7 @@ pub struct FlagBuilder<'n, 'e> {
     pub long: Option<&'e str>,
     pub aliases: Option<Vec<(&'e str, bool)>>,
     pub help: Option<&'e str>,
+    pub long_help: Option<&'e str>,
     pub blacklist: Option<Vec<&'e str>>,
     pub requires: Option<Vec<&'e str>>,
     pub short: Option<char>,
@@ -26,6 +30,7 @@ pub struct FlagBuilder<'n, 'e> {
     pub settings: ArgFlags,
     pub disp_ord: usize,
     pub unified_ord: usize,
+    pub action: Option<ArgAction>,
 }
 
 impl<'n, 'e> Default for FlagBuilder<'n, 'e> {
@@ -35,6 +40,7 @@ impl<'n, 'e> Default for FlagBuilder<'n, 'e> {
             long: None,
             aliases: None,
             help: None,
+            long_help: None,
             blacklist: None,
             requires: None,
             short: None,
@@ -42,6 +48,7 @@ impl<'n, 'e> Default for FlagBuilder<'n, 'e> {
             settings: ArgFlags::new(),
             disp_ord: 999,
             unified_ord: 999,
+            action: None,
         }
     }
 }
@@ -58,6 +65,14 @@ impl<'a, 'b, 'z> From<&'z Arg<'a, 'b>> for FlagBuilder<'a, 'b> {
                 format!("The argument '{}' has a validator set, yet was parsed as a flag. Ensure \
                 .takes_value(true) or .index(u64) is set.",
                         a.name));
+        assert!(a.value_parser.is_none(),
+                format!("The argument '{}' has a value parser set, yet was parsed as a flag. \
+                Ensure .takes_value(true) or .index(u64) is set.",
+                        a.name));
+        assert!(a.prompt.is_none(),
+                format!("The argument '{}' has a prompt set, yet was parsed as a flag. Ensure \
+                .takes_value(true) or .index(u64) is set.",
+                        a.name));
         assert!(a.possible_vals.is_none(),
                 format!("The argument '{}' cannot have a specific value set because it doesn't \
                 have takes_value(true) set",
@@ -66,6 +81,15 @@ impl<'a, 'b, 'z> From<&'z Arg<'a, 'b>> for FlagBuilder<'a, 'b> {
                 format!("The argument '{}' cannot be required because it's a flag, perhaps you \
                 forgot takes_value(true)?",
                         a.name));
+        assert!(a.r_ifs.is_none() && a.requires_ifs.is_none(),
+                format!("The argument '{}' cannot have conditional requirements because it's a \
+                flag, perhaps you forgot takes_value(true)?",
+                        a.name));
+        assert!(!a.is_set(ArgSettings::Negatable) || a.long.is_some(),
+                format!("The argument '{}' cannot be negatable because it doesn't have a long \
+                version, perhaps you forgot long(\"{}\")?",
+                        a.name,
+                        a.name));
         // No need to check for index() or takes_value() as that is handled above
 
         FlagBuilder {
@@ -74,11 +98,13 @@ impl<'a, 'b, 'z> From<&'z Arg<'a, 'b>> for FlagBuilder<'a, 'b> {
             long: a.long,
             aliases: a.aliases.clone(),
             help: a.help,
+            long_help: a.long_help,
             blacklist: a.blacklist.clone(),
             overrides: a.overrides.clone(),
             requires: a.requires.clone(),
             settings: a.settings,
             disp_ord: a.disp_ord,
+            action: a.action.clone(),
             ..Default::default()
         }
     }
@@ -104,12 +130,14 @@ impl<'n, 'e> Clone for FlagBuilder<'n, 'e> {
             long: self.long,
             aliases: self.aliases.clone(),
             help: self.help,
+            long_help: self.long_help,
             blacklist: self.blacklist.clone(),
             overrides: self.overrides.clone(),
             requires: self.requires.clone(),
             settings: self.settings,
             disp_ord: self.disp_ord,
             unified_ord: self.unified_ord,
+            action: self.action.clone(),
         }
     }
 }
@@ -157,6 +185,9 @@ impl<'n, 'e> AnyArg<'n, 'e> for FlagBuilder<'n, 'e> {
     fn validator(&self) -> Option<&Rc<Fn(String) -> StdResult<(), String>>> {
         None
     }
+    fn value_parser(&self) -> Option<&Rc<Fn(&OsStr) -> StdResult<Arc<Any + Send + Sync>, String>>> {
+        None
+    }
     fn min_vals(&self) -> Option<u64> {
         None
     }
@@ -172,9 +203,30 @@ impl<'n, 'e> AnyArg<'n, 'e> for FlagBuilder<'n, 'e> {
     fn help(&self) -> Option<&'e str> {
         self.help
     }
+    fn long_help(&self) -> Option<&'e str> {
+        self.long_help
+    }
+    fn val_terminator(&self) -> Option<&'e str> {
+        None
+    }
     fn default_val(&self) -> Option<&'n str> {
         None
     }
+    fn default_missing_val(&self) -> Option<&'n str> {
+        None
+    }
+    fn prompt(&self) -> Option<&'e str> {
+        None
+    }
+    fn action(&self) -> Option<&ArgAction> {
+        self.action.as_ref()
+    }
+    fn default_vals_ifs(&self) -> Option<vec_map::Values<(&'n str, Option<&'e str>, &'e str)>> {
+        None
+    }
+    fn env<'s>(&'s self) -> Option<(&'n OsStr, Option<&'s OsString>)> {
+        None
+    }
     fn longest_filter(&self) -> bool {
         self.long.is_some()
     }
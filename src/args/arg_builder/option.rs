@@ -1,13 +1,16 @@
 // Std
+use std::any::Any;
+use std::ffi::{OsStr, OsString};
 use std::fmt::{Display, Formatter, Result};
 use std::rc::Rc;
+use std::sync::Arc;
 use std::result::Result as StdResult;
 
 // Third Party
-use vec_map::VecMap;
+use vec_map::{self, VecMap};
 
 // Internal
-use args::{AnyArg, Arg, DispOrder};
+use args::{AnyArg, Arg, ArgAction, DispOrder};
 use args::settings::{ArgFlags, ArgSettings};
 
 #[allow(missing_debug_implementations)]
@@ -18,6 +21,7 @@ pub struct OptBuilder<'n, 'e> {
     pub long: Option<&'e str>,
     pub aliases: Option<Vec<(&'e str, bool)>>,
     pub help: Option<&'e str>,
+    pub long_help: Option<&'e str>,
     pub blacklist: Option<Vec<&'e str>>,
     pub possible_vals: Option<Vec<&'e str>>,
     pub requires: Option<Vec<&'e str>>,
@@ -26,13 +30,23 @@ pub struct OptBuilder<'n, 'e> {
     pub max_vals: Option<u64>,
     pub val_names: Option<VecMap<&'e str>>,
     pub validator: Option<Rc<Fn(String) -> StdResult<(), String>>>,
+    pub value_parser: Option<Rc<Fn(&OsStr) -> StdResult<Arc<Any + Send + Sync>, String>>>,
     pub overrides: Option<Vec<&'e str>>,
     pub settings: ArgFlags,
     pub val_delim: Option<char>,
     pub default_val: Option<&'n str>,
+    pub default_missing_val: Option<&'n str>,
+    pub default_vals_ifs: Option<VecMap<(&'n str, Option<&'e str>, &'e str)>>,
+    pub default_vals_if_fns: Option<VecMap<(&'n str, Rc<Fn(&OsStr) -> bool>, &'e str)>>,
+    pub env: Option<(&'n OsStr, Option<OsString>)>,
     pub disp_ord: usize,
     pub unified_ord: usize,
     pub r_unless: Option<Vec<&'e str>>,
+    pub r_ifs: Option<Vec<(&'n str, &'e str)>>,
+    pub requires_ifs: Option<Vec<(&'e str, &'n str)>>,
+    pub terminator: Option<&'e str>,
+    pub action: Option<ArgAction>,
+    pub prompt: Option<&'e str>,
 }
 
 impl<'n, 'e> Default for OptBuilder<'n, 'e> {
@@ -43,6 +57,7 @@ impl<'n, 'e> Default for OptBuilder<'n, 'e> {
             long: None,
             aliases: None,
             help: None,
+            long_help: None,
             blacklist: None,
             possible_vals: None,
             requires: None,
@@ -51,13 +66,23 @@ impl<'n, 'e> Default for OptBuilder<'n, 'e> {
             max_vals: None,
             val_names: None,
             validator: None,
+            value_parser: None,
             overrides: None,
             settings: ArgFlags::new(),
             val_delim: Some(','),
             default_val: None,
+            default_missing_val: None,
+            default_vals_ifs: None,
+            default_vals_if_fns: None,
+            env: None,
             disp_ord: 999,
             unified_ord: 999,
             r_unless: None,
+            r_ifs: None,
+            requires_ifs: None,
+            terminator: None,
+            action: None,
+            prompt: None,
         }
     }
 }
@@ -80,6 +105,7 @@ impl<'n, 'e> OptBuilder<'n, 'e> {
             long: a.long,
             aliases: a.aliases.clone(),
             help: a.help,
+            long_help: a.long_help,
             num_vals: a.num_vals,
             min_vals: a.min_vals,
             max_vals: a.max_vals,
@@ -91,8 +117,17 @@ impl<'n, 'e> OptBuilder<'n, 'e> {
             possible_vals: a.possible_vals.clone(),
             settings: a.settings,
             default_val: a.default_val,
+            default_missing_val: a.default_missing_val,
+            default_vals_ifs: a.default_vals_ifs.clone(),
+            default_vals_if_fns: a.default_vals_if_fns.clone(),
+            env: a.env.clone(),
             disp_ord: a.disp_ord,
             r_unless: a.r_unless.clone(),
+            r_ifs: a.r_ifs.clone(),
+            requires_ifs: a.requires_ifs.clone(),
+            terminator: a.terminator,
+            action: a.action.clone(),
+            prompt: a.prompt,
             ..Default::default()
         };
         if let Some(ref vec) = ob.val_names {
@@ -108,6 +143,9 @@ impl<'n, 'e> OptBuilder<'n, 'e> {
         if let Some(ref p) = a.validator {
             ob.validator = Some(p.clone());
         }
+        if let Some(ref p) = a.value_parser {
+            ob.value_parser = Some(p.clone());
+        }
         // If the arg is required, add all it's requirements to master required list
         if a.is_set(ArgSettings::Required) {
             if let Some(ref areqs) = a.requires {
@@ -123,11 +161,22 @@ impl<'n, 'e> OptBuilder<'n, 'e> {
 impl<'n, 'e> Display for OptBuilder<'n, 'e> {
     fn fmt(&self, f: &mut Formatter) -> Result {
         debugln!("fn=fmt");
+        // Options which require an equals, and whose value may be left off, render as
+        // --long[=<val>]
+        let optional = self.is_set(ArgSettings::RequireEquals) &&
+                       (self.default_missing_val.is_some() || self.min_vals == Some(0));
+        let sep = if optional {
+            "[="
+        } else if self.is_set(ArgSettings::RequireEquals) {
+            "="
+        } else {
+            " "
+        };
         // Write the name such --long or -l
         if let Some(l) = self.long {
-            try!(write!(f, "--{} ", l));
+            try!(write!(f, "--{}{}", l, sep));
         } else {
-            try!(write!(f, "-{} ", self.short.unwrap()));
+            try!(write!(f, "-{}{}", self.short.unwrap(), sep));
         }
 
         // Write the values such as <name1> <name2>
@@ -162,6 +211,15 @@ impl<'n, 'e> Display for OptBuilder<'n, 'e> {
                         }));
         }
 
+        if optional {
+            try!(write!(f, "]"));
+        }
+
+        // Write the value terminator such as `;`
+        if let Some(t) = self.terminator {
+            try!(write!(f, " {}", t));
+        }
+
         Ok(())
     }
 }
@@ -174,6 +232,7 @@ impl<'n, 'e> Clone for OptBuilder<'n, 'e> {
             long: self.long,
             aliases: self.aliases.clone(),
             help: self.help,
+            long_help: self.long_help,
             blacklist: self.blacklist.clone(),
             overrides: self.overrides.clone(),
             requires: self.requires.clone(),
@@ -187,8 +246,18 @@ impl<'n, 'e> Clone for OptBuilder<'n, 'e> {
             val_delim: self.val_delim,
             possible_vals: self.possible_vals.clone(),
             default_val: self.default_val,
+            default_missing_val: self.default_missing_val,
+            default_vals_ifs: self.default_vals_ifs.clone(),
+            default_vals_if_fns: self.default_vals_if_fns.clone(),
+            env: self.env.clone(),
             validator: self.validator.clone(),
+            value_parser: self.value_parser.clone(),
             r_unless: self.r_unless.clone(),
+            r_ifs: self.r_ifs.clone(),
+            requires_ifs: self.requires_ifs.clone(),
+            terminator: self.terminator,
+            action: self.action.clone(),
+            prompt: self.prompt,
         }
     }
 }
@@ -233,6 +302,9 @@ impl<'n, 'e> AnyArg<'n, 'e> for OptBuilder<'n, 'e> {
     fn validator(&self) -> Option<&Rc<Fn(String) -> StdResult<(), String>>> {
         self.validator.as_ref()
     }
+    fn value_parser(&self) -> Option<&Rc<Fn(&OsStr) -> StdResult<Arc<Any + Send + Sync>, String>>> {
+        self.value_parser.as_ref()
+    }
     fn min_vals(&self) -> Option<u64> {
         self.min_vals
     }
@@ -251,9 +323,30 @@ impl<'n, 'e> AnyArg<'n, 'e> for OptBuilder<'n, 'e> {
     fn help(&self) -> Option<&'e str> {
         self.help
     }
+    fn long_help(&self) -> Option<&'e str> {
+        self.long_help
+    }
+    fn val_terminator(&self) -> Option<&'e str> {
+        self.terminator
+    }
     fn default_val(&self) -> Option<&'n str> {
         self.default_val
     }
+    fn default_missing_val(&self) -> Option<&'n str> {
+        self.default_missing_val
+    }
+    fn prompt(&self) -> Option<&'e str> {
+        self.prompt
+    }
+    fn action(&self) -> Option<&ArgAction> {
+        self.action.as_ref()
+    }
+    fn default_vals_ifs(&self) -> Option<vec_map::Values<(&'n str, Option<&'e str>, &'e str)>> {
+        self.default_vals_ifs.as_ref().map(|vm| vm.values())
+    }
+    fn env<'s>(&'s self) -> Option<(&'n OsStr, Option<&'s OsString>)> {
+        self.env.as_ref().map(|&(key, ref value)| (key, value.as_ref()))
+    }
     fn longest_filter(&self) -> bool {
         true
     }
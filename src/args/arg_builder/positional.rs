@@ -1,15 +1,18 @@
 // Std
+use std::any::Any;
+use std::ffi::{OsStr, OsString};
 use std::borrow::Cow;
 use std::fmt::{Display, Formatter, Result};
 use std::rc::Rc;
+use std::sync::Arc;
 use std::result::Result as StdResult;
 
 // Third Party
-use vec_map::VecMap;
+use vec_map::{self, VecMap};
 
 // Internal
 use Arg;
-use args::{AnyArg, DispOrder};
+use args::{AnyArg, ArgAction, DispOrder};
 use args::settings::{ArgFlags, ArgSettings};
 
 #[allow(missing_debug_implementations)]
@@ -17,6 +20,7 @@ use args::settings::{ArgFlags, ArgSettings};
 pub struct PosBuilder<'n, 'e> {
     pub name: &'n str,
     pub help: Option<&'e str>,
+    pub long_help: Option<&'e str>,
     pub requires: Option<Vec<&'e str>>,
     pub blacklist: Option<Vec<&'e str>>,
     pub possible_vals: Option<Vec<&'e str>>,
@@ -26,12 +30,20 @@ pub struct PosBuilder<'n, 'e> {
     pub min_vals: Option<u64>,
     pub val_names: Option<VecMap<&'e str>>,
     pub validator: Option<Rc<Fn(String) -> StdResult<(), String>>>,
+    pub value_parser: Option<Rc<Fn(&OsStr) -> StdResult<Arc<Any + Send + Sync>, String>>>,
     pub overrides: Option<Vec<&'e str>>,
     pub settings: ArgFlags,
     pub val_delim: Option<char>,
     pub default_val: Option<&'n str>,
+    pub default_vals_ifs: Option<VecMap<(&'n str, Option<&'e str>, &'e str)>>,
+    pub default_vals_if_fns: Option<VecMap<(&'n str, Rc<Fn(&OsStr) -> bool>, &'e str)>>,
+    pub env: Option<(&'n OsStr, Option<OsString>)>,
     pub disp_ord: usize,
     pub r_unless: Option<Vec<&'e str>>,
+    pub r_ifs: Option<Vec<(&'n str, &'e str)>>,
+    pub requires_ifs: Option<Vec<(&'e str, &'n str)>>,
+    pub terminator: Option<&'e str>,
+    pub prompt: Option<&'e str>,
 }
 
 impl<'n, 'e> Default for PosBuilder<'n, 'e> {
@@ -39,6 +51,7 @@ impl<'n, 'e> Default for PosBuilder<'n, 'e> {
         PosBuilder {
             name: "",
             help: None,
+            long_help: None,
             requires: None,
             blacklist: None,
             possible_vals: None,
@@ -48,12 +61,20 @@ impl<'n, 'e> Default for PosBuilder<'n, 'e> {
             max_vals: None,
             val_names: None,
             validator: None,
+            value_parser: None,
             overrides: None,
             settings: ArgFlags::new(),
             val_delim: Some(','),
             default_val: None,
+            default_vals_ifs: None,
+            default_vals_if_fns: None,
+            env: None,
             disp_ord: 999,
             r_unless: None,
+            r_ifs: None,
+            requires_ifs: None,
+            terminator: None,
+            prompt: None,
         }
     }
 }
@@ -88,11 +109,19 @@ impl<'n, 'e> PosBuilder<'n, 'e> {
             requires: a.requires.clone(),
             possible_vals: a.possible_vals.clone(),
             help: a.help,
+            long_help: a.long_help,
             val_delim: a.val_delim,
             settings: a.settings,
             default_val: a.default_val,
+            default_vals_ifs: a.default_vals_ifs.clone(),
+            default_vals_if_fns: a.default_vals_if_fns.clone(),
+            env: a.env.clone(),
             disp_ord: a.disp_ord,
             r_unless: a.r_unless.clone(),
+            r_ifs: a.r_ifs.clone(),
+            requires_ifs: a.requires_ifs.clone(),
+            terminator: a.terminator,
+            prompt: a.prompt,
             ..Default::default()
         };
         if a.max_vals.is_some() || a.min_vals.is_some() ||
@@ -102,6 +131,9 @@ impl<'n, 'e> PosBuilder<'n, 'e> {
         if let Some(ref p) = a.validator {
             pb.validator = Some(p.clone());
         }
+        if let Some(ref p) = a.value_parser {
+            pb.value_parser = Some(p.clone());
+        }
         // If the arg is required, add all it's requirements to master required list
         if a.is_set(ArgSettings::Required) {
             if let Some(ref areqs) = a.requires {
@@ -148,6 +180,9 @@ impl<'n, 'e> Display for PosBuilder<'n, 'e> {
         if self.settings.is_set(ArgSettings::Multiple) && self.val_names.is_none() {
             try!(write!(f, "..."));
         }
+        if let Some(t) = self.terminator {
+            try!(write!(f, " {}", t));
+        }
 
         Ok(())
     }
@@ -158,6 +193,7 @@ impl<'n, 'e> Clone for PosBuilder<'n, 'e> {
         PosBuilder {
             name: self.name,
             help: self.help,
+            long_help: self.long_help,
             blacklist: self.blacklist.clone(),
             overrides: self.overrides.clone(),
             requires: self.requires.clone(),
@@ -170,8 +206,16 @@ impl<'n, 'e> Clone for PosBuilder<'n, 'e> {
             val_delim: self.val_delim,
             possible_vals: self.possible_vals.clone(),
             default_val: self.default_val,
+            default_vals_ifs: self.default_vals_ifs.clone(),
+            default_vals_if_fns: self.default_vals_if_fns.clone(),
+            env: self.env.clone(),
             validator: self.validator.clone(),
+            value_parser: self.value_parser.clone(),
             r_unless: self.r_unless.clone(),
+            r_ifs: self.r_ifs.clone(),
+            requires_ifs: self.requires_ifs.clone(),
+            terminator: self.terminator,
+            prompt: self.prompt,
             index: self.index,
         }
     }
@@ -217,6 +261,9 @@ impl<'n, 'e> AnyArg<'n, 'e> for PosBuilder<'n, 'e> {
     fn validator(&self) -> Option<&Rc<Fn(String) -> StdResult<(), String>>> {
         self.validator.as_ref()
     }
+    fn value_parser(&self) -> Option<&Rc<Fn(&OsStr) -> StdResult<Arc<Any + Send + Sync>, String>>> {
+        self.value_parser.as_ref()
+    }
     fn min_vals(&self) -> Option<u64> {
         self.min_vals
     }
@@ -235,9 +282,30 @@ impl<'n, 'e> AnyArg<'n, 'e> for PosBuilder<'n, 'e> {
     fn help(&self) -> Option<&'e str> {
         self.help
     }
+    fn long_help(&self) -> Option<&'e str> {
+        self.long_help
+    }
+    fn val_terminator(&self) -> Option<&'e str> {
+        self.terminator
+    }
     fn default_val(&self) -> Option<&'n str> {
         self.default_val
     }
+    fn default_missing_val(&self) -> Option<&'n str> {
+        None
+    }
+    fn prompt(&self) -> Option<&'e str> {
+        self.prompt
+    }
+    fn action(&self) -> Option<&ArgAction> {
+        None
+    }
+    fn default_vals_ifs(&self) -> Option<vec_map::Values<(&'n str, Option<&'e str>, &'e str)>> {
+        self.default_vals_ifs.as_ref().map(|vm| vm.values())
+    }
+    fn env<'s>(&'s self) -> Option<(&'n OsStr, Option<&'s OsString>)> {
+        self.env.as_ref().map(|&(key, ref value)| (key, value.as_ref()))
+    }
     fn longest_filter(&self) -> bool {
         true
     }
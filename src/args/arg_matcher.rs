@@ -1,23 +1,27 @@
 // Std
+use std::any::Any;
+use std::cmp;
+use std::collections::HashMap;
 use std::collections::hash_map::{Entry, Iter};
 use std::ffi::OsStr;
 use std::ops::Deref;
+use std::sync::Arc;
 
 // Third Party
 use vec_map::VecMap;
 
 // Internal
-use args::{ArgMatches, MatchedArg, SubCommand};
+use args::{ArgMatches, MatchedArg, SubCommand, ValueSource};
 use args::AnyArg;
 use args::settings::ArgSettings;
 
 #[doc(hidden)]
 #[allow(missing_debug_implementations)]
-pub struct ArgMatcher<'a>(pub ArgMatches<'a>);
+pub struct ArgMatcher<'a>(pub ArgMatches<'a>, ValueSource);
 
 impl<'a> Default for ArgMatcher<'a> {
     fn default() -> Self {
-        ArgMatcher(ArgMatches::default())
+        ArgMatcher(ArgMatches::default(), ValueSource::CommandLine)
     }
 }
 
@@ -26,6 +30,11 @@ impl<'a> ArgMatcher<'a> {
         ArgMatcher::default()
     }
 
+    // Sets where the values added from now on come from
+    pub fn set_source(&mut self, source: ValueSource) {
+        self.1 = source;
+    }
+
     pub fn get_mut(&mut self, arg: &str) -> Option<&mut MatchedArg> {
         self.0.args.get_mut(arg)
     }
@@ -44,8 +53,29 @@ impl<'a> ArgMatcher<'a> {
         }
     }
 
+    // Turns off a negatable flag, which is then no longer present
+    pub fn negate(&mut self, arg: &'a str) {
+        self.0.args.remove(arg);
+        if !self.0.negated.contains(&arg) {
+            self.0.negated.push(arg);
+        }
+    }
+
+    pub fn remove_negation(&mut self, arg: &str) {
+        self.0.negated.retain(|&n| n != arg);
+    }
+
+    // Forgets the values (and their indices) of an arg whose last use wins
+    pub fn clear_vals(&mut self, arg: &str) {
+        if let Some(ma) = self.0.args.get_mut(arg) {
+            ma.vals.clear();
+            ma.indices.clear();
+            ma.parsed.clear();
+        }
+    }
+
     pub fn insert(&mut self, name: &'a str) {
-        self.0.args.insert(name, MatchedArg::new());
+        self.0.args.insert(name, MatchedArg { source: self.1, ..MatchedArg::new() });
     }
 
     pub fn contains(&self, arg: &str) -> bool {
@@ -68,22 +98,37 @@ impl<'a> ArgMatcher<'a> {
         self.0.args.entry(arg)
     }
 
+    // With AppSettings::SubcommandsChain, the subcommands after the first are chained to it
     pub fn subcommand(&mut self, sc: SubCommand<'a>) {
-        self.0.subcommand = Some(Box::new(sc));
+        if self.0.subcommand.is_none() {
+            self.0.subcommand = Some(Box::new(sc));
+        } else {
+            self.0.chained.push(sc);
+        }
     }
 
     pub fn subcommand_name(&self) -> Option<&str> {
         self.0.subcommand_name()
     }
 
+    // Gives global args the same value at every level of the matched subcommands
+    pub fn propagate_globals(&mut self, globals: &[&'a str]) {
+        debugln!("fn=propagate_globals; globals={:?}", globals);
+        let mut vals = HashMap::new();
+        collect_globals(&self.0, globals, &mut vals);
+        apply_globals(&mut self.0, &vals);
+    }
+
     pub fn iter(&self) -> Iter<&str, MatchedArg> {
         self.0.args.iter()
     }
 
     pub fn inc_occurrence_of(&mut self, arg: &'a str) {
+        let source = self.1;
         if let Some(a) = self.get_mut(arg) {
             debugln!("+1 to {}'s occurrences", arg);
             a.occurs += 1;
+            a.source = cmp::max(a.source, source);
             return;
         }
         self.insert(arg);
@@ -96,12 +141,35 @@ impl<'a> ArgMatcher<'a> {
     }
 
     pub fn add_val_to(&mut self, arg: &'a str, val: &OsStr) {
+        let ma = self.matched_arg(arg);
+        let len = ma.vals.len() + 1;
+        ma.vals.insert(len, val.to_owned());
+    }
+
+    pub fn add_parsed_val_to(&mut self, arg: &str, val: Arc<Any + Send + Sync>) {
+        if let Some(ma) = self.get_mut(arg) {
+            ma.parsed.push(val);
+        }
+    }
+
+    pub fn add_index_to(&mut self, arg: &'a str, idx: usize) {
+        self.matched_arg(arg).indices.push(idx);
+    }
+
+    // The arg's matches, without any occurrence when they're new, and from the current source
+    // unless they already came from a more explicit one
+    fn matched_arg(&mut self, arg: &'a str) -> &mut MatchedArg {
+        let source = self.1;
         let ma = self.entry(arg).or_insert(MatchedArg {
             occurs: 0,
+            indices: Vec::new(),
             vals: VecMap::new(),
+            parsed: Vec::new(),
+            source: source,
+            implicit: false,
         });
-        let len = ma.vals.len() + 1;
-        ma.vals.insert(len, val.to_owned());
+        ma.source = cmp::max(ma.source, source);
+        ma
     }
 
     pub fn needs_more_vals<'b, A>(&self, o: &A) -> bool
@@ -125,6 +193,55 @@ impl<'a> ArgMatcher<'a> {
     }
 }
 
+// Walks the matches in the order the args were used, so the value used last wins, unless it came
+// from a less explicit source than an earlier one, e.g. a default after a use on the command line.
+// A `None` value is a negated flag.
+fn collect_globals<'a>(m: &ArgMatches<'a>,
+                       globals: &[&'a str],
+                       vals: &mut HashMap<&'a str, Option<MatchedArg>>) {
+    for &name in globals {
+        let found = if m.negated.contains(&name) {
+            None
+        } else if let Some(ma) = m.args.get(name) {
+            Some(ma.clone())
+        } else {
+            continue;
+        };
+        let keep_earlier = match (vals.get(name), &found) {
+            (Some(earlier), &Some(ref ma)) => {
+                ma.source < earlier.as_ref().map_or(ValueSource::CommandLine, |e| e.source)
+            }
+            _ => false,
+        };
+        if !keep_earlier {
+            vals.insert(name, found);
+        }
+    }
+    for sc in m.subcommand.iter().map(|sc| &**sc).chain(m.chained.iter()) {
+        collect_globals(&sc.matches, globals, vals);
+    }
+}
+
+fn apply_globals<'a>(m: &mut ArgMatches<'a>, vals: &HashMap<&'a str, Option<MatchedArg>>) {
+    for (&name, val) in vals.iter() {
+        if let Some(ref ma) = *val {
+            m.args.insert(name, ma.clone());
+            m.negated.retain(|&n| n != name);
+        } else {
+            m.args.remove(name);
+            if !m.negated.contains(&name) {
+                m.negated.push(name);
+            }
+        }
+    }
+    if let Some(ref mut sc) = m.subcommand {
+        apply_globals(&mut sc.matches, vals);
+    }
+    for sc in &mut m.chained {
+        apply_globals(&mut sc.matches, vals);
+    }
+}
+
 impl<'a> Into<ArgMatches<'a>> for ArgMatcher<'a> {
     fn into(self) -> ArgMatches<'a> {
         self.0
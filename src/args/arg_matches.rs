@@ -1,4 +1,5 @@
 // Std
+use std::any::Any;
 use std::borrow::Cow;
 use std::collections::HashMap;
 use std::ffi::{OsStr, OsString};
@@ -6,17 +7,29 @@ use std::iter::Map;
 use std::slice;
 
 // Third Party
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 use vec_map;
 
 // Internal
 use INVALID_UTF8;
-use args::MatchedArg;
+use args::{MatchedArg, ValueSource};
+use errors::Error;
 use args::SubCommand;
 
 /// Used to get information about the arguments that where supplied to the program at runtime by
 /// the user. New instances of this struct are obtained by using the [`App::get_matches`] family of
 /// methods.
 ///
+/// With the `serde` Cargo feature, `ArgMatches` implements `Serialize` and `Deserialize`, keeping
+/// the occurrences, indices, values (as raw bytes when they aren't valid UTF-8), [value sources]
+/// and subcommand matches. The argument names are borrowed from the deserialized input, so it must
+/// outlive the matches, e.g. deserialize from a `&str` rather than a reader. To deserialize from a
+/// reader, or input in which names may be escaped, use [`App::deserialize_matches`].
+///
+/// **NOTE:** The typed values of a [value parser] aren't serialized, so
+/// [`ArgMatches::get_one`] returns an error for them after deserializing.
+///
 /// # Examples
 ///
 /// ```no_run
@@ -60,14 +73,28 @@ use args::SubCommand;
 /// }
 /// ```
 /// [`App::get_matches`]: ./struct.App.html#method.get_matches
+/// [value sources]: ./enum.ValueSource.html
+/// [value parser]: ./struct.Arg.html#method.value_parser
+/// [`ArgMatches::get_one`]: ./struct.ArgMatches.html#method.get_one
+/// [`App::deserialize_matches`]: ./struct.App.html#method.deserialize_matches
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ArgMatches<'a> {
     #[doc(hidden)]
+    #[cfg_attr(feature = "serde", serde(borrow))]
     pub args: HashMap<&'a str, MatchedArg>,
     #[doc(hidden)]
+    #[cfg_attr(feature = "serde", serde(borrow))]
     pub subcommand: Option<Box<SubCommand<'a>>>,
+    // The subcommands used after the first one with AppSettings::SubcommandsChain
+    #[doc(hidden)]
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub chained: Vec<SubCommand<'a>>,
     #[doc(hidden)]
     pub usage: Option<String>,
+    #[doc(hidden)]
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub negated: Vec<&'a str>,
 }
 
 impl<'a> Default for ArgMatches<'a> {
@@ -75,7 +102,9 @@ impl<'a> Default for ArgMatches<'a> {
         ArgMatches {
             args: HashMap::new(),
             subcommand: None,
+            chained: Vec::new(),
             usage: None,
+            negated: Vec::new(),
         }
     }
 }
@@ -299,6 +328,76 @@ impl<'a> ArgMatches<'a> {
         None
     }
 
+    /// Gets the value of a specific argument as parsed by its [value parser], i.e. as type `T`.
+    /// Returns `Ok(None)` if the argument wasn't present at runtime or has no value. If the
+    /// argument has multiple values, only the first is returned.
+    ///
+    /// # Errors
+    ///
+    /// An [`ErrorKind::ValueTypeMismatch`] error naming the argument if its value parser produces
+    /// a type other than `T`, or the argument doesn't have a value parser.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// let m = App::new("myapp")
+    ///     .arg(Arg::with_name("jobs")
+    ///         .short("j")
+    ///         .takes_value(true)
+    ///         .value_parser::<usize>())
+    ///     .get_matches_from(vec!["myapp", "-j", "4"]);
+    ///
+    /// assert_eq!(m.get_one::<usize>("jobs").unwrap(), Some(&4));
+    /// ```
+    /// [value parser]: ./struct.Arg.html#method.value_parser
+    /// [`ErrorKind::ValueTypeMismatch`]: ./enum.ErrorKind.html#variant.ValueTypeMismatch
+    pub fn get_one<T: Any>(&self, name: &str) -> Result<Option<&T>, Error> {
+        Ok(try!(self.get_many::<T>(name)).and_then(|vals| vals.into_iter().next()))
+    }
+
+    /// Gets all the values of a specific argument as parsed by its [value parser], i.e. as type
+    /// `T`. Returns `Ok(None)` if the argument wasn't present at runtime or has no values.
+    ///
+    /// # Errors
+    ///
+    /// An [`ErrorKind::ValueTypeMismatch`] error naming the argument if its value parser produces
+    /// a type other than `T`, or the argument doesn't have a value parser.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// let m = App::new("myapp")
+    ///     .arg(Arg::with_name("ports")
+    ///         .takes_value(true)
+    ///         .multiple(true)
+    ///         .value_parser::<u16>())
+    ///     .get_matches_from(vec!["myapp", "80", "443"]);
+    ///
+    /// assert_eq!(m.get_many::<u16>("ports").unwrap(), Some(vec![&80, &443]));
+    /// ```
+    /// [value parser]: ./struct.Arg.html#method.value_parser
+    /// [`ErrorKind::ValueTypeMismatch`]: ./enum.ErrorKind.html#variant.ValueTypeMismatch
+    pub fn get_many<T: Any>(&self, name: &str) -> Result<Option<Vec<&T>>, Error> {
+        let arg = match self.args.get(name) {
+            Some(arg) if !arg.vals.is_empty() => arg,
+            _ => return Ok(None),
+        };
+        // Values without a parsed counterpart come from an arg without a value parser
+        if arg.parsed.len() != arg.vals.len() {
+            return Err(Error::value_type_mismatch_auto(name));
+        }
+        let mut vals = Vec::with_capacity(arg.parsed.len());
+        for p in &arg.parsed {
+            match p.downcast_ref::<T>() {
+                Some(v) => vals.push(v),
+                None => return Err(Error::value_type_mismatch_auto(name)),
+            }
+        }
+        Ok(Some(vals))
+    }
+
     /// Returns `true` if an argument was present at runtime, otherwise `false`.
     ///
     /// # Examples
@@ -320,7 +419,92 @@ impl<'a> ArgMatches<'a> {
                 return true;
             }
         }
-        self.args.contains_key(name.as_ref())
+        self.args.get(name.as_ref()).map_or(false, |a| !a.implicit)
+    }
+
+    /// Returns `true` if a [negatable] flag was turned off at runtime, i.e. the last time it was
+    /// used was in the form `--no-<long>`, otherwise `false`.
+    ///
+    /// **NOTE:** A flag which was turned off is *not* present, so [`ArgMatches::is_present`]
+    /// returns `false` for it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// let m = App::new("myprog")
+    ///     .arg(Arg::with_name("cache")
+    ///         .long("cache")
+    ///         .negatable(true))
+    ///     .get_matches_from(vec![
+    ///         "myprog", "--no-cache"
+    ///     ]);
+    ///
+    /// assert!(m.is_negated("cache"));
+    /// assert!(!m.is_present("cache"));
+    /// ```
+    /// [negatable]: ./struct.Arg.html#method.negatable
+    /// [`ArgMatches::is_present`]: ./struct.ArgMatches.html#method.is_present
+    pub fn is_negated<S: AsRef<str>>(&self, name: S) -> bool {
+        self.negated.iter().any(|&n| n == name.as_ref())
+    }
+
+    /// Returns where the values of an argument came from, or `None` if it isn't present. See
+    /// [`ValueSource`] for the possible sources.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg, ValueSource};
+    /// let m = App::new("myprog")
+    ///     .arg(Arg::with_name("jobs")
+    ///         .long("jobs")
+    ///         .default_value("1"))
+    ///     .arg(Arg::with_name("out")
+    ///         .long("out")
+    ///         .takes_value(true))
+    ///     .get_matches_from(vec![
+    ///         "myprog", "--out", "build"
+    ///     ]);
+    ///
+    /// assert_eq!(m.value_source("jobs"), Some(ValueSource::DefaultValue));
+    /// assert_eq!(m.value_source("out"), Some(ValueSource::CommandLine));
+    /// assert_eq!(m.value_source("verbose"), None);
+    /// ```
+    /// [`ValueSource`]: ./enum.ValueSource.html
+    pub fn value_source<S: AsRef<str>>(&self, name: S) -> Option<ValueSource> {
+        self.args.get(name.as_ref()).map(|a| a.source)
+    }
+
+    /// Returns `true` if an argument was present at runtime and its values didn't come from its
+    /// [default value], otherwise `false`. Unlike [`ArgMatches::is_present`], this is `false` for
+    /// an argument which wasn't used and only got its default value. Values from an environment
+    /// variable, a config file or a prompt are explicit, since the user gave them.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// let m = App::new("myprog")
+    ///     .arg(Arg::with_name("jobs")
+    ///         .long("jobs")
+    ///         .default_value("1"))
+    ///     .get_matches_from(vec![
+    ///         "myprog"
+    ///     ]);
+    ///
+    /// assert!(m.is_present("jobs"));
+    /// assert!(!m.is_explicitly_present("jobs"));
+    /// ```
+    /// [default value]: ./struct.Arg.html#method.default_value
+    /// [`ArgMatches::is_present`]: ./struct.ArgMatches.html#method.is_present
+    pub fn is_explicitly_present<S: AsRef<str>>(&self, name: S) -> bool {
+        if let Some(ref sc) = self.subcommand {
+            if sc.name == name.as_ref() {
+                return true;
+            }
+        }
+        self.value_source(name).map_or(false, |s| s != ValueSource::DefaultValue)
     }
 
     /// Returns the number of times an argument was used at runtime. If an argument isn't present
@@ -366,10 +550,208 @@ impl<'a> ArgMatches<'a> {
         self.args.get(name.as_ref()).map_or(0, |a| a.occurs)
     }
 
+    /// Gets the starting index of the argument in respect to all other arguments. Indices are
+    /// similar to argv indices, but are not exactly 1:1.
+    ///
+    /// For flags (i.e. those arguments which don't have an associated value), indices refer
+    /// to occurrence of the switch, such as `-f`, or `--flag`. However, for options the indices
+    /// refer to the *values*, `-o val` would therefore not represent two distinct indices, only the
+    /// index for `val` would be recorded. This is by design.
+    ///
+    /// Besides the flag/option discrepancy, the primary difference between an argv index and clap
+    /// index, is that clap continues counting once all arguments have been properly separated,
+    /// whereas an argv index does not.
+    ///
+    /// The examples should clear this up.
+    ///
+    /// **NOTE:** If an argument is allowed multiple times, this method will only give the *first*
+    /// index. Values which came from an environment variable or a default value don't have an
+    /// index.
+    ///
+    /// # Examples
+    ///
+    /// The argv indices are listed in the comments below. See how they correspond to the clap
+    /// indices. Note that if it's not listed in a clap index, this is because it's not saved in
+    /// an `ArgMatches` struct for querying.
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// let m = App::new("myapp")
+    ///     .arg(Arg::with_name("flag")
+    ///         .short("f"))
+    ///     .arg(Arg::with_name("option")
+    ///         .short("o")
+    ///         .takes_value(true))
+    ///     .get_matches_from(vec!["myapp", "-f", "-o", "val"]);
+    ///             // ARGV idxs: ^0       ^1    ^2    ^3
+    ///             // clap idxs:          ^1          ^3
+    ///
+    /// assert_eq!(m.index_of("flag"), Some(1));
+    /// assert_eq!(m.index_of("option"), Some(3));
+    /// ```
+    ///
+    /// Now notice, if we use one of the other styles of options:
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// let m = App::new("myapp")
+    ///     .arg(Arg::with_name("flag")
+    ///         .short("f"))
+    ///     .arg(Arg::with_name("option")
+    ///         .short("o")
+    ///         .takes_value(true))
+    ///     .get_matches_from(vec!["myapp", "-f", "-o=val"]);
+    ///             // ARGV idxs: ^0       ^1    ^2
+    ///             // clap idxs:          ^1       ^3
+    ///
+    /// assert_eq!(m.index_of("flag"), Some(1));
+    /// assert_eq!(m.index_of("option"), Some(3));
+    /// ```
+    ///
+    /// Things become much more complicated, or clear if we look at a more complex combination of
+    /// flags. Let's also throw in the final option style for good measure.
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// let m = App::new("myapp")
+    ///     .arg(Arg::with_name("flag")
+    ///         .short("f"))
+    ///     .arg(Arg::with_name("flag2")
+    ///         .short("F"))
+    ///     .arg(Arg::with_name("flag3")
+    ///         .short("z"))
+    ///     .arg(Arg::with_name("option")
+    ///         .short("o")
+    ///         .takes_value(true))
+    ///     .get_matches_from(vec!["myapp", "-fzF", "-oval"]);
+    ///             // ARGV idxs: ^0       ^1      ^2
+    ///             // clap idxs:          ^1,2,3    ^5
+    ///             //
+    ///             // clap sees the above as 'myapp -f -z -F -o val'
+    ///             //                         ^0    ^1 ^2 ^3 ^4 ^5
+    /// assert_eq!(m.index_of("flag"), Some(1));
+    /// assert_eq!(m.index_of("flag2"), Some(3));
+    /// assert_eq!(m.index_of("flag3"), Some(2));
+    /// assert_eq!(m.index_of("option"), Some(5));
+    /// ```
+    ///
+    /// One final combination of flags/options to see how they combine:
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// let m = App::new("myapp")
+    ///     .arg(Arg::with_name("flag")
+    ///         .short("f"))
+    ///     .arg(Arg::with_name("flag2")
+    ///         .short("F"))
+    ///     .arg(Arg::with_name("flag3")
+    ///         .short("z"))
+    ///     .arg(Arg::with_name("option")
+    ///         .short("o")
+    ///         .takes_value(true)
+    ///         .multiple(true))
+    ///     .get_matches_from(vec!["myapp", "-fzFoval"]);
+    ///             // ARGV idxs: ^0       ^1
+    ///             // clap idxs:          ^1,2,3^5
+    ///             //
+    ///             // clap sees the above as 'myapp -f -z -F -o val'
+    ///             //                         ^0    ^1 ^2 ^3 ^4 ^5
+    /// assert_eq!(m.index_of("flag"), Some(1));
+    /// assert_eq!(m.index_of("flag2"), Some(3));
+    /// assert_eq!(m.index_of("flag3"), Some(2));
+    /// assert_eq!(m.index_of("option"), Some(5));
+    /// ```
+    ///
+    /// The last part to mention is when values are sent in multiple groups with a [delimiter].
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// let m = App::new("myapp")
+    ///     .arg(Arg::with_name("option")
+    ///         .short("o")
+    ///         .takes_value(true)
+    ///         .multiple(true))
+    ///     .get_matches_from(vec!["myapp", "-o=val1,val2,val3"]);
+    ///             // ARGV idxs: ^0       ^1
+    ///             // clap idxs:             ^2   ^3   ^4
+    ///             //
+    ///             // clap sees the above as 'myapp -o val1 val2 val3'
+    ///             //                         ^0    ^1 ^2   ^3   ^4
+    /// assert_eq!(m.index_of("option"), Some(2));
+    /// ```
+    /// [delimiter]: ./struct.Arg.html#method.value_delimiter
+    pub fn index_of<S: AsRef<str>>(&self, name: S) -> Option<usize> {
+        self.args.get(name.as_ref()).and_then(|arg| arg.indices.first().cloned())
+    }
+
+    /// Gets all indices of the argument in respect to all other arguments. Indices are
+    /// similar to argv indices, but are not exactly 1:1.
+    ///
+    /// For flags (i.e. those arguments which don't have an associated value), indices refer
+    /// to occurrence of the switch, such as `-f`, or `--flag`. However, for options the indices
+    /// refer to the *values*, `-o val` would therefore not represent two distinct indices, only the
+    /// index for `val` would be recorded. This is by design.
+    ///
+    /// *NOTE:* For more information about how clap indices compare to argv indices, see
+    /// [`ArgMatches::index_of`]
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// let m = App::new("myapp")
+    ///     .arg(Arg::with_name("option")
+    ///         .short("o")
+    ///         .takes_value(true)
+    ///         .use_delimiter(true)
+    ///         .multiple(true))
+    ///     .get_matches_from(vec!["myapp", "-o=val1,val2,val3"]);
+    ///             // ARGV idxs: ^0       ^1
+    ///             // clap idxs:             ^2   ^3   ^4
+    ///             //
+    ///             // clap sees the above as 'myapp -o val1 val2 val3'
+    ///             //                         ^0    ^1 ^2   ^3   ^4
+    /// assert_eq!(m.indices_of("option").unwrap().collect::<Vec<_>>(), &[2, 3, 4]);
+    /// ```
+    ///
+    /// Another quick example is when flags and options are used together
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// let m = App::new("myapp")
+    ///     .arg(Arg::with_name("option")
+    ///         .short("o")
+    ///         .takes_value(true)
+    ///         .multiple(true))
+    ///     .arg(Arg::with_name("flag")
+    ///         .short("f")
+    ///         .multiple(true))
+    ///     .get_matches_from(vec!["myapp", "-o", "val1", "-f", "-o", "val2", "-f"]);
+    ///             // ARGV idxs: ^0       ^1    ^2      ^3    ^4    ^5      ^6
+    ///             // clap idxs:                ^2      ^3          ^5      ^6
+    ///
+    /// assert_eq!(m.indices_of("option").unwrap().collect::<Vec<_>>(), &[2, 5]);
+    /// assert_eq!(m.indices_of("flag").unwrap().collect::<Vec<_>>(), &[3, 6]);
+    /// ```
+    /// [`ArgMatches::index_of`]: ./struct.ArgMatches.html#method.index_of
+    pub fn indices_of<S: AsRef<str>>(&'a self, name: S) -> Option<Indices<'a>> {
+        if let Some(arg) = self.args.get(name.as_ref()) {
+            fn to_usize(i: &usize) -> usize {
+                *i
+            }
+            let to_usize: fn(&usize) -> usize = to_usize; // coerce to fn pointer
+            return Some(Indices { iter: arg.indices.iter().map(to_usize) });
+        }
+        None
+    }
+
     /// Because [`Subcommand`]s are essentially "sub-[`App`]s" they have their own [`ArgMatches`]
     /// as well. This method returns the [`ArgMatches`] for a particular subcommand or `None` if
     /// the subcommand wasn't present at runtime.
     ///
+    /// **NOTE:** With [`AppSettings::SubcommandsChain`] this returns the matches of the first use
+    /// of the subcommand, see [`ArgMatches::subcommands`] for all of them.
+    ///
     /// # Examples
     ///
     /// ```rust
@@ -397,11 +779,14 @@ impl<'a> ArgMatches<'a> {
     /// [`Subcommand`]: ./struct.SubCommand.html
     /// [`App`]: ./struct.App.html
     /// [`ArgMatches`]: ./struct.ArgMatches.html
+    /// [`AppSettings::SubcommandsChain`]: ./enum.AppSettings.html#variant.SubcommandsChain
+    /// [`ArgMatches::subcommands`]: ./struct.ArgMatches.html#method.subcommands
     pub fn subcommand_matches<S: AsRef<str>>(&self, name: S) -> Option<&ArgMatches<'a>> {
         if let Some(ref s) = self.subcommand {
             if s.name == name.as_ref() {
                 return Some(&s.matches);
             }
+            return self.chained.iter().find(|sc| sc.name == name.as_ref()).map(|sc| &sc.matches);
         }
         None
     }
@@ -519,6 +904,38 @@ impl<'a> ArgMatches<'a> {
         self.subcommand.as_ref().map_or(("", None), |sc| (&sc.name[..], Some(&sc.matches)))
     }
 
+    /// Returns the names and [`ArgMatches`] of all the [`SubCommand`]s used at runtime, in the
+    /// order they were used. Without [`AppSettings::SubcommandsChain`] there is at most one.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg, AppSettings, SubCommand};
+    /// let app_m = App::new("tool")
+    ///     .setting(AppSettings::SubcommandsChain)
+    ///     .subcommand(SubCommand::with_name("build")
+    ///         .arg(Arg::with_name("release").long("release")))
+    ///     .subcommand(SubCommand::with_name("test"))
+    ///     .get_matches_from(vec!["tool", "build", "test", "build", "--release"]);
+    ///
+    /// let used: Vec<_> = app_m.subcommands()
+    ///     .into_iter()
+    ///     .map(|(name, sub_m)| (name, sub_m.is_present("release")))
+    ///     .collect();
+    /// assert_eq!(used, [("build", false), ("test", false), ("build", true)]);
+    /// ```
+    /// [`ArgMatches`]: ./struct.ArgMatches.html
+    /// [`SubCommand`]: ./struct.SubCommand.html
+    /// [`AppSettings::SubcommandsChain`]: ./enum.AppSettings.html#variant.SubcommandsChain
+    pub fn subcommands(&self) -> Vec<(&str, &ArgMatches<'a>)> {
+        self.subcommand
+            .iter()
+            .map(|sc| &**sc)
+            .chain(self.chained.iter())
+            .map(|sc| (&sc.name[..], &sc.matches))
+            .collect()
+    }
+
     /// Returns a string slice of the usage statement for the [`App`] or [`SubCommand`]
     ///
     /// # Examples
@@ -672,3 +1089,47 @@ impl<'a> DoubleEndedIterator for OsValues<'a> {
         self.iter.next_back()
     }
 }
+
+/// An iterator for getting the indices of an argument via the [`ArgMatches::indices_of`]
+/// method.
+///
+/// # Examples
+///
+/// ```rust
+/// # use clap::{App, Arg};
+/// let m = App::new("myapp")
+///     .arg(Arg::with_name("output")
+///         .short("o")
+///         .multiple(true)
+///         .takes_value(true))
+///     .get_matches_from(vec!["myapp", "-o", "val1", "val2"]);
+///
+/// let mut indices = m.indices_of("output").unwrap();
+///
+/// assert_eq!(indices.next(), Some(2));
+/// assert_eq!(indices.next(), Some(3));
+/// assert_eq!(indices.next(), None);
+/// ```
+/// [`ArgMatches::indices_of`]: ./struct.ArgMatches.html#method.indices_of
+#[derive(Clone)]
+#[allow(missing_debug_implementations)]
+pub struct Indices<'a> {
+    iter: Map<slice::Iter<'a, usize>, fn(&'a usize) -> usize>,
+}
+
+impl<'a> Iterator for Indices<'a> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        self.iter.next()
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<'a> DoubleEndedIterator for Indices<'a> {
+    fn next_back(&mut self) -> Option<usize> {
+        self.iter.next_back()
+    }
+}
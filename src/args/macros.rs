@@ -45,3 +45,48 @@ macro_rules! yaml_to_usize {
         $a.$c($v.as_i64().unwrap_or_else(|| panic!("failed to convert YAML {:?} value to a string", $v)) as usize)
     }};
 }
+
+macro_rules! yaml_tuple3 {
+    ($a:ident, $v:ident, $c:ident) => {{
+        if let Some(vec) = $v.as_vec() {
+            for ys in vec {
+                if let Some(tup) = ys.as_vec() {
+                    debug_assert_eq!(3, tup.len());
+                    let arg = tup[0].as_str().unwrap_or_else(|| panic!("failed to convert YAML {:?} value to a string", tup[0]));
+                    let val = if tup[1].is_null() {
+                        None
+                    } else {
+                        Some(tup[1].as_str().unwrap_or_else(|| panic!("failed to convert YAML {:?} value to a string", tup[1])))
+                    };
+                    let def = tup[2].as_str().unwrap_or_else(|| panic!("failed to convert YAML {:?} value to a string", tup[2]));
+                    $a = $a.$c(arg, val, def);
+                } else {
+                    panic!("Failed to convert YAML value to vec");
+                }
+            }
+        } else {
+            panic!("Failed to convert YAML value to vec");
+        }
+        $a
+    }};
+}
+
+macro_rules! yaml_tuple2 {
+    ($a:ident, $v:ident, $c:ident) => {{
+        if let Some(vec) = $v.as_vec() {
+            for ys in vec {
+                if let Some(tup) = ys.as_vec() {
+                    debug_assert_eq!(2, tup.len());
+                    let first = tup[0].as_str().unwrap_or_else(|| panic!("failed to convert YAML {:?} value to a string", tup[0]));
+                    let second = tup[1].as_str().unwrap_or_else(|| panic!("failed to convert YAML {:?} value to a string", tup[1]));
+                    $a = $a.$c(first, second);
+                } else {
+                    panic!("Failed to convert YAML value to vec");
+                }
+            }
+        } else {
+            panic!("Failed to convert YAML value to vec");
+        }
+        $a
+    }};
+}
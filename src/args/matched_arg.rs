@@ -1,23 +1,49 @@
 // Std
+use std::any::Any;
 use std::ffi::OsString;
+use std::sync::Arc;
 
 // Third Party
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 use vec_map::VecMap;
 
+// Internal
+use args::ValueSource;
+
 #[doc(hidden)]
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct MatchedArg {
     #[doc(hidden)]
     pub occurs: u64,
     #[doc(hidden)]
+    pub indices: Vec<usize>,
+    #[doc(hidden)]
+    #[cfg_attr(feature = "serde", serde(with = "::args::serde_vals"))]
     pub vals: VecMap<OsString>,
+    // The values as produced by the arg's value parser, if it has one, which can't be serialized
+    #[doc(hidden)]
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub parsed: Vec<Arc<Any + Send + Sync>>,
+    #[doc(hidden)]
+    pub source: ValueSource,
+    // Set for the value given to an unused ArgAction::SetTrue or ArgAction::SetFalse flag, which
+    // doesn't make the flag present
+    #[doc(hidden)]
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub implicit: bool,
 }
 
 impl Default for MatchedArg {
     fn default() -> Self {
         MatchedArg {
             occurs: 1,
+            indices: Vec::new(),
             vals: VecMap::new(),
+            parsed: Vec::new(),
+            source: ValueSource::CommandLine,
+            implicit: false,
         }
     }
 }
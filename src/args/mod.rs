@@ -1,15 +1,18 @@
+pub use self::action::ArgAction;
 pub use self::any_arg::{AnyArg, DispOrder};
 pub use self::arg::Arg;
 pub use self::arg_builder::{FlagBuilder, OptBuilder, PosBuilder};
 pub use self::arg_matcher::ArgMatcher;
-pub use self::arg_matches::{Values, OsValues, ArgMatches};
+pub use self::arg_matches::{Values, OsValues, Indices, ArgMatches};
 pub use self::group::ArgGroup;
 pub use self::matched_arg::MatchedArg;
 pub use self::settings::ArgSettings;
 pub use self::subcommand::SubCommand;
+pub use self::value_source::ValueSource;
 
 #[macro_use]
 mod macros;
+mod action;
 mod arg;
 pub mod any_arg;
 mod arg_matches;
@@ -19,3 +22,6 @@ mod arg_builder;
 mod matched_arg;
 mod group;
 pub mod settings;
+mod value_source;
+#[cfg(feature = "serde")]
+mod serde_vals;
@@ -0,0 +1,83 @@
+// Serializes the values of a MatchedArg as a sequence in which each value is tagged as either a
+// string, or when it isn't valid UTF-8, its raw bytes (code units on Windows) so it round-trips
+// losslessly. The tag keeps the format usable with formats which aren't self-describing
+
+// Std
+use std::ffi::{OsStr, OsString};
+#[cfg(unix)]
+use std::os::unix::ffi::{OsStrExt, OsStringExt};
+#[cfg(windows)]
+use std::os::windows::ffi::{OsStrExt, OsStringExt};
+
+// Third Party
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use vec_map::VecMap;
+
+#[derive(Serialize, Deserialize)]
+enum Val {
+    Utf8(String),
+    #[cfg(not(windows))]
+    Raw(Vec<u8>),
+    #[cfg(windows)]
+    Raw(Vec<u16>),
+}
+
+impl<'a> From<&'a OsStr> for Val {
+    fn from(val: &'a OsStr) -> Self {
+        match val.to_str() {
+            Some(s) => Val::Utf8(s.to_owned()),
+            None => raw(val),
+        }
+    }
+}
+
+impl From<Val> for OsString {
+    fn from(val: Val) -> Self {
+        match val {
+            Val::Utf8(s) => OsString::from(s),
+            Val::Raw(r) => from_raw(r),
+        }
+    }
+}
+
+#[cfg(unix)]
+fn raw(val: &OsStr) -> Val {
+    Val::Raw(val.as_bytes().to_vec())
+}
+
+#[cfg(unix)]
+fn from_raw(raw: Vec<u8>) -> OsString {
+    OsString::from_vec(raw)
+}
+
+#[cfg(windows)]
+fn raw(val: &OsStr) -> Val {
+    Val::Raw(val.encode_wide().collect())
+}
+
+#[cfg(windows)]
+fn from_raw(raw: Vec<u16>) -> OsString {
+    OsString::from_wide(&raw)
+}
+
+#[cfg(not(any(unix, windows)))]
+fn raw(val: &OsStr) -> Val {
+    Val::Utf8(val.to_string_lossy().into_owned())
+}
+
+#[cfg(not(any(unix, windows)))]
+fn from_raw(raw: Vec<u8>) -> OsString {
+    OsString::from(String::from_utf8_lossy(&raw).into_owned())
+}
+
+pub fn serialize<S: Serializer>(vals: &VecMap<OsString>, serializer: S) -> Result<S::Ok, S::Error> {
+    // Collected first, as formats like bincode need the exact number of values up front
+    let vals: Vec<Val> = vals.values().map(|v| Val::from(&**v)).collect();
+    vals.serialize(serializer)
+}
+
+pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<VecMap<OsString>, D::Error> {
+    let vals: Vec<Val> = try!(Deserialize::deserialize(deserializer));
+    // Keyed from 1, like the values added while parsing
+    Ok(vals.into_iter().enumerate().map(|(i, v)| (i + 1, OsString::from(v))).collect())
+}
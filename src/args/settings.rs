@@ -3,19 +3,26 @@ use std::ascii::AsciiExt;
 use std::str::FromStr;
 
 bitflags! {
-    flags Flags: u16 {
-        const REQUIRED       = 0b000000000001,
-        const MULTIPLE       = 0b000000000010,
-        const EMPTY_VALS     = 0b000000000100,
-        const GLOBAL         = 0b000000001000,
-        const HIDDEN         = 0b000000010000,
-        const TAKES_VAL      = 0b000000100000,
-        const USE_DELIM      = 0b000001000000,
-        const NEXT_LINE_HELP = 0b000010000000,
-        const R_UNLESS_ALL   = 0b000100000000,
-        const REQ_DELIM      = 0b001000000000,
-        const DELIM_NOT_SET  = 0b010000000000,
-        const HIDE_POS_VALS  = 0b100000000000,
+    flags Flags: u32 {
+        const REQUIRED         = 0b0000000000000000001,
+        const MULTIPLE         = 0b0000000000000000010,
+        const EMPTY_VALS       = 0b0000000000000000100,
+        const GLOBAL           = 0b0000000000000001000,
+        const HIDDEN           = 0b0000000000000010000,
+        const TAKES_VAL        = 0b0000000000000100000,
+        const USE_DELIM        = 0b0000000000001000000,
+        const NEXT_LINE_HELP   = 0b0000000000010000000,
+        const R_UNLESS_ALL     = 0b0000000000100000000,
+        const REQ_DELIM        = 0b0000000001000000000,
+        const DELIM_NOT_SET    = 0b0000000010000000000,
+        const HIDE_POS_VALS    = 0b0000000100000000000,
+        const CASE_INSENSITIVE = 0b0000001000000000000,
+        const LAST             = 0b0000010000000000000,
+        const ALLOW_TAC_VALS   = 0b0000100000000000000,
+        const NEGATABLE        = 0b0001000000000000000,
+        const REQUIRE_EQUALS   = 0b0010000000000000000,
+        const HIDE_INPUT       = 0b0100000000000000000,
+        const HIDE_ENV_VALS    = 0b1000000000000000000,
     }
 }
 
@@ -40,7 +47,14 @@ impl ArgFlags {
         RequiredUnlessAll => R_UNLESS_ALL,
         RequireDelimiter => REQ_DELIM,
         ValueDelimiterNotSet => DELIM_NOT_SET,
-        HidePossibleValues => HIDE_POS_VALS
+        HidePossibleValues => HIDE_POS_VALS,
+        CaseInsensitive => CASE_INSENSITIVE,
+        Last => LAST,
+        AllowLeadingHyphen => ALLOW_TAC_VALS,
+        Negatable => NEGATABLE,
+        RequireEquals => REQUIRE_EQUALS,
+        HideInput => HIDE_INPUT,
+        HideEnvValues => HIDE_ENV_VALS
     }
 }
 
@@ -78,6 +92,27 @@ pub enum ArgSettings {
     RequireDelimiter,
     /// Hides the possible values from the help string
     HidePossibleValues,
+    /// Possible values are matched without regard to ASCII case
+    CaseInsensitive,
+    /// Specifies that a positional argument may only be supplied after `--`
+    Last,
+    /// Allows values which start with a leading hyphen such as `--option -5`
+    AllowLeadingHyphen,
+    /// A flag which may also be turned off with `--no-<long>`
+    Negatable,
+    /// Requires that options use the `--option=val` syntax (i.e. an equals between the option
+    /// and its value)
+    RequireEquals,
+    /// Doesn't echo what the user types when [prompted] for the value, i.e. for passwords. Only
+    /// possible with the `color` Cargo feature on a Unix-like OS, otherwise prompting fails with
+    /// an [`ErrorKind::Io`] error
+    /// [prompted]: ./struct.Arg.html#method.prompt
+    /// [`ErrorKind::Io`]: ./enum.ErrorKind.html#variant.Io
+    HideInput,
+    /// Hides the current value of the argument's [environment variable] in the help text, only
+    /// showing its name, i.e. for variables holding secrets
+    /// [environment variable]: ./struct.Arg.html#method.env
+    HideEnvValues,
     #[doc(hidden)]
     RequiredUnlessAll,
     #[doc(hidden)]
@@ -100,6 +135,13 @@ impl FromStr for ArgSettings {
             "requiredelimiter" => Ok(ArgSettings::RequireDelimiter),
             "valuedelimiternotset" => Ok(ArgSettings::ValueDelimiterNotSet),
             "hidepossiblevalues" => Ok(ArgSettings::HidePossibleValues),
+            "caseinsensitive" => Ok(ArgSettings::CaseInsensitive),
+            "last" => Ok(ArgSettings::Last),
+            "allowleadinghyphen" => Ok(ArgSettings::AllowLeadingHyphen),
+            "negatable" => Ok(ArgSettings::Negatable),
+            "requireequals" => Ok(ArgSettings::RequireEquals),
+            "hideinput" => Ok(ArgSettings::HideInput),
+            "hideenvvalues" => Ok(ArgSettings::HideEnvValues),
             _ => Err("unknown ArgSetting, cannot convert from str".to_owned()),
         }
     }
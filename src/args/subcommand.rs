@@ -1,6 +1,8 @@
 // Third Party
 #[cfg(feature = "yaml")]
 use yaml_rust::Yaml;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 
 // Internal
 use App;
@@ -28,10 +30,12 @@ use ArgMatches;
 /// [`App`]: ./struct.App.html
 /// [arguments]: ./struct.Arg.html
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct SubCommand<'a> {
     #[doc(hidden)]
     pub name: String,
     #[doc(hidden)]
+    #[cfg_attr(feature = "serde", serde(borrow))]
     pub matches: ArgMatches<'a>,
 }
 
@@ -0,0 +1,35 @@
+// Third Party
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Where the values of a matched argument came from, as returned by
+/// [`ArgMatches::value_source`]. The sources are ordered from the least to the most explicit, so
+/// when an [`ArgGroup`] gets values from more than one source, its source is the greatest of them.
+///
+/// [`ArgMatches::value_source`]: ./struct.ArgMatches.html#method.value_source
+/// [`ArgGroup`]: ./struct.ArgGroup.html
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ValueSource {
+    /// The argument wasn't used, so it got its [default value], or its value from
+    /// [`ArgAction::SetTrue`] or [`ArgAction::SetFalse`]
+    ///
+    /// [default value]: ./struct.Arg.html#method.default_value
+    /// [`ArgAction::SetTrue`]: ./enum.ArgAction.html#variant.SetTrue
+    /// [`ArgAction::SetFalse`]: ./enum.ArgAction.html#variant.SetFalse
+    DefaultValue,
+    /// The values were read from a [config file] (requires the `config` Cargo feature)
+    ///
+    /// [config file]: ./struct.App.html#method.config_file
+    ConfigFile,
+    /// The value was read from the argument's [environment variable]
+    ///
+    /// [environment variable]: ./struct.Arg.html#method.env
+    EnvVariable,
+    /// The value was typed in by the user when [prompted] for it
+    ///
+    /// [prompted]: ./struct.Arg.html#method.prompt
+    Prompt,
+    /// The argument was used on the command line
+    CommandLine,
+}
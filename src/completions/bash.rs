@@ -233,6 +233,13 @@ complete -F _{name} {name}
                        p.long_list
                            .iter()
                            .fold(String::new(), |acc, l| format!("{} --{}", acc, l)));
+        opts = format!("{} {}",
+                       opts,
+                       p.flags
+                           .iter()
+                           .filter(|f| f.settings.is_set(ArgSettings::Negatable))
+                           .filter_map(|f| f.long)
+                           .fold(String::new(), |acc, l| format!("{} --no-{}", acc, l)));
         opts = format!("{} {}",
                        opts,
                        p.positionals
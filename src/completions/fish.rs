@@ -4,6 +4,7 @@ use std::io::Write;
 
 // Internal
 use app::parser::Parser;
+use args::ArgSettings;
 
 pub struct FishGen<'a, 'b>
     where 'a: 'b
@@ -87,6 +88,9 @@ fn gen_fish_inner(root_command: &str,
         }
         if let Some(data) = flag.long {
             template.push_str(format!(" -l {}", data).as_str());
+            if flag.settings.is_set(ArgSettings::Negatable) {
+                template.push_str(format!(" -l no-{}", data).as_str());
+            }
         }
         if let Some(data) = flag.help {
             template.push_str(format!(" -d \"{}\"", data).as_str());
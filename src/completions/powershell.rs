@@ -4,6 +4,7 @@ use std::io::Write;
 
 // Internal
 use app::parser::Parser;
+use args::ArgSettings;
 
 pub struct PowerShellGen<'a, 'b>
     where 'a: 'b
@@ -98,6 +99,12 @@ fn generate_inner<'a, 'b>(p: &Parser<'a, 'b>, previous_command_name: &str) -> (S
     for long in &p.long_list {
         completions.push_str(&format!("'--{}', ", long));
     }
+    for long in p.flags
+        .iter()
+        .filter(|f| f.settings.is_set(ArgSettings::Negatable))
+        .filter_map(|f| f.long) {
+        completions.push_str(&format!("'--no-{}', ", long));
+    }
 
     let mut subcommands_cases = format!(r"
             '{}' {{
@@ -357,11 +357,20 @@ fn write_flags_of(p: &Parser) -> String {
         }
 
         if let Some(long) = f.long() {
-            let l = format!("\"{conflicts}{multiple}--{arg}[{help}]\" \\",
-                conflicts = conflicts,
-                multiple = multiple,
-                arg = long,
-                help = help);
+            // Negatable flags complete as both --flag and --no-flag via brace expansion
+            let l = if f.is_set(ArgSettings::Negatable) {
+                format!("\"{conflicts}{multiple}\"{{--{arg},--no-{arg}}}\"[{help}]\" \\",
+                    conflicts = conflicts,
+                    multiple = multiple,
+                    arg = long,
+                    help = help)
+            } else {
+                format!("\"{conflicts}{multiple}--{arg}[{help}]\" \\",
+                    conflicts = conflicts,
+                    multiple = multiple,
+                    arg = long,
+                    help = help)
+            };
 
             debugln!("Wrote...{}", &*l);
             ret.push(l);
@@ -39,6 +39,9 @@ pub enum ErrorKind {
 
     /// Occurs when a user provides a flag, option, argument or subcommand which isn't defined.
     ///
+    /// This error is also returned when [`AppSettings::InferLongArgs`] is set and the long
+    /// argument is an ambiguous prefix of more than one long argument.
+    ///
     /// # Examples
     ///
     /// ```rust
@@ -49,6 +52,7 @@ pub enum ErrorKind {
     /// assert!(result.is_err());
     /// assert_eq!(result.unwrap_err().kind, ErrorKind::UnknownArgument);
     /// ```
+    /// [`AppSettings::InferLongArgs`]: ./enum.AppSettings.html#variant.InferLongArgs
     UnknownArgument,
 
     /// Occurs when the user provides an unrecognized [`SubCommand`] which meets the threshold for
@@ -56,6 +60,9 @@ pub enum ErrorKind {
     /// If it doesn't meet the threshold, or the 'suggestions' feature is disabled,
     /// the more general [`UnknownArgument`] error is returned.
     ///
+    /// This error is also returned when [`AppSettings::InferSubcommands`] is set and the
+    /// subcommand is an ambiguous prefix of more than one subcommand.
+    ///
     /// # Examples
     ///
     #[cfg_attr(not(feature="suggestions"), doc=" ```no_run")]
@@ -73,6 +80,7 @@ pub enum ErrorKind {
     /// ```
     /// [`SubCommand`]: ./struct.SubCommand.html
     /// [`UnknownArgument`]: ./enum.ErrorKind.html#variant.UnknownArgument
+    /// [`AppSettings::InferSubcommands`]: ./enum.AppSettings.html#variant.InferSubcommands
     InvalidSubcommand,
 
     /// Occurs when the user provides an unrecognized [`SubCommand`] which either
@@ -357,6 +365,29 @@ pub enum ErrorKind {
     /// [`values_t!`]: ./macro.values_t!.html
     ArgumentNotFound,
 
+    /// Occurs when using [`ArgMatches::get_one`] or [`ArgMatches::get_many`] to retrieve the
+    /// values of an argument as type `T`, but the argument's [value parser] produces a different
+    /// type, or the argument doesn't have a value parser at all.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg, ErrorKind};
+    /// let m = App::new("myprog")
+    ///     .arg(Arg::with_name("port")
+    ///         .long("port")
+    ///         .takes_value(true)
+    ///         .value_parser::<u16>())
+    ///     .get_matches_from(vec!["myprog", "--port", "8080"]);
+    /// let result = m.get_one::<String>("port");
+    /// assert!(result.is_err());
+    /// assert_eq!(result.unwrap_err().kind, ErrorKind::ValueTypeMismatch);
+    /// ```
+    /// [`ArgMatches::get_one`]: ./struct.ArgMatches.html#method.get_one
+    /// [`ArgMatches::get_many`]: ./struct.ArgMatches.html#method.get_many
+    /// [value parser]: ./struct.Arg.html#method.value_parser
+    ValueTypeMismatch,
+
     /// Represents an [I/O error].
     /// Can occur when writing to `stderr` or `stdout` or reading a configuration file.
     /// [I/O error]: https://doc.rust-lang.org/std/io/struct.Error.html
@@ -369,6 +400,85 @@ pub enum ErrorKind {
     Format,
 }
 
+/// The semantic kind of a piece of context attached to an [`Error`]
+///
+/// Each [`Error`] carries the context that was used to build its message, so callers can
+/// re-render, localize or serialize errors without parsing [`Error::message`].
+///
+/// # Examples
+///
+/// ```rust
+/// # use clap::{App, Arg, ContextKind, ContextValue};
+/// let res = App::new("myprog")
+///     .arg(Arg::with_name("speed")
+///         .long("speed")
+///         .takes_value(true)
+///         .possible_values(&["fast", "slow"]))
+///     .get_matches_from_safe(vec!["myprog", "--speed", "slo"]);
+/// let err = res.unwrap_err();
+/// assert_eq!(err.get(ContextKind::InvalidValue),
+///            Some(&ContextValue::String("slo".to_owned())));
+/// ```
+/// [`Error`]: ./struct.Error.html
+/// [`Error::message`]: ./struct.Error.html#structfield.message
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum ContextKind {
+    /// The argument which caused the error, as displayed in help and usage (i.e. `--opt <val>`)
+    InvalidArg,
+    /// The subcommand which caused the error
+    InvalidSubcommand,
+    /// The value which caused the error
+    InvalidValue,
+    /// An existing argument which conflicts with the [`ContextKind::InvalidArg`]
+    /// [`ContextKind::InvalidArg`]: ./enum.ContextKind.html#variant.InvalidArg
+    PriorArg,
+    /// The values which would have been accepted
+    ValidValue,
+    /// The subcommands which would have been accepted
+    ValidSubcommand,
+    /// The arguments which would have been accepted
+    ValidArg,
+    /// A similarly spelled argument the user may have meant
+    SuggestedArg,
+    /// A similarly spelled subcommand the user may have meant
+    SuggestedSubcommand,
+    /// A similarly spelled value the user may have meant
+    SuggestedValue,
+    /// The number of values the argument expects
+    ExpectedNumValues,
+    /// The minimum number of values the argument expects
+    MinValues,
+    /// The number of values which were actually provided
+    ActualNumValues,
+    /// The usage string, including the `USAGE:` title
+    Usage,
+    /// A free-form description, such as the message returned from a [`Arg::validator`]
+    /// [`Arg::validator`]: ./struct.Arg.html#method.validator
+    Custom,
+}
+
+/// The value of a piece of context attached to an [`Error`]
+/// [`Error`]: ./struct.Error.html
+#[derive(Debug, Clone, PartialEq)]
+pub enum ContextValue {
+    /// A single string, such as an argument or value
+    String(String),
+    /// A list of strings, such as the possible values of an argument
+    Strings(Vec<String>),
+    /// A number, such as a count of values
+    Number(usize),
+}
+
+impl Display for ContextValue {
+    fn fmt(&self, f: &mut std_fmt::Formatter) -> std_fmt::Result {
+        match *self {
+            ContextValue::String(ref s) => write!(f, "{}", s),
+            ContextValue::Strings(ref v) => write!(f, "{}", v.join(", ")),
+            ContextValue::Number(n) => write!(f, "{}", n),
+        }
+    }
+}
+
 /// Command Line Argument Parser Error
 #[derive(Debug)]
 pub struct Error {
@@ -378,9 +488,50 @@ pub struct Error {
     pub kind: ErrorKind,
     /// Any additional information passed along, such as the argument name that caused the error
     pub info: Option<Vec<String>>,
+    context: Vec<(ContextKind, ContextValue)>,
 }
 
 impl Error {
+    /// Returns the typed context used to build the [`Error::message`], such as the offending
+    /// argument, the bad value and the values which would have been accepted
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg, ContextKind};
+    /// let res = App::new("myprog")
+    ///     .arg(Arg::with_name("mode")
+    ///         .long("mode")
+    ///         .takes_value(true)
+    ///         .possible_values(&["fast", "slow"]))
+    ///     .get_matches_from_safe(vec!["myprog", "--mode", "fats"]);
+    /// let err = res.unwrap_err();
+    /// assert!(err.context().iter().any(|&(k, _)| k == ContextKind::ValidValue));
+    /// ```
+    /// [`Error::message`]: ./struct.Error.html#structfield.message
+    pub fn context(&self) -> &[(ContextKind, ContextValue)] {
+        &self.context
+    }
+
+    /// Returns the first piece of context of the given [`ContextKind`], if any
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg, ContextKind, ContextValue};
+    /// let res = App::new("myprog")
+    ///     .arg(Arg::with_name("debug")
+    ///         .long("debug"))
+    ///     .get_matches_from_safe(vec!["myprog", "--debug", "--debug"]);
+    /// let err = res.unwrap_err();
+    /// assert_eq!(err.get(ContextKind::InvalidArg),
+    ///            Some(&ContextValue::String("--debug".to_owned())));
+    /// ```
+    /// [`ContextKind`]: ./enum.ContextKind.html
+    pub fn get(&self, kind: ContextKind) -> Option<&ContextValue> {
+        self.context.iter().find(|&&(k, _)| k == kind).map(|&(_, ref v)| v)
+    }
+
     /// Should the message be written to `stdout` or not
     pub fn use_stderr(&self) -> bool {
         match self.kind {
@@ -417,31 +568,34 @@ impl Error {
               U: Display
     {
         let mut v = vec![arg.name().to_owned()];
+        let mut context = vec![(ContextKind::InvalidArg, ContextValue::String(arg.to_string()))];
         let c = fmt::Colorizer {
             use_stderr: true,
             when: color,
         };
+        let other = match other {
+            Some(name) => {
+                let n = name.into();
+                v.push(n.clone());
+                context.push((ContextKind::PriorArg, ContextValue::String(n.clone())));
+                c.warning(format!("'{}'", n))
+            }
+            None => c.none("one or more of the other specified arguments".to_owned()),
+        };
+        let usage = usage.to_string();
+        context.push((ContextKind::Usage, ContextValue::String(usage.clone())));
         Error {
             message: format!("{} The argument '{}' cannot be used with {}\n\n\
                             {}\n\n\
                             For more information try {}",
                              c.error("error:"),
                              c.warning(&*arg.to_string()),
-                             match other {
-                                 Some(name) => {
-                                     let n = name.into();
-                                     v.push(n.clone());
-                                     c.warning(format!("'{}'", n))
-                                 }
-                                 None => {
-                                     c.none("one or more of the other specified arguments"
-                                         .to_owned())
-                                 }
-                             },
+                             other,
                              usage,
                              c.good("--help")),
             kind: ErrorKind::ArgumentConflict,
             info: Some(v),
+            context: context,
         }
     }
 
@@ -454,6 +608,7 @@ impl Error {
             use_stderr: true,
             when: color,
         };
+        let usage = usage.to_string();
         Error {
             message: format!("{} The argument '{}' requires a value but none was supplied\
                             \n\n\
@@ -465,6 +620,8 @@ impl Error {
                              c.good("--help")),
             kind: ErrorKind::EmptyValue,
             info: Some(vec![arg.name().to_owned()]),
+            context: vec![(ContextKind::InvalidArg, ContextValue::String(arg.to_string())),
+                          (ContextKind::Usage, ContextValue::String(usage))],
         }
     }
 
@@ -496,6 +653,16 @@ impl Error {
         }
         sorted.sort();
         let valid_values = sorted.join(", ");
+        let usage = usage.to_string();
+        let good_vals_s = good_vals.iter().map(|v| v.as_ref().to_owned()).collect();
+        let mut context =
+            vec![(ContextKind::InvalidArg, ContextValue::String(arg.to_string())),
+                 (ContextKind::InvalidValue, ContextValue::String(bad_val.as_ref().to_owned())),
+                 (ContextKind::ValidValue, ContextValue::Strings(good_vals_s))];
+        if let Some(suggested) = suffix.1 {
+            context.push((ContextKind::SuggestedValue, ContextValue::String(suggested.to_owned())));
+        }
+        context.push((ContextKind::Usage, ContextValue::String(usage.clone())));
         Error {
             message: format!("{} '{}' isn't a valid value for '{}'\n\t\
                             [values: {}]\n\
@@ -511,6 +678,7 @@ impl Error {
                              c.good("--help")),
             kind: ErrorKind::InvalidValue,
             info: Some(vec![arg.name().to_owned(), bad_val.as_ref().to_owned()]),
+            context: context,
         }
     }
 
@@ -531,6 +699,7 @@ impl Error {
             use_stderr: true,
             when: color,
         };
+        let usage = usage.to_string();
         Error {
             message: format!("{} The subcommand '{}' wasn't recognized\n\t\
                             Did you mean '{}'?\n\n\
@@ -547,10 +716,60 @@ impl Error {
                              usage,
                              c.good("--help")),
             kind: ErrorKind::InvalidSubcommand,
+            context: vec![(ContextKind::InvalidSubcommand, ContextValue::String(s.clone())),
+                          (ContextKind::SuggestedSubcommand,
+                           ContextValue::String(did_you_mean.as_ref().to_owned())),
+                          (ContextKind::Usage, ContextValue::String(usage))],
             info: Some(vec![s]),
         }
     }
 
+    #[doc(hidden)]
+    pub fn ambiguous_subcommand<S, N, U>(subcmd: S,
+                                         candidates: &[&str],
+                                         name: N,
+                                         usage: U,
+                                         color: fmt::ColorWhen)
+                                         -> Self
+        where S: Into<String>,
+              N: Display,
+              U: Display
+    {
+        let s = subcmd.into();
+        let c = fmt::Colorizer {
+            use_stderr: true,
+            when: color,
+        };
+        let usage = usage.to_string();
+        let mut info = vec![s.clone()];
+        info.extend(candidates.iter().map(|&sc| sc.to_owned()));
+        Error {
+            message: format!("{} The subcommand '{}' is ambiguous\n\t\
+                            It could be any of: {}\n\n\
+                            If you believe you received this message in error, try \
+                            re-running with '{} {} {}'\n\n\
+                            {}\n\n\
+                            For more information try {}",
+                             c.error("error:"),
+                             c.warning(&*s),
+                             candidates.iter()
+                                 .map(|sc| format!("'{}'", c.good(sc)))
+                                 .collect::<Vec<_>>()
+                                 .join(", "),
+                             name,
+                             c.good("--"),
+                             &*s,
+                             usage,
+                             c.good("--help")),
+            kind: ErrorKind::InvalidSubcommand,
+            context: vec![(ContextKind::InvalidSubcommand, ContextValue::String(s.clone())),
+                          (ContextKind::ValidSubcommand,
+                           ContextValue::Strings(info[1..].to_vec())),
+                          (ContextKind::Usage, ContextValue::String(usage))],
+            info: Some(info),
+        }
+    }
+
     #[doc(hidden)]
     pub fn unrecognized_subcommand<S, N>(subcmd: S, name: N, color: fmt::ColorWhen) -> Self
         where S: Into<String>,
@@ -572,12 +791,17 @@ impl Error {
                              name,
                              c.good("--help")),
             kind: ErrorKind::UnrecognizedSubcommand,
+            context: vec![(ContextKind::InvalidSubcommand, ContextValue::String(s.clone()))],
             info: Some(vec![s]),
         }
     }
 
     #[doc(hidden)]
-    pub fn missing_required_argument<R, U>(required: R, usage: U, color: fmt::ColorWhen) -> Self
+    pub fn missing_required_argument<R, U>(required: R,
+                                           missing: Vec<String>,
+                                           usage: U,
+                                           color: fmt::ColorWhen)
+                                           -> Self
         where R: Display,
               U: Display
     {
@@ -585,6 +809,7 @@ impl Error {
             use_stderr: true,
             when: color,
         };
+        let usage = usage.to_string();
         Error {
             message: format!("{} The following required arguments were not provided:{}\n\n\
                             {}\n\n\
@@ -595,6 +820,8 @@ impl Error {
                              c.good("--help")),
             kind: ErrorKind::MissingRequiredArgument,
             info: None,
+            context: vec![(ContextKind::InvalidArg, ContextValue::Strings(missing)),
+                          (ContextKind::Usage, ContextValue::String(usage))],
         }
     }
 
@@ -607,6 +834,7 @@ impl Error {
             use_stderr: true,
             when: color,
         };
+        let usage = usage.to_string();
         Error {
             message: format!("{} '{}' requires a subcommand, but one was not provided\n\n\
                             {}\n\n\
@@ -617,6 +845,7 @@ impl Error {
                              c.good("--help")),
             kind: ErrorKind::MissingSubcommand,
             info: None,
+            context: vec![(ContextKind::Usage, ContextValue::String(usage))],
         }
     }
 
@@ -629,6 +858,7 @@ impl Error {
             use_stderr: true,
             when: color,
         };
+        let usage = usage.to_string();
         Error {
             message: format!("{} Invalid UTF-8 was detected in one or more arguments\n\n\
                             {}\n\n\
@@ -638,6 +868,7 @@ impl Error {
                              c.good("--help")),
             kind: ErrorKind::InvalidUtf8,
             info: None,
+            context: vec![(ContextKind::Usage, ContextValue::String(usage))],
         }
     }
 
@@ -656,6 +887,7 @@ impl Error {
             use_stderr: true,
             when: color,
         };
+        let usage = usage.to_string();
         Error {
             message: format!("{} The value '{}' was provided to '{}', but it wasn't expecting \
                             any more values\n\n\
@@ -668,6 +900,9 @@ impl Error {
                              c.good("--help")),
             kind: ErrorKind::TooManyValues,
             info: Some(vec![arg.name().to_owned(), v.to_owned()]),
+            context: vec![(ContextKind::InvalidArg, ContextValue::String(arg.to_string())),
+                          (ContextKind::InvalidValue, ContextValue::String(v.to_owned())),
+                          (ContextKind::Usage, ContextValue::String(usage))],
         }
     }
 
@@ -685,6 +920,7 @@ impl Error {
             use_stderr: true,
             when: color,
         };
+        let usage = usage.to_string();
         Error {
             message: format!("{} The argument '{}' requires at least {} values, but only {} w{} \
                             provided\n\n\
@@ -699,25 +935,42 @@ impl Error {
                              c.good("--help")),
             kind: ErrorKind::TooFewValues,
             info: Some(vec![arg.name().to_owned()]),
+            context: vec![(ContextKind::InvalidArg, ContextValue::String(arg.to_string())),
+                          (ContextKind::MinValues, ContextValue::Number(min_vals as usize)),
+                          (ContextKind::ActualNumValues, ContextValue::Number(curr_vals)),
+                          (ContextKind::Usage, ContextValue::String(usage))],
         }
     }
 
     #[doc(hidden)]
-    pub fn value_validation(err: String, color: fmt::ColorWhen) -> Self {
+    pub fn value_validation<'a, 'b>(arg: Option<&AnyArg<'a, 'b>>,
+                                    val: Option<&str>,
+                                    err: String,
+                                    color: fmt::ColorWhen)
+                                    -> Self {
         let c = fmt::Colorizer {
             use_stderr: true,
             when: color,
         };
+        let mut context = vec![];
+        if let Some(a) = arg {
+            context.push((ContextKind::InvalidArg, ContextValue::String(a.to_string())));
+        }
+        if let Some(v) = val {
+            context.push((ContextKind::InvalidValue, ContextValue::String(v.to_owned())));
+        }
+        context.push((ContextKind::Custom, ContextValue::String(err.clone())));
         Error {
             message: format!("{} {}", c.error("error:"), err),
             kind: ErrorKind::ValueValidation,
             info: None,
+            context: context,
         }
     }
 
     #[doc(hidden)]
     pub fn value_validation_auto(err: String) -> Self {
-        Error::value_validation(err, fmt::ColorWhen::Auto)
+        Error::value_validation(None, None, err, fmt::ColorWhen::Auto)
     }
 
     #[doc(hidden)]
@@ -736,6 +989,7 @@ impl Error {
             use_stderr: true,
             when: color,
         };
+        let usage = usage.to_string();
         Error {
             message: format!("{} The argument '{}' requires {} values, but {} w{} \
                             provided\n\n\
@@ -750,6 +1004,10 @@ impl Error {
                              c.good("--help")),
             kind: ErrorKind::WrongNumberOfValues,
             info: Some(vec![arg.name().to_owned()]),
+            context: vec![(ContextKind::InvalidArg, ContextValue::String(arg.to_string())),
+                          (ContextKind::ExpectedNumValues, ContextValue::Number(num_vals as usize)),
+                          (ContextKind::ActualNumValues, ContextValue::Number(curr_vals)),
+                          (ContextKind::Usage, ContextValue::String(usage))],
         }
     }
 
@@ -762,6 +1020,7 @@ impl Error {
             use_stderr: true,
             when: color,
         };
+        let usage = usage.to_string();
         Error {
             message: format!("{} The argument '{}' was provided more than once, but cannot \
                             be used multiple times\n\n\
@@ -773,12 +1032,14 @@ impl Error {
                              c.good("--help")),
             kind: ErrorKind::UnexpectedMultipleUsage,
             info: Some(vec![arg.name().to_owned()]),
+            context: vec![(ContextKind::InvalidArg, ContextValue::String(arg.to_string())),
+                          (ContextKind::Usage, ContextValue::String(usage))],
         }
     }
 
     #[doc(hidden)]
     pub fn unknown_argument<A, U>(arg: A,
-                                  did_you_mean: &str,
+                                  did_you_mean: Option<&str>,
                                   usage: U,
                                   color: fmt::ColorWhen)
                                   -> Self
@@ -790,6 +1051,19 @@ impl Error {
             use_stderr: true,
             when: color,
         };
+        let usage = usage.to_string();
+        let mut context = vec![(ContextKind::InvalidArg, ContextValue::String(a.clone()))];
+        let suffix = match did_you_mean {
+            Some(long) => {
+                context.push((ContextKind::SuggestedArg,
+                              ContextValue::String(format!("--{}", long))));
+                format!("\n\tDid you mean {}{}?\n",
+                        fmt::Format::Good("--"),
+                        fmt::Format::Good(long))
+            }
+            None => "\n".to_owned(),
+        };
+        context.push((ContextKind::Usage, ContextValue::String(usage.clone())));
         Error {
             message: format!("{} Found argument '{}' which wasn't expected, or isn't valid in \
             this context{}\n\
@@ -797,15 +1071,88 @@ impl Error {
                             For more information try {}",
                              c.error("error:"),
                              c.warning(&*a),
-                             if did_you_mean.is_empty() {
-                                 "\n".to_owned()
-                             } else {
-                                 format!("{}\n", did_you_mean)
-                             },
+                             suffix,
                              usage,
                              c.good("--help")),
             kind: ErrorKind::UnknownArgument,
             info: Some(vec![a]),
+            context: context,
+        }
+    }
+
+    #[doc(hidden)]
+    pub fn ambiguous_argument<A, U>(arg: A,
+                                    candidates: &[String],
+                                    usage: U,
+                                    color: fmt::ColorWhen)
+                                    -> Self
+        where A: Into<String>,
+              U: Display
+    {
+        let a = arg.into();
+        let c = fmt::Colorizer {
+            use_stderr: true,
+            when: color,
+        };
+        let usage = usage.to_string();
+        let candidates = candidates.iter().map(|l| format!("--{}", l)).collect::<Vec<_>>();
+        let mut info = vec![a.clone()];
+        info.extend(candidates.iter().cloned());
+        Error {
+            message: format!("{} The argument '{}' is ambiguous\n\t\
+                            It could be any of: {}\n\n\
+                            {}\n\n\
+                            For more information try {}",
+                             c.error("error:"),
+                             c.warning(&*a),
+                             candidates.iter()
+                                 .map(|l| format!("'{}'", c.good(l)))
+                                 .collect::<Vec<_>>()
+                                 .join(", "),
+                             usage,
+                             c.good("--help")),
+            kind: ErrorKind::UnknownArgument,
+            context: vec![(ContextKind::InvalidArg, ContextValue::String(a)),
+                          (ContextKind::ValidArg, ContextValue::Strings(candidates)),
+                          (ContextKind::Usage, ContextValue::String(usage))],
+            info: Some(info),
+        }
+    }
+
+    #[doc(hidden)]
+    pub fn missing_last_escape<'a, 'b, A, V, U>(arg: &A,
+                                                val: V,
+                                                usage: U,
+                                                color: fmt::ColorWhen)
+                                                -> Self
+        where A: AnyArg<'a, 'b> + Display,
+              V: Into<String>,
+              U: Display
+    {
+        let v = val.into();
+        let c = fmt::Colorizer {
+            use_stderr: true,
+            when: color,
+        };
+        let usage = usage.to_string();
+        let escaped = format!("-- {}", v);
+        Error {
+            message: format!("{} Found argument '{}' which wasn't expected, or isn't valid in \
+            this context\n\n\
+                            \tThe argument '{}' may only be supplied after '--', i.e. '{}'\n\n\
+                            {}\n\n\
+                            For more information try {}",
+                             c.error("error:"),
+                             c.warning(&*v),
+                             c.warning(arg.to_string()),
+                             c.good(&*escaped),
+                             usage,
+                             c.good("--help")),
+            kind: ErrorKind::UnknownArgument,
+            info: Some(vec![v.clone()]),
+            context: vec![(ContextKind::InvalidArg, ContextValue::String(v)),
+                          (ContextKind::SuggestedArg, ContextValue::String(escaped)),
+                          (ContextKind::Usage, ContextValue::String(usage))],
         }
     }
 
@@ -819,6 +1166,53 @@ impl Error {
             message: format!("{} {}", c.error("error:"), e.description()),
             kind: ErrorKind::Io,
             info: None,
+            context: vec![(ContextKind::Custom, ContextValue::String(e.description().to_owned()))],
+        }
+    }
+
+    #[doc(hidden)]
+    pub fn response_file<P, R>(path: P, reason: R, color: fmt::ColorWhen) -> Self
+        where P: Into<String>,
+              R: Display
+    {
+        let p = path.into();
+        let c = fmt::Colorizer {
+            use_stderr: true,
+            when: color,
+        };
+        let reason = reason.to_string();
+        Error {
+            message: format!("{} Failed to expand response file '{}': {}",
+                             c.error("error:"),
+                             c.warning(&*p),
+                             reason),
+            kind: ErrorKind::Io,
+            info: Some(vec![p.clone()]),
+            context: vec![(ContextKind::InvalidValue, ContextValue::String(p)),
+                          (ContextKind::Custom, ContextValue::String(reason))],
+        }
+    }
+
+    #[doc(hidden)]
+    pub fn config_file<P, R>(path: P, reason: R, color: fmt::ColorWhen) -> Self
+        where P: Into<String>,
+              R: Display
+    {
+        let p = path.into();
+        let c = fmt::Colorizer {
+            use_stderr: true,
+            when: color,
+        };
+        let reason = reason.to_string();
+        Error {
+            message: format!("{} Failed to read config file '{}': {}",
+                             c.error("error:"),
+                             c.warning(&*p),
+                             reason),
+            kind: ErrorKind::Io,
+            info: Some(vec![p.clone()]),
+            context: vec![(ContextKind::InvalidValue, ContextValue::String(p)),
+                          (ContextKind::Custom, ContextValue::String(reason))],
         }
     }
 
@@ -836,6 +1230,27 @@ impl Error {
                              c.error("error:"),
                              a.clone()),
             kind: ErrorKind::ArgumentNotFound,
+            context: vec![(ContextKind::InvalidArg, ContextValue::String(a.clone()))],
+            info: Some(vec![a]),
+        }
+    }
+
+    #[doc(hidden)]
+    pub fn value_type_mismatch_auto<A>(arg: A) -> Self
+        where A: Into<String>
+    {
+        let a = arg.into();
+        let c = fmt::Colorizer {
+            use_stderr: true,
+            when: fmt::ColorWhen::Auto,
+        };
+        Error {
+            message: format!("{} The values of argument '{}' were requested as a different type \
+                              than its value parser produces",
+                             c.error("error:"),
+                             a.clone()),
+            kind: ErrorKind::ValueTypeMismatch,
+            context: vec![(ContextKind::InvalidArg, ContextValue::String(a.clone()))],
             info: Some(vec![a]),
         }
     }
@@ -853,6 +1268,34 @@ impl Error {
             message: format!("{} {}", c.error("error:"), description),
             kind: kind,
             info: None,
+            context: vec![(ContextKind::Custom, ContextValue::String(description.to_owned()))],
+        }
+    }
+
+    /// Create an error of the given kind whose message is used exactly as is, i.e. without the
+    /// `error:` prefix added by [`Error::with_description`], and without any context.
+    ///
+    /// This is useful for errors which aren't really errors, such as an [`ArgAction::Custom`]
+    /// printing some information and exiting like `--help` does with
+    /// [`ErrorKind::HelpDisplayed`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{Error, ErrorKind};
+    /// let err = Error::raw(ErrorKind::HelpDisplayed, "git\nhg");
+    /// assert_eq!(err.message, "git\nhg");
+    /// assert!(err.context().is_empty());
+    /// ```
+    /// [`Error::with_description`]: ./struct.Error.html#method.with_description
+    /// [`ArgAction::Custom`]: ./enum.ArgAction.html#variant.Custom
+    /// [`ErrorKind::HelpDisplayed`]: ./enum.ErrorKind.html#variant.HelpDisplayed
+    pub fn raw<S: Into<String>>(kind: ErrorKind, message: S) -> Self {
+        Error {
+            message: message.into(),
+            kind: kind,
+            info: None,
+            context: vec![],
         }
     }
 }
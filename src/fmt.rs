@@ -12,11 +12,15 @@ use std::fmt;
 const STDERR: i32 = libc::STDERR_FILENO;
 #[cfg(all(feature = "color", not(target_os = "windows")))]
 const STDOUT: i32 = libc::STDOUT_FILENO;
+#[cfg(all(feature = "color", not(target_os = "windows")))]
+const STDIN: i32 = libc::STDIN_FILENO;
 
 #[cfg(target_os = "windows")]
 const STDERR: i32 = 0;
 #[cfg(target_os = "windows")]
 const STDOUT: i32 = 0;
+#[cfg(target_os = "windows")]
+const STDIN: i32 = 0;
 
 #[doc(hidden)]
 #[derive(Debug, Copy, Clone, PartialEq)]
@@ -40,6 +44,18 @@ pub fn is_a_tty(_: bool) -> bool {
     false
 }
 
+#[cfg(feature = "color")]
+pub fn is_a_tty_stdin() -> bool {
+    debugln!("fn=is_a_tty_stdin;");
+    unsafe { libc::isatty(STDIN) != 0 }
+}
+
+#[cfg(not(feature = "color"))]
+pub fn is_a_tty_stdin() -> bool {
+    debugln!("fn=is_a_tty_stdin;");
+    false
+}
+
 #[doc(hidden)]
 pub struct Colorizer {
     pub use_stderr: bool,
@@ -352,6 +352,8 @@
 //! #### Opt-in features
 //!
 //! * **"yaml"**: Enables building CLIs from YAML documents.
+//! * **"config"**: Enables reading argument values from layered TOML, INI and YAML config files.
+//! * **"serde"**: Enables serializing and deserializing `ArgMatches`.
 //!
 //! ### More Information
 //!
@@ -410,8 +412,14 @@
 extern crate strsim;
 #[cfg(feature = "color")]
 extern crate ansi_term;
-#[cfg(feature = "yaml")]
+#[cfg(any(feature = "yaml", feature = "config"))]
 extern crate yaml_rust;
+#[cfg(feature = "config")]
+extern crate toml;
+#[cfg(feature = "config")]
+extern crate ini;
+#[cfg(feature = "serde")]
+extern crate serde;
 #[cfg(any(feature = "wrap_help", feature = "color"))]
 extern crate libc;
 extern crate unicode_width;
@@ -424,10 +432,11 @@ extern crate unicode_segmentation;
 
 #[cfg(feature = "yaml")]
 pub use yaml_rust::YamlLoader;
-pub use args::{Arg, ArgGroup, ArgMatches, ArgSettings, SubCommand, Values, OsValues};
+pub use args::{Arg, ArgAction, ArgGroup, ArgMatches, ArgSettings, SubCommand, Values, OsValues, Indices,
+               ValueSource};
 pub use app::{App, AppSettings};
 pub use fmt::Format;
-pub use errors::{Error, ErrorKind, Result};
+pub use errors::{ContextKind, ContextValue, Error, ErrorKind, Result};
 pub use completions::Shell;
 
 #[macro_use]
@@ -441,6 +450,7 @@ mod errors;
 mod osstringext;
 mod strext;
 mod completions;
+mod manpage;
 
 const INTERNAL_ERROR_MSG: &'static str = "Fatal internal error. Please consider filing a bug \
                                           report at https://github.com/kbknapp/clap-rs/issues";
@@ -0,0 +1,258 @@
+// Std
+use std::collections::BTreeMap;
+use std::io::{self, Write};
+
+// Third Party
+use vec_map::VecMap;
+
+// Internal
+use app::{App, AppSettings};
+use app::parser::Parser;
+use args::{AnyArg, ArgSettings, DispOrder};
+
+pub struct ManGen<'a, 'b>
+    where 'a: 'b
+{
+    p: &'b Parser<'a, 'b>,
+}
+
+impl<'a, 'b> ManGen<'a, 'b> {
+    pub fn new(p: &'b Parser<'a, 'b>) -> Self {
+        ManGen { p: p }
+    }
+
+    pub fn generate_to<W: Write>(&self, buf: &mut W) {
+        match self.write_page(buf) {
+            Ok(..) => (),
+            Err(..) => panic!("Failed to write to man page file"),
+        }
+    }
+
+    fn write_page<W: Write>(&self, buf: &mut W) -> io::Result<()> {
+        let name = self.p.meta.bin_name.as_ref().unwrap_or(&self.p.meta.name);
+        debugln!("fn=write_page;name={}", name);
+        try!(writeln!(buf,
+                      ".TH {} 1 \"\" \"{}\"",
+                      escape(&*name.to_uppercase()),
+                      escape(&*format!("{}{}",
+                                       name,
+                                       self.p
+                                           .meta
+                                           .version
+                                           .map_or(String::new(), |v| format!(" {}", v))))));
+
+        try!(writeln!(buf, ".SH NAME"));
+        if let Some(about) = self.p.meta.about {
+            try!(writeln!(buf, "{} \\- {}", escape(name), escape(about)));
+        } else {
+            try!(writeln!(buf, "{}", escape(name)));
+        }
+
+        try!(writeln!(buf, ".SH SYNOPSIS"));
+        try!(write_synopsis(self.p, buf));
+
+        if self.p.meta.pre_help.is_some() || self.p.meta.about.is_some() ||
+           self.p.meta.long_about.is_some() {
+            try!(writeln!(buf, ".SH DESCRIPTION"));
+            try!(write_description(self.p, buf));
+        }
+
+        if has_visible_switches(self.p) {
+            try!(writeln!(buf, ".SH OPTIONS"));
+            try!(write_switches(self.p, buf));
+        }
+
+        if has_visible_positionals(self.p) {
+            try!(writeln!(buf, ".SH ARGS"));
+            try!(write_positionals(self.p, buf));
+        }
+
+        let subcmds = visible_subcommands(self.p);
+        if !subcmds.is_empty() {
+            try!(writeln!(buf, ".SH SUBCOMMANDS"));
+            for sc in subcmds {
+                try!(write_subcommand(&sc.p, buf));
+            }
+        }
+
+        if let Some(more) = self.p.meta.more_help {
+            try!(writeln!(buf, ".SH NOTES"));
+            try!(write_paragraphs(more, buf));
+        }
+
+        if let Some(author) = self.p.meta.author {
+            try!(writeln!(buf, ".SH AUTHOR"));
+            try!(writeln!(buf, "{}", escape(author)));
+        }
+        Ok(())
+    }
+}
+
+// Writes a subcommand as its own subsection of the SUBCOMMANDS section, followed by all of its
+// (visible) children so that the entire tree ends up in the one page.
+fn write_subcommand<W: Write>(p: &Parser, buf: &mut W) -> io::Result<()> {
+    let name = p.meta.bin_name.as_ref().unwrap_or(&p.meta.name);
+    debugln!("fn=write_subcommand;name={}", name);
+    try!(writeln!(buf, ".SS \"{}\"", escape(name)));
+    try!(write_synopsis(p, buf));
+    try!(write_description(p, buf));
+    if has_visible_switches(p) {
+        try!(writeln!(buf, ".PP\n\\fBOPTIONS:\\fR"));
+        try!(write_switches(p, buf));
+    }
+    if has_visible_positionals(p) {
+        try!(writeln!(buf, ".PP\n\\fBARGS:\\fR"));
+        try!(write_positionals(p, buf));
+    }
+    for sc in visible_subcommands(p) {
+        try!(write_subcommand(&sc.p, buf));
+    }
+    Ok(())
+}
+
+fn write_synopsis<W: Write>(p: &Parser, buf: &mut W) -> io::Result<()> {
+    let usage = p.create_usage_no_title(&[]);
+    for line in usage.lines().map(|l| l.trim()).filter(|l| !l.is_empty()) {
+        try!(writeln!(buf, ".PP\n{}", bold_bin_name(p, line)));
+    }
+    Ok(())
+}
+
+fn write_description<W: Write>(p: &Parser, buf: &mut W) -> io::Result<()> {
+    if let Some(pre) = p.meta.pre_help {
+        try!(write_paragraphs(pre, buf));
+    }
+    // A man page is the place for the detailed description, so prefer the long version
+    if let Some(about) = p.meta.long_about.or(p.meta.about) {
+        try!(write_paragraphs(about, buf));
+    }
+    Ok(())
+}
+
+fn write_paragraphs<W: Write>(s: &str, buf: &mut W) -> io::Result<()> {
+    for para in s.split("\n\n").map(|p| p.trim()).filter(|p| !p.is_empty()) {
+        try!(writeln!(buf, ".PP\n{}", escape(para)));
+    }
+    Ok(())
+}
+
+// Flags and options share a section and are sorted the same way the help message sorts them, by
+// display order and then by name.
+fn write_switches<W: Write>(p: &Parser, buf: &mut W) -> io::Result<()> {
+    let mut ord_m = VecMap::new();
+    for f in p.flags().filter(|f| !f.is_set(ArgSettings::Hidden)) {
+        let arg: &AnyArg = f;
+        let btm = ord_m.entry(f.disp_ord()).or_insert(BTreeMap::new());
+        btm.insert(f.name(), (switch_term(f, None), arg));
+    }
+    for o in p.opts().filter(|o| !o.is_set(ArgSettings::Hidden)) {
+        // The Display impl writes the switch followed by a single space and the value names
+        let disp = o.to_string();
+        let vals = disp.splitn(2, ' ').nth(1).unwrap_or("").to_owned();
+        let arg: &AnyArg = o;
+        let btm = ord_m.entry(o.disp_ord()).or_insert(BTreeMap::new());
+        btm.insert(o.name(), (switch_term(o, Some(&*vals)), arg));
+    }
+    for btm in ord_m.values() {
+        for &(ref term, arg) in btm.values() {
+            try!(write_item(term, arg, buf));
+        }
+    }
+    Ok(())
+}
+
+fn write_positionals<W: Write>(p: &Parser, buf: &mut W) -> io::Result<()> {
+    for pos in p.positionals().filter(|p| !p.is_set(ArgSettings::Hidden)) {
+        try!(write_item(&*format!("\\fI{}\\fR", escape(&*pos.to_string())), pos, buf));
+    }
+    Ok(())
+}
+
+fn write_item<W: Write>(term: &str, arg: &AnyArg, buf: &mut W) -> io::Result<()> {
+    try!(writeln!(buf, ".TP\n{}", term));
+    if let Some(h) = arg.long_help().or_else(|| arg.help()) {
+        try!(writeln!(buf, "{}", escape(h)));
+    }
+    if let Some(env) = arg.env() {
+        try!(writeln!(buf, ".br\n[env: {}]", escape(&*env.0.to_string_lossy())));
+    }
+    if let Some(dv) = arg.default_val() {
+        try!(writeln!(buf, ".br\n[default: {}]", escape(dv)));
+    }
+    if let Some(ifs) = arg.default_vals_ifs() {
+        for &(a, val, def) in ifs {
+            let cond = val.map_or(a.to_owned(), |v| format!("{}={}", a, v));
+            try!(writeln!(buf, ".br\n[default: {} if {}]", escape(def), escape(&*cond)));
+        }
+    }
+    if let Some(ref aliases) = arg.aliases() {
+        try!(writeln!(buf, ".br\n[aliases: {}]", escape(&*aliases.join(", "))));
+    }
+    if !arg.is_set(ArgSettings::HidePossibleValues) {
+        if let Some(pv) = arg.possible_vals() {
+            try!(writeln!(buf, ".br\n[possible values: {}]", escape(&*pv.join(", "))));
+        }
+    }
+    Ok(())
+}
+
+fn switch_term(arg: &AnyArg, vals: Option<&str>) -> String {
+    let mut switches = vec![];
+    if let Some(s) = arg.short() {
+        switches.push(format!("\\fB\\-{}\\fR", escape(&*s.to_string())));
+    }
+    if let Some(l) = arg.long() {
+        if arg.is_set(ArgSettings::Negatable) {
+            switches.push(format!("\\fB\\-\\-[no\\-]{}\\fR", escape(l)));
+        } else {
+            switches.push(format!("\\fB\\-\\-{}\\fR", escape(l)));
+        }
+    }
+    let mut term = switches.join(", ");
+    if let Some(v) = vals {
+        term.push_str(&*format!(" \\fI{}\\fR", escape(v)));
+    }
+    term
+}
+
+// Highlights the leading binary name of a usage line, i.e. "myapp sub [FLAGS]"
+fn bold_bin_name(p: &Parser, line: &str) -> String {
+    let name = p.meta.bin_name.as_ref().unwrap_or(&p.meta.name);
+    if line.starts_with(&**name) {
+        format!("\\fB{}\\fR{}", escape(name), escape(&line[name.len()..]))
+    } else {
+        escape(line)
+    }
+}
+
+fn has_visible_switches(p: &Parser) -> bool {
+    p.flags().any(|f| !f.is_set(ArgSettings::Hidden)) ||
+    p.opts().any(|o| !o.is_set(ArgSettings::Hidden))
+}
+
+fn has_visible_positionals(p: &Parser) -> bool {
+    p.positionals().any(|p| !p.is_set(ArgSettings::Hidden))
+}
+
+fn visible_subcommands<'a, 'b, 'c>(p: &'c Parser<'a, 'b>) -> Vec<&'c App<'a, 'b>> {
+    let mut ord_m = VecMap::new();
+    for sc in p.subcommands.iter().filter(|s| !s.p.is_set(AppSettings::Hidden)) {
+        let btm = ord_m.entry(sc.p.meta.disp_ord).or_insert(BTreeMap::new());
+        btm.insert(&*sc.p.meta.name, sc);
+    }
+    ord_m.values().flat_map(|btm| btm.values().cloned()).collect()
+}
+
+// Escapes text for use in roff source, which treats backslashes, hyphens and lines beginning with
+// a control character specially.
+fn escape(s: &str) -> String {
+    let escaped = s.replace('\\', "\\e").replace('-', "\\-");
+    escaped.lines()
+        .map(|l| if l.starts_with('.') || l.starts_with('\'') {
+            format!("\\&{}", l)
+        } else {
+            l.to_owned()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
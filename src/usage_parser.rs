@@ -16,6 +16,7 @@ enum UsageToken {
     Long,
     Help,
     Multiple,
+    Terminator,
     Unknown,
 }
 
@@ -51,6 +52,9 @@ impl<'a> UsageParser<'a> {
         let mut arg = Arg::default();
         loop {
             debugln!("iter; pos={};", self.pos);
+            if self.prev == UsageToken::Multiple {
+                self.terminator(&mut arg);
+            }
             self.stop_at(token);
             if let Some(&c) = self.usage.as_bytes().get(self.pos) {
                 match c {
@@ -179,6 +183,24 @@ impl<'a> UsageParser<'a> {
         }
     }
 
+    // "something... ;"
+    fn terminator(&mut self, arg: &mut Arg<'a, 'a>) {
+        debugln!("fn=terminator;");
+        self.stop_at(|b| !terminator_end(b));
+        if let Some(&c) = self.usage.as_bytes().get(self.pos) {
+            if token(c) {
+                self.stop_at(terminator_end);
+                debugln!("setting terminator: {}", &self.usage[self.start..self.pos]);
+                arg.terminator = Some(&self.usage[self.start..self.pos]);
+                // Values up to the terminator are taken verbatim, so don't split them
+                arg.unsetb(ArgSettings::UseValueDelimiter);
+                arg.setb(ArgSettings::ValueDelimiterNotSet);
+                arg.val_delim = None;
+            }
+        }
+        self.prev = UsageToken::Terminator;
+    }
+
     fn help(&mut self, arg: &mut Arg<'a, 'a>) {
         debugln!("fn=help;");
         self.stop_at(help_start);
@@ -206,6 +228,11 @@ fn long_end(b: u8) -> bool {
     b != b'\'' && b != b'.' && b != b'<' && b != b'[' && b != b'=' && b != b' '
 }
 
+#[inline]
+fn terminator_end(b: u8) -> bool {
+    b != b' ' && b != b'\t' && b != b'\n' && b != b'\r'
+}
+
 #[inline]
 fn help_start(b: u8) -> bool {
     b != b'\''
@@ -1177,6 +1204,43 @@ mod test {
         assert!(c.num_vals.is_none());
     }
 
+    #[test]
+    fn opt_mult_terminator() {
+        let a = Arg::from_usage("--exec <cmd>... ; 'some help info'");
+        assert_eq!(a.name, "exec");
+        assert_eq!(a.long.unwrap(), "exec");
+        assert_eq!(a.help.unwrap(), "some help info");
+        assert!(a.is_set(ArgSettings::Multiple));
+        assert!(a.is_set(ArgSettings::TakesValue));
+        assert!(!a.is_set(ArgSettings::UseValueDelimiter));
+        assert!(a.val_delim.is_none());
+        assert_eq!(a.terminator, Some(";"));
+        assert_eq!(a.val_names.unwrap().values().collect::<Vec<_>>(), [&"cmd"]);
+
+        let b = Arg::from_usage("[exec] -e [cmd]... +");
+        assert_eq!(b.name, "exec");
+        assert_eq!(b.short.unwrap(), 'e');
+        assert!(b.help.is_none());
+        assert_eq!(b.terminator, Some("+"));
+    }
+
+    #[test]
+    fn pos_mult_terminator() {
+        let a = Arg::from_usage("[files]... ;; 'some help info'");
+        assert_eq!(a.name, "files");
+        assert!(a.is_set(ArgSettings::Multiple));
+        assert_eq!(a.terminator, Some(";;"));
+        assert_eq!(a.help.unwrap(), "some help info");
+    }
+
+    #[test]
+    fn mult_no_terminator() {
+        let a = Arg::from_usage("--opt [val]... 'some help info'");
+        assert!(a.terminator.is_none());
+        assert!(a.is_set(ArgSettings::UseValueDelimiter));
+        assert_eq!(a.help.unwrap(), "some help info");
+    }
+
     #[test]
     fn nonascii() {
         let a = Arg::from_usage("<ASCII> 'üñíčöĐ€'");
@@ -1,7 +1,18 @@
 extern crate clap;
 
+use std::env;
+use std::fs::File;
+use std::io::Write;
+use std::path::PathBuf;
+
 use clap::{App, Arg, SubCommand, AppSettings, ErrorKind};
 
+fn response_file(name: &str, contents: &str) -> PathBuf {
+    let path = env::temp_dir().join(format!("clap-{}-{}", name, std::process::id()));
+    File::create(&path).unwrap().write_all(contents.as_bytes()).unwrap();
+    path
+}
+
 #[test]
 fn sub_command_negate_required() {
     App::new("sub_command_negate")
@@ -371,3 +382,184 @@ fn test_unset_settings() {
     assert!(!m.p.is_set(AppSettings::AllowInvalidUtf8));
     assert!(!m.p.is_set(AppSettings::ColorAuto));
 }
+
+#[test]
+fn response_files() {
+    let path = response_file("rsp", "--opt 'some value'\n\"in put\"");
+    let m = App::new("rsp")
+        .setting(AppSettings::ResponseFiles)
+        .arg(Arg::from_usage("--opt [val] 'some option'"))
+        .arg(Arg::from_usage("[input]... 'some input'"))
+        .get_matches_from(vec!["rsp".to_owned(),
+                               format!("@{}", path.display()),
+                               "@@literal".to_owned(),
+                               "@".to_owned()]);
+    assert_eq!(m.value_of("opt"), Some("some value"));
+    assert_eq!(m.values_of("input").unwrap().collect::<Vec<_>>(),
+               ["in put", "@literal", "@"]);
+}
+
+#[test]
+fn response_files_nested() {
+    let inner = response_file("rsp-inner", "--flag");
+    let outer = response_file("rsp-outer", &format!("@'{}' value", inner.display()));
+    let m = App::new("rsp")
+        .setting(AppSettings::ResponseFiles)
+        .arg(Arg::from_usage("--flag 'some flag'"))
+        .arg(Arg::from_usage("[input] 'some input'"))
+        .get_matches_from(vec!["rsp".to_owned(), format!("@{}", outer.display())]);
+    assert!(m.is_present("flag"));
+    assert_eq!(m.value_of("input"), Some("value"));
+}
+
+#[test]
+fn response_files_recursion_limit() {
+    let path = env::temp_dir().join(format!("clap-rsp-self-{}", std::process::id()));
+    let path = response_file("rsp-self", &format!("@'{}'", path.display()));
+    let res = App::new("rsp")
+        .setting(AppSettings::ResponseFiles)
+        .get_matches_from_safe(vec!["rsp".to_owned(), format!("@{}", path.display())]);
+    assert!(res.is_err());
+    let err = res.unwrap_err();
+    assert_eq!(err.kind, ErrorKind::Io);
+    assert!(err.message.contains(&*path.display().to_string()));
+}
+
+#[test]
+fn response_files_missing() {
+    let res = App::new("rsp")
+        .setting(AppSettings::ResponseFiles)
+        .arg(Arg::from_usage("[input] 'some input'"))
+        .get_matches_from_safe(vec!["rsp", "@clap-no-such-file.txt"]);
+    assert!(res.is_err());
+    let err = res.unwrap_err();
+    assert_eq!(err.kind, ErrorKind::Io);
+    assert_eq!(err.info, Some(vec!["clap-no-such-file.txt".to_owned()]));
+}
+
+#[test]
+fn response_files_unterminated_quote() {
+    let path = response_file("rsp-quote", "'oops");
+    let res = App::new("rsp")
+        .setting(AppSettings::ResponseFiles)
+        .arg(Arg::from_usage("[input] 'some input'"))
+        .get_matches_from_safe(vec!["rsp".to_owned(), format!("@{}", path.display())]);
+    assert!(res.is_err());
+    assert_eq!(res.unwrap_err().kind, ErrorKind::Io);
+}
+
+#[test]
+fn response_files_stop_at_double_dash() {
+    let path = response_file("rsp-dash", "--flag -- @not-a-file");
+    let m = App::new("rsp")
+        .setting(AppSettings::ResponseFiles)
+        .arg(Arg::from_usage("--flag 'some flag'"))
+        .arg(Arg::from_usage("[input]... 'some input'"))
+        .get_matches_from(vec!["rsp".to_owned(),
+                               format!("@{}", path.display()),
+                               "@clap-no-such-file.txt".to_owned(),
+                               "@@literal".to_owned()]);
+    assert!(m.is_present("flag"));
+    assert_eq!(m.values_of("input").unwrap().collect::<Vec<_>>(),
+               ["@not-a-file", "@clap-no-such-file.txt", "@@literal"]);
+}
+
+#[test]
+fn response_files_not_set() {
+    let m = App::new("rsp")
+        .arg(Arg::from_usage("[input] 'some input'"))
+        .get_matches_from(vec!["rsp", "@clap-no-such-file.txt"]);
+    assert_eq!(m.value_of("input"), Some("@clap-no-such-file.txt"));
+}
+
+#[test]
+fn infer_long_args_flag() {
+    let m = App::new("prog")
+        .setting(AppSettings::InferLongArgs)
+        .arg(Arg::from_usage("--verbose 'some flag'"))
+        .get_matches_from(vec!["prog", "--verb"]);
+    assert!(m.is_present("verbose"));
+}
+
+#[test]
+fn infer_long_args_opt() {
+    let app = App::new("prog")
+        .setting(AppSettings::InferLongArgs)
+        .arg(Arg::from_usage("--output [file] 'some opt'"));
+    let m = app.clone().get_matches_from(vec!["prog", "--out", "a.txt"]);
+    assert_eq!(m.value_of("output"), Some("a.txt"));
+    let m = app.get_matches_from(vec!["prog", "--o=b.txt"]);
+    assert_eq!(m.value_of("output"), Some("b.txt"));
+}
+
+#[test]
+fn infer_long_args_alias() {
+    let m = App::new("prog")
+        .setting(AppSettings::InferLongArgs)
+        .arg(Arg::from_usage("--output [file] 'some opt'").alias("destination"))
+        .get_matches_from(vec!["prog", "--dest", "a.txt"]);
+    assert_eq!(m.value_of("output"), Some("a.txt"));
+}
+
+#[test]
+fn infer_long_args_name_and_alias_same_arg() {
+    let m = App::new("prog")
+        .setting(AppSettings::InferLongArgs)
+        .arg(Arg::from_usage("--test 'some flag'").alias("testing"))
+        .get_matches_from(vec!["prog", "--te"]);
+    assert!(m.is_present("test"));
+}
+
+#[test]
+fn infer_long_args_exact_match_wins() {
+    let m = App::new("prog")
+        .setting(AppSettings::InferLongArgs)
+        .arg(Arg::from_usage("--all 'some flag'"))
+        .arg(Arg::from_usage("--all-targets 'some other flag'"))
+        .get_matches_from(vec!["prog", "--all"]);
+    assert!(m.is_present("all"));
+    assert!(!m.is_present("all-targets"));
+}
+
+#[test]
+fn infer_long_args_negated() {
+    let m = App::new("prog")
+        .setting(AppSettings::InferLongArgs)
+        .arg(Arg::from_usage("--color 'some flag'").negatable(true))
+        .get_matches_from(vec!["prog", "--color", "--no-col"]);
+    assert!(!m.is_present("color"));
+    assert!(m.is_negated("color"));
+}
+
+#[test]
+fn infer_long_args_help() {
+    let res = App::new("prog")
+        .setting(AppSettings::InferLongArgs)
+        .get_matches_from_safe(vec!["prog", "--he"]);
+    assert!(res.is_err());
+    assert_eq!(res.unwrap_err().kind, ErrorKind::HelpDisplayed);
+}
+
+#[test]
+fn infer_long_args_ambiguous() {
+    let res = App::new("prog")
+        .setting(AppSettings::InferLongArgs)
+        .arg(Arg::from_usage("--verbose 'some flag'"))
+        .arg(Arg::from_usage("--verbatim [mode] 'some opt'"))
+        .get_matches_from_safe(vec!["prog", "--verb"]);
+    assert!(res.is_err());
+    let err = res.unwrap_err();
+    assert_eq!(err.kind, ErrorKind::UnknownArgument);
+    assert_eq!(err.info,
+               Some(vec!["--verb".to_owned(), "--verbatim".to_owned(), "--verbose".to_owned()]));
+    assert!(err.message.contains("It could be any of: '--verbatim', '--verbose'"));
+}
+
+#[test]
+fn infer_long_args_not_set() {
+    let res = App::new("prog")
+        .arg(Arg::from_usage("--verbose 'some flag'"))
+        .get_matches_from_safe(vec!["prog", "--verb"]);
+    assert!(res.is_err());
+    assert_eq!(res.unwrap_err().kind, ErrorKind::UnknownArgument);
+}
@@ -23,3 +23,25 @@ fn test_generation() {
     assert_eq!(first_line, "_myapp() {");
     assert_eq!(last_line, "complete -F _myapp myapp");
 }
+
+#[test]
+fn negatable_flag_completions() {
+    let app = || {
+        App::new("myapp")
+            .arg(Arg::with_name("color")
+                .long("color")
+                .help("colors the output")
+                .negatable(true))
+            .subcommand(SubCommand::with_name("test"))
+    };
+    let gen = |shell| {
+        let mut buf = vec![];
+        app().gen_completions_to("myapp", shell, &mut buf);
+        String::from_utf8(buf).unwrap()
+    };
+
+    assert!(gen(Shell::Bash).contains(" --no-color"));
+    assert!(gen(Shell::Fish).contains("-l color -l no-color -d \"colors the output\""));
+    assert!(gen(Shell::Zsh).contains("{--color,--no-color}\"[colors the output]\""));
+    assert!(gen(Shell::PowerShell).contains("'--no-color'"));
+}
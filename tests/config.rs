@@ -0,0 +1,216 @@
+#![cfg(feature="config")]
+
+extern crate clap;
+
+use std::env;
+use std::fs::{self, File};
+use std::io::Write;
+use std::path::PathBuf;
+
+use clap::{App, Arg, ArgAction, ErrorKind, SubCommand, ValueSource};
+
+// Writes a config file to the temp dir, named after the test so tests can run in parallel
+fn config(name: &str, contents: &str) -> PathBuf {
+    let dir = env::temp_dir().join(format!("clap-config-{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    let path = dir.join(name);
+    File::create(&path).unwrap().write_all(contents.as_bytes()).unwrap();
+    path
+}
+
+fn app() -> App<'static, 'static> {
+    App::new("cfg")
+        .arg(Arg::with_name("cluster")
+            .long("cluster")
+            .takes_value(true))
+        .arg(Arg::with_name("port")
+            .long("port")
+            .takes_value(true)
+            .default_value("80")
+            .validator(|v| v.parse::<u16>().map(|_| ()).map_err(|e| e.to_string())))
+        .arg(Arg::with_name("verbose")
+            .short("v")
+            .multiple(true))
+        .subcommand(SubCommand::with_name("deploy")
+            .arg(Arg::with_name("region")
+                .long("region")
+                .takes_value(true)
+                .possible_values(&["eu", "us"]))
+            .arg(Arg::with_name("dry-run").long("dry-run")))
+}
+
+#[test]
+fn config_toml() {
+    let path = config("toml.toml", "cluster = \"prod\"\nport = 8080\nverbose = 2\n");
+    let m = app().config_file(path).get_matches_from(vec!["cfg"]);
+
+    assert_eq!(m.value_of("cluster"), Some("prod"));
+    assert_eq!(m.value_of("port"), Some("8080"));
+    assert_eq!(m.occurrences_of("verbose"), 2);
+    assert_eq!(m.value_source("cluster"), Some(ValueSource::ConfigFile));
+    assert_eq!(m.value_source("verbose"), Some(ValueSource::ConfigFile));
+}
+
+#[test]
+fn config_ini() {
+    let path = config("ini.ini", "cluster = prod\n\n[deploy]\nregion = eu\ndry-run = true\n");
+    let m = app().config_file(path).get_matches_from(vec!["cfg", "deploy"]);
+
+    assert_eq!(m.value_of("cluster"), Some("prod"));
+    let sub_m = m.subcommand_matches("deploy").unwrap();
+    assert_eq!(sub_m.value_of("region"), Some("eu"));
+    assert!(sub_m.is_present("dry-run"));
+}
+
+#[test]
+fn config_yaml() {
+    let path = config("yaml.yml", "cluster: prod\ndeploy:\n  region: us\n");
+    let m = app().config_file(path).get_matches_from(vec!["cfg", "deploy"]);
+
+    assert_eq!(m.value_of("cluster"), Some("prod"));
+    assert_eq!(m.subcommand_matches("deploy").unwrap().value_of("region"), Some("us"));
+}
+
+#[test]
+fn config_command_line_wins() {
+    let path = config("cli_wins.toml", "cluster = \"prod\"\n");
+    let m = app().config_file(path).get_matches_from(vec!["cfg", "--cluster", "staging"]);
+
+    assert_eq!(m.value_of("cluster"), Some("staging"));
+}
+
+#[test]
+fn config_env_wins() {
+    env::set_var("CLP_CONFIG_CLUSTER", "staging");
+    let path = config("env_wins.toml", "cluster = \"prod\"\n");
+    let m = App::new("cfg")
+        .config_file(path)
+        .arg(Arg::with_name("cluster")
+            .long("cluster")
+            .env("CLP_CONFIG_CLUSTER"))
+        .get_matches_from(vec!["cfg"]);
+
+    assert_eq!(m.value_of("cluster"), Some("staging"));
+}
+
+#[test]
+fn config_layers() {
+    let system = config("system.toml", "cluster = \"prod\"\nport = 8080\n[deploy]\nregion = \"eu\"\n");
+    let user = config("user.toml", "port = 9090\n");
+    let project = config("project.ini", "[deploy]\ndry-run = true\n");
+    let m = app()
+        .config_files(&[system, user, project])
+        .get_matches_from(vec!["cfg", "deploy"]);
+
+    assert_eq!(m.value_of("cluster"), Some("prod"));
+    assert_eq!(m.value_of("port"), Some("9090"));
+    let sub_m = m.subcommand_matches("deploy").unwrap();
+    assert_eq!(sub_m.value_of("region"), Some("eu"));
+    assert!(sub_m.is_present("dry-run"));
+}
+
+#[test]
+fn config_missing_file_skipped() {
+    let m = app()
+        .config_file("/this/file/does/not/exist.toml")
+        .get_matches_from(vec!["cfg"]);
+
+    assert!(!m.is_present("cluster"));
+    assert_eq!(m.value_of("port"), Some("80"));
+}
+
+#[test]
+fn config_unknown_keys_ignored() {
+    let path = config("unknown.toml", "colour = \"blue\"\ncluster = \"prod\"\n");
+    let m = app().config_file(path).get_matches_from(vec!["cfg"]);
+
+    assert_eq!(m.value_of("cluster"), Some("prod"));
+}
+
+#[test]
+fn config_value_validated() {
+    let path = config("validated.toml", "port = \"eighty\"\n");
+    let res = app().config_file(path).get_matches_from_safe(vec!["cfg"]);
+
+    assert!(res.is_err());
+    assert_eq!(res.unwrap_err().kind, ErrorKind::ValueValidation);
+}
+
+#[test]
+fn config_possible_values() {
+    let path = config("possible.ini", "[deploy]\nregion = asia\n");
+    let res = app().config_file(path).get_matches_from_safe(vec!["cfg", "deploy"]);
+
+    assert!(res.is_err());
+    assert_eq!(res.unwrap_err().kind, ErrorKind::InvalidValue);
+}
+
+#[test]
+fn config_parse_error() {
+    let path = config("broken.toml", "cluster = \n");
+    let res = app().config_file(path).get_matches_from_safe(vec!["cfg"]);
+
+    assert!(res.is_err());
+    assert_eq!(res.unwrap_err().kind, ErrorKind::Io);
+}
+
+#[test]
+fn config_unknown_format() {
+    let path = config("unknown.json", "{}");
+    let res = app().config_file(path).get_matches_from_safe(vec!["cfg"]);
+
+    assert!(res.is_err());
+    assert_eq!(res.unwrap_err().kind, ErrorKind::Io);
+}
+
+#[test]
+fn config_multiple_values() {
+    let path = config("multiple.toml", "files = [\"a.txt\", \"b.txt\"]\n");
+    let m = App::new("cfg")
+        .config_file(path)
+        .arg(Arg::with_name("files").multiple(true))
+        .get_matches_from(vec!["cfg"]);
+
+    assert_eq!(m.values_of("files").unwrap().collect::<Vec<_>>(), ["a.txt", "b.txt"]);
+}
+
+#[test]
+fn config_set_true_flag() {
+    let path = config("set_true.yaml", "color: true\n");
+    let m = App::new("cfg")
+        .config_file(path)
+        .arg(Arg::with_name("color")
+            .long("color")
+            .action(ArgAction::SetTrue))
+        .get_matches_from(vec!["cfg"]);
+
+    assert_eq!(m.value_of("color"), Some("true"));
+}
+
+#[test]
+fn config_negatable_flag() {
+    let path = config("negatable.toml", "color = false\n");
+    let m = App::new("cfg")
+        .config_file(path)
+        .arg(Arg::with_name("color")
+            .long("color")
+            .negatable(true))
+        .get_matches_from(vec!["cfg"]);
+
+    assert!(!m.is_present("color"));
+    assert!(m.is_negated("color"));
+}
+
+#[test]
+fn config_required_arg() {
+    let path = config("required.toml", "cluster = \"prod\"\n");
+    let m = App::new("cfg")
+        .config_file(path)
+        .arg(Arg::with_name("cluster")
+            .long("cluster")
+            .takes_value(true)
+            .required(true))
+        .get_matches_from(vec!["cfg"]);
+
+    assert_eq!(m.value_of("cluster"), Some("prod"));
+}
@@ -0,0 +1,212 @@
+extern crate clap;
+extern crate regex;
+
+include!("../clap-test.rs");
+
+use clap::{App, Arg, ErrorKind};
+
+static DVIF_HELP: &'static str = "prog 1.0
+
+USAGE:
+    prog [FLAGS] [OPTIONS]
+
+FLAGS:
+    -h, --help       Prints help information
+        --machine    machine readable output
+    -V, --version    Prints version information
+
+OPTIONS:
+        --format <format>    output format [default: text]  [default: json if machine]";
+
+#[test]
+fn opt_default_if_present() {
+    let r = App::new("df")
+        .arg(Arg::from_usage("--opt [FILE] 'some arg'")
+            .default_value_if("flag", None, "default"))
+        .arg(Arg::from_usage("--flag 'some arg'"))
+        .get_matches_from_safe(vec!["", "--flag"]);
+    assert!(r.is_ok());
+    let m = r.unwrap();
+    assert!(m.is_present("opt"));
+    assert_eq!(m.occurrences_of("opt"), 0);
+    assert_eq!(m.value_of("opt").unwrap(), "default");
+}
+
+#[test]
+fn opt_default_if_present_no_default() {
+    let r = App::new("df")
+        .arg(Arg::from_usage("--opt [FILE] 'some arg'")
+            .default_value_if("flag", None, "default"))
+        .arg(Arg::from_usage("--flag 'some arg'"))
+        .get_matches_from_safe(vec![""]);
+    assert!(r.is_ok());
+    let m = r.unwrap();
+    assert!(!m.is_present("opt"));
+    assert_eq!(m.value_of("opt"), None);
+}
+
+#[test]
+fn opt_default_if_present_user_override() {
+    let r = App::new("df")
+        .arg(Arg::from_usage("--opt [FILE] 'some arg'")
+            .default_value_if("flag", None, "default"))
+        .arg(Arg::from_usage("--flag 'some arg'"))
+        .get_matches_from_safe(vec!["", "--flag", "--opt", "value"]);
+    assert!(r.is_ok());
+    let m = r.unwrap();
+    assert_eq!(m.occurrences_of("opt"), 1);
+    assert_eq!(m.value_of("opt").unwrap(), "value");
+}
+
+#[test]
+fn opt_default_if_value() {
+    let r = App::new("df")
+        .arg(Arg::from_usage("--opt [FILE] 'some arg'")
+            .default_value_if("output", Some("out.json"), "json"))
+        .arg(Arg::from_usage("--output [FILE] 'some arg'"))
+        .get_matches_from_safe(vec!["", "--output", "out.json"]);
+    assert!(r.is_ok());
+    let m = r.unwrap();
+    assert_eq!(m.value_of("opt").unwrap(), "json");
+}
+
+#[test]
+fn opt_default_if_value_no_match() {
+    let r = App::new("df")
+        .arg(Arg::from_usage("--opt [FILE] 'some arg'")
+            .default_value("text")
+            .default_value_if("output", Some("out.json"), "json"))
+        .arg(Arg::from_usage("--output [FILE] 'some arg'"))
+        .get_matches_from_safe(vec!["", "--output", "out.txt"]);
+    assert!(r.is_ok());
+    let m = r.unwrap();
+    assert_eq!(m.value_of("opt").unwrap(), "text");
+}
+
+#[test]
+fn pos_default_if_present() {
+    let r = App::new("df")
+        .arg(Arg::from_usage("[arg] 'some arg'")
+            .default_value_if("flag", None, "default"))
+        .arg(Arg::from_usage("--flag 'some arg'"))
+        .get_matches_from_safe(vec!["", "--flag"]);
+    assert!(r.is_ok());
+    let m = r.unwrap();
+    assert_eq!(m.occurrences_of("arg"), 0);
+    assert_eq!(m.value_of("arg").unwrap(), "default");
+}
+
+#[test]
+fn default_ifs_first_match_wins() {
+    let r = App::new("df")
+        .arg(Arg::from_usage("--opt [FILE] 'some arg'")
+            .default_value_ifs(&[("flag", None, "flag_default"),
+                                 ("output", Some("out.json"), "json")]))
+        .arg(Arg::from_usage("--flag 'some arg'"))
+        .arg(Arg::from_usage("--output [FILE] 'some arg'"))
+        .get_matches_from_safe(vec!["", "--output", "out.json", "--flag"]);
+    assert!(r.is_ok());
+    let m = r.unwrap();
+    assert_eq!(m.value_of("opt").unwrap(), "flag_default");
+}
+
+#[test]
+fn default_ifs_second_match() {
+    let r = App::new("df")
+        .arg(Arg::from_usage("--opt [FILE] 'some arg'")
+            .default_value_ifs(&[("flag", None, "flag_default"),
+                                 ("output", Some("out.json"), "json")]))
+        .arg(Arg::from_usage("--flag 'some arg'"))
+        .arg(Arg::from_usage("--output [FILE] 'some arg'"))
+        .get_matches_from_safe(vec!["", "--output", "out.json"]);
+    assert!(r.is_ok());
+    let m = r.unwrap();
+    assert_eq!(m.value_of("opt").unwrap(), "json");
+}
+
+#[test]
+fn default_if_uses_env_value() {
+    ::std::env::set_var("CLP_TEST_DVIF_ENV", "out.json");
+
+    let r = App::new("df")
+        .arg(Arg::from_usage("--opt [FILE] 'some arg'")
+            .default_value_if("output", Some("out.json"), "json"))
+        .arg(Arg::from_usage("--output [FILE] 'some arg'").env("CLP_TEST_DVIF_ENV"))
+        .get_matches_from_safe(vec![""]);
+    assert!(r.is_ok());
+    let m = r.unwrap();
+    assert_eq!(m.value_of("opt").unwrap(), "json");
+}
+
+#[test]
+fn default_if_invalid_value() {
+    let r = App::new("df")
+        .arg(Arg::from_usage("--opt [FILE] 'some arg'")
+            .possible_values(&["json", "text"])
+            .default_value_if("flag", None, "yaml"))
+        .arg(Arg::from_usage("--flag 'some arg'"))
+        .get_matches_from_safe(vec!["", "--flag"]);
+    assert!(r.is_err());
+    assert_eq!(r.unwrap_err().kind, ErrorKind::InvalidValue);
+}
+
+#[test]
+fn default_if_fn_arg_present_matching_val() {
+    let r = App::new("df")
+        .arg(Arg::from_usage("--output [FILE] 'some arg'"))
+        .arg(Arg::from_usage("--format [FMT] 'some arg'")
+            .default_value("text")
+            .default_value_if_fn("output", |v| v.to_string_lossy().ends_with(".json"), "json"))
+        .get_matches_from_safe(vec!["", "--output", "out.json"]);
+    assert!(r.is_ok());
+    let m = r.unwrap();
+    assert_eq!(m.value_of("format").unwrap(), "json");
+}
+
+#[test]
+fn default_if_fn_arg_present_other_val() {
+    let r = App::new("df")
+        .arg(Arg::from_usage("--output [FILE] 'some arg'"))
+        .arg(Arg::from_usage("--format [FMT] 'some arg'")
+            .default_value("text")
+            .default_value_if_fn("output", |v| v.to_string_lossy().ends_with(".json"), "json"))
+        .get_matches_from_safe(vec!["", "--output", "out.txt"]);
+    assert!(r.is_ok());
+    let m = r.unwrap();
+    assert_eq!(m.value_of("format").unwrap(), "text");
+}
+
+#[test]
+fn default_if_fn_order_with_default_if() {
+    let r = App::new("df")
+        .arg(Arg::from_usage("--output [FILE] 'some arg'"))
+        .arg(Arg::from_usage("--format [FMT] 'some arg'")
+            .default_value_if("output", Some("out.json"), "exact")
+            .default_value_if_fn("output", |v| v.to_string_lossy().ends_with(".json"), "json"))
+        .get_matches_from_safe(vec!["", "--output", "out.json"]);
+    assert!(r.is_ok());
+    let m = r.unwrap();
+    assert_eq!(m.value_of("format").unwrap(), "exact");
+
+    let r = App::new("df")
+        .arg(Arg::from_usage("--output [FILE] 'some arg'"))
+        .arg(Arg::from_usage("--format [FMT] 'some arg'")
+            .default_value_if_fn("output", |v| v.to_string_lossy().ends_with(".json"), "json")
+            .default_value_if("output", Some("out.json"), "exact"))
+        .get_matches_from_safe(vec!["", "--output", "out.json"]);
+    assert!(r.is_ok());
+    let m = r.unwrap();
+    assert_eq!(m.value_of("format").unwrap(), "json");
+}
+
+#[test]
+fn default_if_help() {
+    let app = App::new("prog")
+        .version("1.0")
+        .arg(Arg::from_usage("--format [format] 'output format'")
+            .default_value("text")
+            .default_value_if("machine", None, "json"))
+        .arg(Arg::from_usage("--machine 'machine readable output'"));
+
+    test::check_help(app, DVIF_HELP);
+}
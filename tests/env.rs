@@ -0,0 +1,313 @@
+#[macro_use]
+extern crate clap;
+extern crate regex;
+
+use std::env;
+use std::ffi::OsStr;
+
+include!("../clap-test.rs");
+
+use clap::{App, Arg, ErrorKind};
+
+static ENV_HELP: &'static str = "prog 1.0
+
+USAGE:
+    prog [OPTIONS]
+
+FLAGS:
+    -h, --help       Prints help information
+    -V, --version    Prints version information
+
+OPTIONS:
+        --arg <arg>    some opt [env: CLP_TEST_ENV_HELP=env]";
+
+static HIDE_ENV_VALS_HELP: &'static str = "prog 1.0
+
+USAGE:
+    prog [OPTIONS]
+
+FLAGS:
+    -h, --help       Prints help information
+    -V, --version    Prints version information
+
+OPTIONS:
+        --token <token>    some opt [env: CLP_TEST_ENV_TOKEN]";
+
+#[test]
+fn env() {
+    env::set_var("CLP_TEST_ENV", "env");
+
+    let r = App::new("df")
+        .arg(Arg::from_usage("[arg] 'some opt'").env("CLP_TEST_ENV"))
+        .get_matches_from_safe(vec![""]);
+
+    assert!(r.is_ok());
+    let m = r.unwrap();
+    assert!(m.is_present("arg"));
+    assert_eq!(m.occurrences_of("arg"), 0);
+    assert_eq!(m.value_of("arg").unwrap(), "env");
+}
+
+#[test]
+fn env_os() {
+    env::set_var("CLP_TEST_ENV_OS", "env");
+
+    let r = App::new("df")
+        .arg(Arg::from_usage("[arg] 'some opt'").env_os(OsStr::new("CLP_TEST_ENV_OS")))
+        .get_matches_from_safe(vec![""]);
+
+    assert!(r.is_ok());
+    let m = r.unwrap();
+    assert!(m.is_present("arg"));
+    assert_eq!(m.occurrences_of("arg"), 0);
+    assert_eq!(m.value_of("arg").unwrap(), "env");
+}
+
+#[test]
+fn no_env() {
+    // All the other tests use the presence of the Environment variable...
+    // we need another variable just in case one of the others is running at the same time...
+    env::remove_var("CLP_TEST_ENV_NONE");
+
+    let r = App::new("df")
+        .arg(Arg::from_usage("[arg] 'some opt'").env("CLP_TEST_ENV_NONE"))
+        .get_matches_from_safe(vec![""]);
+
+    assert!(r.is_ok());
+    let m = r.unwrap();
+    assert!(!m.is_present("arg"));
+    assert_eq!(m.occurrences_of("arg"), 0);
+    assert_eq!(m.value_of("arg"), None);
+}
+
+#[test]
+fn with_default() {
+    env::set_var("CLP_TEST_ENV_WD", "env");
+
+    let r = App::new("df")
+        .arg(Arg::from_usage("[arg] 'some opt'")
+            .env("CLP_TEST_ENV_WD")
+            .default_value("default"))
+        .get_matches_from_safe(vec![""]);
+
+    assert!(r.is_ok());
+    let m = r.unwrap();
+    assert!(m.is_present("arg"));
+    assert_eq!(m.occurrences_of("arg"), 0);
+    assert_eq!(m.value_of("arg").unwrap(), "env");
+}
+
+#[test]
+fn opt_user_override() {
+    env::set_var("CLP_TEST_ENV_OR", "env");
+
+    let r = App::new("df")
+        .arg(Arg::from_usage("--arg [FILE] 'some arg'").env("CLP_TEST_ENV_OR"))
+        .get_matches_from_safe(vec!["", "--arg", "opt"]);
+
+    assert!(r.is_ok());
+    let m = r.unwrap();
+    assert!(m.is_present("arg"));
+    assert_eq!(m.occurrences_of("arg"), 1);
+    assert_eq!(m.value_of("arg").unwrap(), "opt");
+}
+
+#[test]
+fn positionals() {
+    env::set_var("CLP_TEST_ENV_P", "env");
+
+    let r = App::new("df")
+        .arg(Arg::from_usage("[arg] 'some opt'").env("CLP_TEST_ENV_P"))
+        .get_matches_from_safe(vec![""]);
+
+    assert!(r.is_ok());
+    let m = r.unwrap();
+    assert!(m.is_present("arg"));
+    assert_eq!(m.occurrences_of("arg"), 0);
+    assert_eq!(m.value_of("arg").unwrap(), "env");
+}
+
+#[test]
+fn positionals_user_override() {
+    env::set_var("CLP_TEST_ENV_POR", "env");
+
+    let r = App::new("df")
+        .arg(Arg::from_usage("[arg] 'some opt'").env("CLP_TEST_ENV_POR"))
+        .get_matches_from_safe(vec!["", "opt"]);
+
+    assert!(r.is_ok());
+    let m = r.unwrap();
+    assert!(m.is_present("arg"));
+    assert_eq!(m.occurrences_of("arg"), 1);
+    assert_eq!(m.value_of("arg").unwrap(), "opt");
+}
+
+#[test]
+fn multiple_one() {
+    env::set_var("CLP_TEST_ENV_MO", "env");
+
+    let r = App::new("df")
+        .arg(Arg::from_usage("[arg] 'some opt'")
+            .env("CLP_TEST_ENV_MO")
+            .use_delimiter(true)
+            .multiple(true))
+        .get_matches_from_safe(vec![""]);
+
+    assert!(r.is_ok());
+    let m = r.unwrap();
+    assert!(m.is_present("arg"));
+    assert_eq!(m.occurrences_of("arg"), 0);
+    assert_eq!(m.values_of("arg").unwrap().collect::<Vec<_>>(), vec!["env"]);
+}
+
+#[test]
+fn multiple_three() {
+    env::set_var("CLP_TEST_ENV_MULTI1", "env1,env2,env3");
+
+    let r = App::new("df")
+        .arg(Arg::from_usage("[arg] 'some opt'")
+            .env("CLP_TEST_ENV_MULTI1")
+            .use_delimiter(true)
+            .multiple(true))
+        .get_matches_from_safe(vec![""]);
+
+    assert!(r.is_ok());
+    let m = r.unwrap();
+    assert!(m.is_present("arg"));
+    assert_eq!(m.occurrences_of("arg"), 0);
+    assert_eq!(m.values_of("arg").unwrap().collect::<Vec<_>>(),
+               vec!["env1", "env2", "env3"]);
+}
+
+#[test]
+fn possible_value() {
+    env::set_var("CLP_TEST_ENV_PV", "env");
+
+    let r = App::new("df")
+        .arg(Arg::from_usage("[arg] 'some opt'")
+            .env("CLP_TEST_ENV_PV")
+            .possible_value("env"))
+        .get_matches_from_safe(vec![""]);
+
+    assert!(r.is_ok());
+    let m = r.unwrap();
+    assert!(m.is_present("arg"));
+    assert_eq!(m.occurrences_of("arg"), 0);
+    assert_eq!(m.value_of("arg").unwrap(), "env");
+}
+
+#[test]
+fn not_possible_value() {
+    env::set_var("CLP_TEST_ENV_NPV", "env");
+
+    let r = App::new("df")
+        .arg(Arg::from_usage("[arg] 'some opt'")
+            .env("CLP_TEST_ENV_NPV")
+            .possible_value("never"))
+        .get_matches_from_safe(vec![""]);
+
+    assert!(r.is_err());
+    assert_eq!(r.unwrap_err().kind, ErrorKind::InvalidValue);
+}
+
+#[test]
+fn validator() {
+    env::set_var("CLP_TEST_ENV_VDOR", "env");
+
+    let r = App::new("df")
+        .arg(Arg::from_usage("[arg] 'some opt'")
+            .env("CLP_TEST_ENV_VDOR")
+            .validator(|s| if s == "env" {
+                Ok(())
+            } else {
+                Err("not equal".to_string())
+            }))
+        .get_matches_from_safe(vec![""]);
+
+    assert!(r.is_ok());
+    let m = r.unwrap();
+    assert!(m.is_present("arg"));
+    assert_eq!(m.occurrences_of("arg"), 0);
+    assert_eq!(m.value_of("arg").unwrap(), "env");
+}
+
+#[test]
+fn validator_invalid() {
+    env::set_var("CLP_TEST_ENV_IV", "env");
+
+    let r = App::new("df")
+        .arg(Arg::from_usage("[arg] 'some opt'")
+            .env("CLP_TEST_ENV_IV")
+            .validator(|s| if s != "env" {
+                Ok(())
+            } else {
+                Err("is equal".to_string())
+            }))
+        .get_matches_from_safe(vec![""]);
+
+    assert!(r.is_err());
+    assert_eq!(r.unwrap_err().kind, ErrorKind::ValueValidation);
+}
+
+#[test]
+fn satisfies_required() {
+    env::set_var("CLP_TEST_ENV_REQ", "env");
+
+    let r = App::new("df")
+        .arg(Arg::from_usage("--arg <FILE> 'some arg'").env("CLP_TEST_ENV_REQ"))
+        .get_matches_from_safe(vec![""]);
+
+    assert!(r.is_ok());
+    let m = r.unwrap();
+    assert_eq!(m.value_of("arg").unwrap(), "env");
+}
+
+#[test]
+fn triggers_conflict() {
+    env::set_var("CLP_TEST_ENV_CONF", "env");
+
+    let r = App::new("df")
+        .arg(Arg::from_usage("--arg [FILE] 'some arg'")
+            .env("CLP_TEST_ENV_CONF")
+            .conflicts_with("flag"))
+        .arg(Arg::from_usage("-f, --flag 'some flag'"))
+        .get_matches_from_safe(vec!["", "--flag"]);
+
+    assert!(r.is_err());
+    assert_eq!(r.unwrap_err().kind, ErrorKind::ArgumentConflict);
+}
+
+#[test]
+fn clap_app_env() {
+    env::set_var("CLP_TEST_ENV_MACRO", "env");
+
+    let m = clap_app!(prog =>
+        (@arg arg: --arg +takes_value env("CLP_TEST_ENV_MACRO") "some arg")
+    ).get_matches_from(vec![""]);
+
+    assert_eq!(m.value_of("arg").unwrap(), "env");
+}
+
+#[test]
+fn help_shows_env() {
+    env::set_var("CLP_TEST_ENV_HELP", "env");
+
+    let app = App::new("prog")
+        .version("1.0")
+        .arg(Arg::from_usage("--arg [arg] 'some opt'").env("CLP_TEST_ENV_HELP"));
+
+    test::check_help(app, ENV_HELP);
+}
+
+#[test]
+fn help_hides_env_values() {
+    env::set_var("CLP_TEST_ENV_TOKEN", "s3cr3t");
+
+    let app = App::new("prog")
+        .version("1.0")
+        .arg(Arg::from_usage("--token [token] 'some opt'")
+            .env("CLP_TEST_ENV_TOKEN")
+            .hide_env_values(true));
+
+    test::check_help(app, HIDE_ENV_VALS_HELP);
+}
@@ -0,0 +1,181 @@
+extern crate clap;
+
+use clap::{App, AppSettings, Arg, ArgGroup, ContextKind, ContextValue, ErrorKind};
+#[cfg(feature = "suggestions")]
+use clap::SubCommand;
+
+fn s(v: &str) -> Option<ContextValue> {
+    Some(ContextValue::String(v.to_owned()))
+}
+
+fn strs(v: &[&str]) -> Option<ContextValue> {
+    Some(ContextValue::Strings(v.iter().map(|s| s.to_string()).collect()))
+}
+
+#[test]
+fn context_invalid_value() {
+    let err = App::new("ctx")
+        .arg(Arg::with_name("speed")
+            .long("speed")
+            .takes_value(true)
+            .possible_values(&["fast", "slow"]))
+        .get_matches_from_safe(vec!["ctx", "--speed", "slo"])
+        .unwrap_err();
+
+    assert_eq!(err.kind, ErrorKind::InvalidValue);
+    assert_eq!(err.get(ContextKind::InvalidArg).cloned(), s("--speed <speed>"));
+    assert_eq!(err.get(ContextKind::InvalidValue).cloned(), s("slo"));
+    assert_eq!(err.get(ContextKind::ValidValue).cloned(), strs(&["fast", "slow"]));
+    if cfg!(feature = "suggestions") {
+        assert_eq!(err.get(ContextKind::SuggestedValue).cloned(), s("slow"));
+    }
+    assert_eq!(err.get(ContextKind::Usage).cloned(),
+               s("USAGE:\n    ctx --speed <speed>"));
+}
+
+#[test]
+fn context_unknown_argument() {
+    let err = App::new("ctx")
+        .arg(Arg::with_name("verbose").long("verbose"))
+        .get_matches_from_safe(vec!["ctx", "--verbos"])
+        .unwrap_err();
+
+    assert_eq!(err.kind, ErrorKind::UnknownArgument);
+    assert_eq!(err.get(ContextKind::InvalidArg).cloned(), s("--verbos"));
+    if cfg!(feature = "suggestions") {
+        assert_eq!(err.get(ContextKind::SuggestedArg).cloned(), s("--verbose"));
+    }
+}
+
+#[test]
+fn context_unknown_argument_no_suggestion() {
+    let err = App::new("ctx")
+        .get_matches_from_safe(vec!["ctx", "-x"])
+        .unwrap_err();
+
+    assert_eq!(err.kind, ErrorKind::UnknownArgument);
+    assert_eq!(err.get(ContextKind::InvalidArg).cloned(), s("-x"));
+    assert_eq!(err.get(ContextKind::SuggestedArg), None);
+}
+
+#[test]
+fn context_ambiguous_argument() {
+    let err = App::new("ctx")
+        .setting(AppSettings::InferLongArgs)
+        .arg(Arg::with_name("verbose").long("verbose"))
+        .arg(Arg::with_name("verbatim").long("verbatim"))
+        .get_matches_from_safe(vec!["ctx", "--verb"])
+        .unwrap_err();
+
+    assert_eq!(err.kind, ErrorKind::UnknownArgument);
+    assert_eq!(err.get(ContextKind::InvalidArg).cloned(), s("--verb"));
+    assert_eq!(err.get(ContextKind::ValidArg).cloned(),
+               strs(&["--verbatim", "--verbose"]));
+}
+
+#[test]
+fn context_argument_conflict() {
+    let err = App::new("ctx")
+        .arg(Arg::with_name("flag").long("flag").conflicts_with("color"))
+        .arg(Arg::with_name("color").long("color"))
+        .get_matches_from_safe(vec!["ctx", "--flag", "--color"])
+        .unwrap_err();
+
+    assert_eq!(err.kind, ErrorKind::ArgumentConflict);
+    assert_eq!(err.get(ContextKind::InvalidArg).cloned(), s("--color"));
+    assert_eq!(err.get(ContextKind::PriorArg).cloned(), s("--flag"));
+}
+
+#[test]
+fn context_wrong_number_of_values() {
+    let err = App::new("ctx")
+        .arg(Arg::with_name("opt")
+            .short("o")
+            .takes_value(true)
+            .number_of_values(2))
+        .get_matches_from_safe(vec!["ctx", "-o", "val"])
+        .unwrap_err();
+
+    assert_eq!(err.kind, ErrorKind::WrongNumberOfValues);
+    assert_eq!(err.get(ContextKind::InvalidArg).cloned(), s("-o <opt> <opt>"));
+    assert_eq!(err.get(ContextKind::ExpectedNumValues), Some(&ContextValue::Number(2)));
+    assert_eq!(err.get(ContextKind::ActualNumValues), Some(&ContextValue::Number(1)));
+}
+
+#[test]
+fn context_missing_required_argument() {
+    let err = App::new("ctx")
+        .arg(Arg::with_name("input").required(true))
+        .arg(Arg::with_name("config").long("config").takes_value(true).required(true))
+        .get_matches_from_safe(vec!["ctx"])
+        .unwrap_err();
+
+    assert_eq!(err.kind, ErrorKind::MissingRequiredArgument);
+    assert_eq!(err.get(ContextKind::InvalidArg).cloned(),
+               strs(&["<input>", "--config <config>"]));
+}
+
+#[test]
+fn context_missing_required_group() {
+    let err = App::new("ctx")
+        .args_from_usage("-a 'flag a'
+                          -b 'flag b'")
+        .group(ArgGroup::with_name("grp").args(&["a", "b"]).required(true))
+        .get_matches_from_safe(vec!["ctx"])
+        .unwrap_err();
+
+    assert_eq!(err.kind, ErrorKind::MissingRequiredArgument);
+    assert_eq!(err.get(ContextKind::InvalidArg).cloned(), strs(&["<-a|-b>"]));
+}
+
+#[test]
+fn context_value_validation() {
+    let err = App::new("ctx")
+        .arg(Arg::with_name("num")
+            .validator(|v| v.parse::<u32>().map(|_| ()).map_err(|_| "not a number".to_owned())))
+        .get_matches_from_safe(vec!["ctx", "abc"])
+        .unwrap_err();
+
+    assert_eq!(err.kind, ErrorKind::ValueValidation);
+    assert_eq!(err.get(ContextKind::InvalidArg).cloned(), s("<num>"));
+    assert_eq!(err.get(ContextKind::InvalidValue).cloned(), s("abc"));
+    assert_eq!(err.get(ContextKind::Custom).cloned(), s("not a number"));
+}
+
+#[cfg(feature = "suggestions")]
+#[test]
+fn context_invalid_subcommand() {
+    let err = App::new("ctx")
+        .subcommand(SubCommand::with_name("status"))
+        .get_matches_from_safe(vec!["ctx", "statsu"])
+        .unwrap_err();
+
+    assert_eq!(err.kind, ErrorKind::InvalidSubcommand);
+    assert_eq!(err.get(ContextKind::InvalidSubcommand).cloned(), s("statsu"));
+    assert_eq!(err.get(ContextKind::SuggestedSubcommand).cloned(), s("status"));
+}
+
+#[test]
+fn context_with_description() {
+    let err = clap::Error::with_description("something broke", ErrorKind::Io);
+    assert_eq!(err.context(),
+               &[(ContextKind::Custom, ContextValue::String("something broke".to_owned()))]);
+}
+
+#[test]
+fn context_value_display() {
+    assert_eq!(ContextValue::String("a".to_owned()).to_string(), "a");
+    assert_eq!(ContextValue::Strings(vec!["a".to_owned(), "b".to_owned()]).to_string(),
+               "a, b");
+    assert_eq!(ContextValue::Number(3).to_string(), "3");
+}
+
+#[test]
+fn context_empty_for_help() {
+    let err = App::new("ctx")
+        .get_matches_from_safe(vec!["ctx", "--help"])
+        .unwrap_err();
+
+    assert_eq!(err.kind, ErrorKind::HelpDisplayed);
+    assert!(err.context().is_empty());
+}
@@ -1,6 +1,8 @@
 extern crate clap;
 
-use clap::{App, Arg, ArgSettings};
+use std::rc::Rc;
+
+use clap::{App, Arg, ArgAction, ArgGroup, ArgSettings, Error, ErrorKind};
 
 #[test]
 fn flag_using_short() {
@@ -145,3 +147,181 @@ fn short_flag_name_missing() {
     assert!(a.num_vals.is_none());
 
 }
+
+#[test]
+fn negatable_flag() {
+    let m = App::new("flag")
+        .arg(Arg::from_usage("-c, --color 'some flag'").negatable(true))
+        .arg(Arg::from_usage("--cache 'some other flag'").negatable(true))
+        .arg(Arg::from_usage("--debug 'yet another flag'").negatable(true))
+        .get_matches_from(vec!["", "--no-color", "--cache", "--no-cache"]);
+    assert!(!m.is_present("color"));
+    assert!(m.is_negated("color"));
+    assert!(!m.is_present("cache"));
+    assert!(m.is_negated("cache"));
+    assert!(!m.is_present("debug"));
+    assert!(!m.is_negated("debug"));
+}
+
+#[test]
+fn negatable_flag_last_wins() {
+    let m = App::new("flag")
+        .arg(Arg::from_usage("-c, --color 'some flag'").negatable(true))
+        .get_matches_from(vec!["", "--no-color", "--color", "--no-color", "-c"]);
+    assert!(m.is_present("color"));
+    assert!(!m.is_negated("color"));
+    assert_eq!(m.occurrences_of("color"), 1);
+}
+
+#[test]
+fn negatable_flag_drops_requires() {
+    let m = App::new("flag")
+        .arg(Arg::from_usage("--color 'some flag'").negatable(true).requires("theme"))
+        .arg(Arg::from_usage("--theme [theme] 'some option'"))
+        .get_matches_from(vec!["", "--color", "--no-color"]);
+    assert!(!m.is_present("color"));
+    assert!(m.is_negated("color"));
+}
+
+#[test]
+fn negatable_flag_drops_conflicts() {
+    let m = App::new("flag")
+        .arg(Arg::from_usage("--color 'some flag'").negatable(true).conflicts_with("mono"))
+        .arg(Arg::from_usage("--mono 'some other flag'"))
+        .get_matches_from(vec!["", "--color", "--no-color", "--mono"]);
+    assert!(!m.is_present("color"));
+    assert!(m.is_present("mono"));
+}
+
+#[test]
+fn negatable_flag_drops_group() {
+    let app = App::new("flag")
+        .arg(Arg::from_usage("--color 'some flag'").negatable(true))
+        .arg(Arg::from_usage("--mono 'some other flag'"))
+        .group(ArgGroup::with_name("grp")
+            .args(&["color", "mono"])
+            .required(true));
+
+    let res = app.clone().get_matches_from_safe(vec!["", "--color", "--no-color"]);
+    assert!(res.is_err());
+    assert_eq!(res.unwrap_err().kind, ErrorKind::MissingRequiredArgument);
+
+    let m = app.get_matches_from(vec!["", "--color", "--no-color", "--mono"]);
+    assert!(m.is_present("grp"));
+    assert!(!m.is_present("color"));
+    assert_eq!(m.occurrences_of("grp"), 1);
+}
+
+#[test]
+fn negatable_flag_not_set() {
+    let res = App::new("flag")
+        .arg(Arg::from_usage("--color 'some flag'"))
+        .get_matches_from_safe(vec!["", "--no-color"]);
+    assert!(res.is_err());
+    assert_eq!(res.unwrap_err().kind, ErrorKind::UnknownArgument);
+}
+
+#[test]
+#[should_panic]
+fn negatable_flag_no_long() {
+    App::new("flag")
+        .arg(Arg::from_usage("-c 'some flag'").negatable(true))
+        .get_matches_from(vec![""]);
+}
+
+#[test]
+fn action_count() {
+    let m = App::new("flag")
+        .arg(Arg::from_usage("-v, --verbose 'some flag'").action(ArgAction::Count))
+        .get_matches_from(vec!["", "-vv", "--verbose"]);
+    assert_eq!(m.occurrences_of("verbose"), 3);
+}
+
+#[test]
+fn action_set_true() {
+    let app = App::new("flag")
+        .arg(Arg::from_usage("--color 'some flag'").action(ArgAction::SetTrue));
+    let m = app.clone().get_matches_from(vec!["", "--color", "--color"]);
+    assert_eq!(m.value_of("color"), Some("true"));
+    assert_eq!(m.occurrences_of("color"), 2);
+    assert!(m.is_present("color"));
+
+    let m = app.get_matches_from(vec![""]);
+    assert_eq!(m.value_of("color"), Some("false"));
+    assert_eq!(m.occurrences_of("color"), 0);
+    assert!(!m.is_present("color"));
+}
+
+#[test]
+fn action_set_false() {
+    let app = App::new("flag")
+        .arg(Arg::with_name("cache").long("no-cache").action(ArgAction::SetFalse));
+    let m = app.clone().get_matches_from(vec!["", "--no-cache"]);
+    assert_eq!(m.value_of("cache"), Some("false"));
+    assert!(m.is_present("cache"));
+
+    let m = app.get_matches_from(vec![""]);
+    assert_eq!(m.value_of("cache"), Some("true"));
+    assert!(!m.is_present("cache"));
+}
+
+#[test]
+fn action_set_false_unused_doesnt_conflict() {
+    let r = App::new("flag")
+        .arg(Arg::with_name("cache")
+            .long("no-cache")
+            .action(ArgAction::SetFalse)
+            .conflicts_with("offline"))
+        .arg(Arg::from_usage("--offline 'some flag'"))
+        .get_matches_from_safe(vec!["", "--offline"]);
+    assert!(r.is_ok());
+    assert_eq!(r.unwrap().value_of("cache"), Some("true"));
+}
+
+#[test]
+fn action_help() {
+    let app = App::new("flag")
+        .arg(Arg::with_name("usage").short("u").long("usage").action(ArgAction::Help));
+    let r = app.clone().get_matches_from_safe(vec!["", "--usage"]);
+    assert!(r.is_err());
+    assert_eq!(r.unwrap_err().kind, ErrorKind::HelpDisplayed);
+
+    let r = app.get_matches_from_safe(vec!["", "-u"]);
+    assert!(r.is_err());
+    assert_eq!(r.unwrap_err().kind, ErrorKind::HelpDisplayed);
+}
+
+#[test]
+fn action_version() {
+    let r = App::new("flag")
+        .version("1.0")
+        .arg(Arg::with_name("ver").long("show-version").action(ArgAction::Version))
+        .get_matches_from_safe(vec!["", "--show-version"]);
+    assert!(r.is_err());
+    assert_eq!(r.unwrap_err().kind, ErrorKind::VersionDisplayed);
+}
+
+#[test]
+fn action_custom() {
+    let r = App::new("flag")
+        .arg(Arg::with_name("plugins")
+            .long("list-plugins")
+            .action(ArgAction::Custom(Rc::new(|val| {
+                assert_eq!(val, None);
+                Err(Error::raw(ErrorKind::HelpDisplayed, "git\nhg"))
+            }))))
+        .get_matches_from_safe(vec!["", "--list-plugins"]);
+    assert!(r.is_err());
+    let err = r.unwrap_err();
+    assert_eq!(err.kind, ErrorKind::HelpDisplayed);
+    assert_eq!(err.message, "git\nhg");
+}
+
+#[test]
+fn no_action_multiple_usage_fails() {
+    let r = App::new("flag")
+        .arg(Arg::from_usage("--color 'some flag'"))
+        .get_matches_from_safe(vec!["", "--color", "--color"]);
+    assert!(r.is_err());
+    assert_eq!(r.unwrap_err().kind, ErrorKind::UnexpectedMultipleUsage);
+}
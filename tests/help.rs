@@ -3,6 +3,8 @@ extern crate regex;
 
 include!("../clap-test.rs");
 
+use std::str;
+
 use clap::{App, AppSettings, SubCommand, ErrorKind, Arg};
 
 static HELP: &'static str = "clap-test v1.4.8
@@ -453,4 +455,207 @@ fn issue_702_multiple_values() {
              .multiple(true)
              .takes_value(true));
     test::check_err_output(app, "myapp --help", ISSUE_702, false);
-}
\ No newline at end of file
+}
+
+static LONG_ABOUT: &'static str = "myapp 1.0
+foo
+something really really long, with
+multiple lines of text
+that should be displayed
+
+USAGE:
+    myapp [FLAGS] [arg1]
+
+FLAGS:
+    -f, --flag       some flag with a much longer explanation
+    -h, --help       Prints help information
+    -V, --version    Prints version information
+
+ARGS:
+    <arg1>    some option";
+
+static SHORT_ABOUT: &'static str = "myapp 1.0
+foo
+bar
+
+USAGE:
+    myapp [FLAGS] [arg1]
+
+FLAGS:
+    -f, --flag       some flag
+    -h, --help       Prints help information
+    -V, --version    Prints version information
+
+ARGS:
+    <arg1>    some option";
+
+static LONG_HELP_SUBCMD: &'static str = "myapp-test 
+detailed subcommand about
+
+USAGE:
+    myapp test [FLAGS]
+
+FLAGS:
+    -d, --debug      turns on debugging mode with the details
+    -h, --help       Prints help information
+    -V, --version    Prints version information";
+
+static LONG_HELP_TEMPLATE: &'static str = "myapp
+something really really long, with
+multiple lines of text
+that should be displayed
+    -f, --flag       some flag with a much longer explanation
+    -h, --help       Prints help information
+    -V, --version    Prints version information";
+
+fn long_help_app() -> App<'static, 'static> {
+    App::new("myapp")
+        .version("1.0")
+        .author("foo")
+        .about("bar")
+        .long_about("something really really long, with\nmultiple lines of text\nthat should be displayed")
+        .arg(Arg::with_name("arg1")
+             .long_help("some option"))
+        .arg(Arg::with_name("flag")
+             .short("f")
+             .long("flag")
+             .help("some flag")
+             .long_help("some flag with a much longer explanation"))
+}
+
+#[test]
+fn long_about_and_long_help() {
+    test::check_err_output(long_help_app(), "myapp --help", LONG_ABOUT, false);
+}
+
+#[test]
+fn short_help_uses_about_and_help() {
+    test::check_err_output(long_help_app(), "myapp -h", SHORT_ABOUT, false);
+}
+
+#[test]
+fn write_help_is_short() {
+    test::check_help(long_help_app(), SHORT_ABOUT);
+}
+
+#[test]
+fn write_long_help() {
+    let mut app = long_help_app();
+    let _ = app.get_matches_from_safe_borrow(vec![""]);
+    let mut help = vec![];
+    app.write_long_help(&mut help).expect("failed to print help");
+    assert_eq!(str::from_utf8(&help).unwrap(), LONG_ABOUT);
+}
+
+#[test]
+fn help_subcommand_uses_long_help() {
+    let app = App::new("myapp")
+        .subcommand(SubCommand::with_name("test")
+            .about("short subcommand about")
+            .long_about("detailed subcommand about")
+            .arg(Arg::with_name("debug")
+                .short("d")
+                .long("debug")
+                .help("turns on debugging mode")
+                .long_help("turns on debugging mode with the details")));
+    test::check_err_output(app, "myapp help test", LONG_HELP_SUBCMD, false);
+}
+
+#[test]
+fn long_help_in_template() {
+    let app = long_help_app().template("{bin}\n{about}\n{flags}");
+    test::check_err_output(app, "myapp --help", LONG_HELP_TEMPLATE, false);
+}
+
+static VALUE_TERMINATOR: &'static str = "find 
+Searches for files
+
+USAGE:
+    find [OPTIONS] [paths]... +
+
+FLAGS:
+    -h, --help       Prints help information
+    -V, --version    Prints version information
+
+OPTIONS:
+        --exec <cmd>... ;    Runs a command on each file
+
+ARGS:
+    <paths>... +    Where to search";
+
+#[test]
+fn value_terminator_in_help() {
+    let app = App::new("find")
+        .about("Searches for files")
+        .arg(Arg::with_name("exec")
+            .long("exec")
+            .value_name("cmd")
+            .help("Runs a command on each file")
+            .value_terminator(";"))
+        .arg(Arg::with_name("paths")
+            .help("Where to search")
+            .value_terminator("+"));
+    test::check_help(app, VALUE_TERMINATOR);
+}
+
+static NEGATABLE_FLAGS: &'static str = "prog 
+Builds things
+
+USAGE:
+    prog [FLAGS]
+
+FLAGS:
+        --[no-]cache    Caches the results
+    -c, --[no-]color    Colors the output
+    -h, --help          Prints help information
+    -V, --version       Prints version information";
+
+#[test]
+fn negatable_flags_in_help() {
+    let app = App::new("prog")
+        .about("Builds things")
+        .arg(Arg::with_name("color")
+            .short("c")
+            .long("color")
+            .help("Colors the output")
+            .negatable(true))
+        .arg(Arg::with_name("cache")
+            .long("cache")
+            .help("Caches the results")
+            .negatable(true));
+    test::check_help(app, NEGATABLE_FLAGS);
+}
+
+static REQUIRE_EQUALS: &'static str = "prog 
+Lists things
+
+USAGE:
+    prog [OPTIONS]
+
+FLAGS:
+    -h, --help       Prints help information
+    -V, --version    Prints version information
+
+OPTIONS:
+    -c, --color[=<WHEN>]    Colors the output [default: auto]
+        --config=<FILE>     Reads the config";
+
+#[test]
+fn require_equals_in_help() {
+    let app = App::new("prog")
+        .about("Lists things")
+        .arg(Arg::with_name("color")
+            .short("c")
+            .long("color")
+            .value_name("WHEN")
+            .help("Colors the output")
+            .default_value("auto")
+            .default_missing_value("always")
+            .require_equals(true))
+        .arg(Arg::with_name("config")
+            .long("config")
+            .value_name("FILE")
+            .help("Reads the config")
+            .require_equals(true));
+    test::check_help(app, REQUIRE_EQUALS);
+}
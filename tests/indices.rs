@@ -0,0 +1,232 @@
+extern crate clap;
+
+use clap::{App, Arg, SubCommand, AppSettings};
+
+#[test]
+fn indices_mult_opts() {
+    let m = App::new("ind")
+        .arg(Arg::with_name("exclude")
+            .short("e")
+            .takes_value(true)
+            .multiple(true))
+        .arg(Arg::with_name("include")
+            .short("i")
+            .takes_value(true)
+            .multiple(true))
+        .get_matches_from(vec!["ind", "-e", "A", "B", "-i", "B", "C", "-e", "C"]);
+
+    assert_eq!(m.indices_of("exclude").unwrap().collect::<Vec<_>>(), &[2, 3, 8]);
+    assert_eq!(m.indices_of("include").unwrap().collect::<Vec<_>>(), &[5, 6]);
+}
+
+#[test]
+fn index_mult_opts() {
+    let m = App::new("ind")
+        .arg(Arg::with_name("exclude")
+            .short("e")
+            .takes_value(true)
+            .multiple(true))
+        .arg(Arg::with_name("include")
+            .short("i")
+            .takes_value(true)
+            .multiple(true))
+        .get_matches_from(vec!["ind", "-e", "A", "B", "-i", "B", "C", "-e", "C"]);
+
+    assert_eq!(m.index_of("exclude"), Some(2));
+    assert_eq!(m.index_of("include"), Some(5));
+}
+
+#[test]
+fn index_flag() {
+    let m = App::new("ind")
+        .arg(Arg::from_usage("-t"))
+        .get_matches_from(vec!["ind", "-t"]);
+
+    assert_eq!(m.index_of("t"), Some(1));
+    assert_eq!(m.indices_of("t").unwrap().collect::<Vec<_>>(), &[1]);
+}
+
+#[test]
+fn index_flags() {
+    let m = App::new("ind")
+        .arg(Arg::from_usage("-t").multiple(true))
+        .get_matches_from(vec!["ind", "-t", "-t"]);
+
+    assert_eq!(m.index_of("t"), Some(1));
+    assert_eq!(m.indices_of("t").unwrap().collect::<Vec<_>>(), &[1, 2]);
+}
+
+#[test]
+fn indices_mult_flags() {
+    let m = App::new("ind")
+        .arg(Arg::from_usage("-e").multiple(true))
+        .arg(Arg::from_usage("-i").multiple(true))
+        .get_matches_from(vec!["ind", "-e", "-i", "-e", "-e", "-i"]);
+
+    assert_eq!(m.indices_of("e").unwrap().collect::<Vec<_>>(), &[1, 3, 4]);
+    assert_eq!(m.indices_of("i").unwrap().collect::<Vec<_>>(), &[2, 5]);
+}
+
+#[test]
+fn indices_mult_flags_combined() {
+    let m = App::new("ind")
+        .arg(Arg::from_usage("-e").multiple(true))
+        .arg(Arg::from_usage("-i").multiple(true))
+        .get_matches_from(vec!["ind", "-eieei"]);
+
+    assert_eq!(m.indices_of("e").unwrap().collect::<Vec<_>>(), &[1, 3, 4]);
+    assert_eq!(m.indices_of("i").unwrap().collect::<Vec<_>>(), &[2, 5]);
+}
+
+#[test]
+fn indices_mult_flags_opt_combined() {
+    let m = App::new("ind")
+        .arg(Arg::from_usage("-e").multiple(true))
+        .arg(Arg::from_usage("-i").multiple(true))
+        .arg(Arg::from_usage("-o [option] 'some option'"))
+        .get_matches_from(vec!["ind", "-eieeio", "val"]);
+
+    assert_eq!(m.indices_of("e").unwrap().collect::<Vec<_>>(), &[1, 3, 4]);
+    assert_eq!(m.indices_of("i").unwrap().collect::<Vec<_>>(), &[2, 5]);
+    assert_eq!(m.indices_of("o").unwrap().collect::<Vec<_>>(), &[7]);
+}
+
+#[test]
+fn indices_mult_flags_opt_combined_eq() {
+    let m = App::new("ind")
+        .arg(Arg::from_usage("-e").multiple(true))
+        .arg(Arg::from_usage("-i").multiple(true))
+        .arg(Arg::from_usage("-o [option] 'some option'"))
+        .get_matches_from(vec!["ind", "-eieeio=val"]);
+
+    assert_eq!(m.indices_of("e").unwrap().collect::<Vec<_>>(), &[1, 3, 4]);
+    assert_eq!(m.indices_of("i").unwrap().collect::<Vec<_>>(), &[2, 5]);
+    assert_eq!(m.indices_of("o").unwrap().collect::<Vec<_>>(), &[7]);
+}
+
+#[test]
+fn indices_mult_opt_value_delim_eq() {
+    let m = App::new("myapp")
+        .arg(Arg::with_name("option")
+            .short("o")
+            .takes_value(true)
+            .use_delimiter(true)
+            .multiple(true))
+        .get_matches_from(vec!["myapp", "-o=val1,val2,val3"]);
+    assert_eq!(m.indices_of("option").unwrap().collect::<Vec<_>>(), &[2, 3, 4]);
+}
+
+#[test]
+fn indices_mult_opt_value_delim() {
+    let m = App::new("myapp")
+        .arg(Arg::with_name("option")
+            .long("option")
+            .takes_value(true)
+            .use_delimiter(true)
+            .multiple(true))
+        .arg(Arg::with_name("flag")
+            .short("f"))
+        .get_matches_from(vec!["myapp", "--option", "val1,val2", "-f"]);
+    assert_eq!(m.indices_of("option").unwrap().collect::<Vec<_>>(), &[2, 3]);
+    assert_eq!(m.index_of("flag"), Some(4));
+}
+
+#[test]
+fn indices_mult_opt_mult_flag() {
+    let m = App::new("myapp")
+        .arg(Arg::with_name("option")
+            .short("o")
+            .takes_value(true)
+            .multiple(true))
+        .arg(Arg::with_name("flag")
+            .short("f")
+            .multiple(true))
+        .get_matches_from(vec!["myapp", "-o", "val1", "-f", "-o", "val2", "-f"]);
+
+    assert_eq!(m.indices_of("option").unwrap().collect::<Vec<_>>(), &[2, 5]);
+    assert_eq!(m.indices_of("flag").unwrap().collect::<Vec<_>>(), &[3, 6]);
+}
+
+#[test]
+fn indices_positionals() {
+    let m = App::new("myapp")
+        .arg(Arg::with_name("pos")
+            .multiple(true))
+        .arg(Arg::with_name("flag")
+            .long("flag"))
+        .get_matches_from(vec!["myapp", "a", "--flag", "b", "c"]);
+
+    assert_eq!(m.indices_of("pos").unwrap().collect::<Vec<_>>(), &[1, 3, 4]);
+    assert_eq!(m.index_of("flag"), Some(2));
+}
+
+#[test]
+fn indices_group() {
+    let m = App::new("myapp")
+        .args_from_usage("-a 'flag a'
+                          -b [val] 'option b'")
+        .group(clap::ArgGroup::with_name("grp").args(&["a", "b"]).multiple(true))
+        .get_matches_from(vec!["myapp", "-b", "x", "-a"]);
+
+    assert_eq!(m.indices_of("grp").unwrap().collect::<Vec<_>>(), &[2, 3]);
+}
+
+#[test]
+fn indices_subcommand_continue_from_parent() {
+    let m = App::new("myapp")
+        .arg(Arg::with_name("flag").short("f"))
+        .subcommand(SubCommand::with_name("sub")
+            .arg(Arg::with_name("opt").short("o").takes_value(true)))
+        .get_matches_from(vec!["myapp", "-f", "sub", "-o", "val"]);
+
+    assert_eq!(m.index_of("flag"), Some(1));
+    let sub_m = m.subcommand_matches("sub").unwrap();
+    assert_eq!(sub_m.index_of("opt"), Some(4));
+}
+
+#[test]
+fn indices_external_subcommand() {
+    let m = App::new("myapp")
+        .setting(AppSettings::AllowExternalSubcommands)
+        .arg(Arg::with_name("flag").short("f"))
+        .get_matches_from(vec!["myapp", "-f", "ext", "a", "b"]);
+
+    match m.subcommand() {
+        ("ext", Some(sub_m)) => {
+            assert_eq!(sub_m.indices_of("").unwrap().collect::<Vec<_>>(), &[3, 4]);
+        }
+        _ => panic!("external subcommand wasn't matched"),
+    }
+}
+
+#[test]
+fn indices_not_recorded_for_defaults() {
+    let m = App::new("myapp")
+        .arg(Arg::with_name("opt")
+            .long("opt")
+            .takes_value(true)
+            .default_value("default"))
+        .get_matches_from(vec!["myapp"]);
+
+    assert_eq!(m.value_of("opt"), Some("default"));
+    assert_eq!(m.index_of("opt"), None);
+}
+
+#[test]
+fn index_not_present() {
+    let m = App::new("myapp")
+        .arg(Arg::with_name("flag").short("f"))
+        .get_matches_from(vec!["myapp"]);
+
+    assert_eq!(m.index_of("flag"), None);
+    assert!(m.indices_of("flag").is_none());
+}
+
+#[test]
+fn indices_reset_between_parses() {
+    let mut app = App::new("myapp").arg(Arg::with_name("flag").short("f"));
+    let m = app.get_matches_from_safe_borrow(vec!["myapp", "-f"]).unwrap();
+    assert_eq!(m.index_of("flag"), Some(1));
+    let m = app.get_matches_from_safe_borrow(vec!["myapp", "-f"]).unwrap();
+    assert_eq!(m.index_of("flag"), Some(1));
+}
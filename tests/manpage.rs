@@ -0,0 +1,177 @@
+extern crate clap;
+
+use clap::{App, AppSettings, Arg, SubCommand};
+
+fn manpage(mut app: App) -> String {
+    let mut buf = vec![];
+    app.gen_manpage_to("myapp", &mut buf);
+    String::from_utf8(buf).unwrap()
+}
+
+static BASIC: &'static str = r#".TH MYAPP 1 "" "myapp 1.0"
+.SH NAME
+myapp \- Tests man pages
+.SH SYNOPSIS
+.PP
+\fBmyapp\fR [OPTIONS] <file>
+.SH DESCRIPTION
+.PP
+Some text before the help
+.PP
+Tests man pages
+.SH OPTIONS
+.TP
+\fB\-c\fR, \fB\-\-case\fR \fI<case>\fR
+the case to test
+.br
+[default: upper]
+.br
+[possible values: upper, lower]
+.TP
+\fB\-h\fR, \fB\-\-help\fR
+Prints help information
+.TP
+\fB\-V\fR, \fB\-\-version\fR
+Prints version information
+.SH ARGS
+.TP
+\fI<file>\fR
+some input file
+.SH AUTHOR
+Kevin K.
+"#;
+
+static SUBCMDS: &'static str = r#".TH MYAPP 1 "" "myapp"
+.SH NAME
+myapp
+.SH SYNOPSIS
+.PP
+\fBmyapp\fR [SUBCOMMAND]
+.SH OPTIONS
+.TP
+\fB\-h\fR, \fB\-\-help\fR
+Prints help information
+.TP
+\fB\-V\fR, \fB\-\-version\fR
+Prints version information
+.SH SUBCOMMANDS
+.SS "myapp help"
+.PP
+\fBmyapp help\fR
+.PP
+Prints this message or the help of the given subcommand(s)
+.PP
+\fBOPTIONS:\fR
+.TP
+\fB\-h\fR, \fB\-\-help\fR
+Prints help information
+.TP
+\fB\-V\fR, \fB\-\-version\fR
+Prints version information
+.SS "myapp test"
+.PP
+\fBmyapp test\fR [OPTIONS]
+.PP
+tests things
+.PP
+\fBOPTIONS:\fR
+.TP
+\fB\-\-case\fR \fI<case>\fR
+the case to test
+.TP
+\fB\-h\fR, \fB\-\-help\fR
+Prints help information
+.TP
+\fB\-V\fR, \fB\-\-version\fR
+Prints version information
+"#;
+
+#[test]
+fn basic() {
+    let app = App::new("myapp")
+        .version("1.0")
+        .author("Kevin K.")
+        .about("Tests man pages")
+        .before_help("Some text before the help")
+        .arg(Arg::with_name("file")
+            .help("some input file")
+            .required(true))
+        .arg(Arg::with_name("case")
+            .short("c")
+            .long("case")
+            .takes_value(true)
+            .possible_values(&["upper", "lower"])
+            .default_value("upper")
+            .help("the case to test"));
+
+    assert_eq!(manpage(app), BASIC);
+}
+
+#[test]
+fn subcommands() {
+    let app = App::new("myapp")
+        .subcommand(SubCommand::with_name("test")
+            .about("tests things")
+            .arg(Arg::with_name("case")
+                .long("case")
+                .takes_value(true)
+                .help("the case to test")))
+        .subcommand(SubCommand::with_name("secret")
+            .setting(AppSettings::Hidden));
+
+    assert_eq!(manpage(app), SUBCMDS);
+}
+
+#[test]
+fn hidden_args() {
+    let page = manpage(App::new("myapp")
+        .arg(Arg::with_name("visible")
+            .long("visible")
+            .help("shown"))
+        .arg(Arg::with_name("secret")
+            .long("secret")
+            .hidden(true)
+            .help("not shown")));
+
+    assert!(page.contains("\\fB\\-\\-visible\\fR\nshown"));
+    assert!(!page.contains("secret"));
+}
+
+#[test]
+fn display_order() {
+    let page = manpage(App::new("myapp")
+        .arg(Arg::with_name("aaa")
+            .long("aaa")
+            .display_order(2))
+        .arg(Arg::with_name("zzz")
+            .long("zzz")
+            .display_order(1)));
+
+    let zzz = page.find("\\-\\-zzz").unwrap();
+    let aaa = page.find("\\-\\-aaa").unwrap();
+    assert!(zzz < aaa);
+}
+
+#[test]
+fn escapes_roff() {
+    let page = manpage(App::new("myapp")
+        .about(".starts with a dot and has a \\ backslash"));
+
+    assert!(page.contains("myapp \\- \\&.starts with a dot and has a \\e backslash\n"));
+}
+
+#[test]
+fn prefers_long_help() {
+    let page = manpage(App::new("myapp")
+        .about("Short about")
+        .long_about("Long about")
+        .arg(Arg::with_name("flag")
+            .long("flag")
+            .help("short help")
+            .long_help("long help")));
+
+    assert!(page.contains(".SH NAME\nmyapp \\- Short about\n"));
+    assert!(page.contains(".SH DESCRIPTION\n.PP\nLong about\n"));
+    assert!(page.contains("\\fB\\-\\-flag\\fR\nlong help\n"));
+    assert!(!page.contains("short help"));
+}
@@ -1105,3 +1105,117 @@ fn low_index_positional_with_flag() {
     assert_eq!(m.value_of("target").unwrap(), "target");
     assert!(m.is_present("flg"));
 }
+
+#[test]
+fn option_value_terminator() {
+    let m = App::new("find")
+        .arg(Arg::with_name("exec")
+            .long("exec")
+            .value_terminator(";"))
+        .arg(Arg::with_name("path"))
+        .get_matches_from_safe(vec!["find", "--exec", "rm", "-rf", "{}", ";", "some/dir"]);
+
+    assert!(m.is_ok());
+    let m = m.unwrap();
+    assert_eq!(m.values_of("exec").unwrap().collect::<Vec<_>>(), ["rm", "-rf", "{}"]);
+    assert_eq!(m.occurrences_of("exec"), 1);
+    assert_eq!(m.value_of("path"), Some("some/dir"));
+}
+
+#[test]
+fn option_value_terminator_swallows_args_and_subcommands() {
+    let m = App::new("find")
+        .arg(Arg::with_name("exec")
+            .short("e")
+            .value_terminator(";"))
+        .arg(Arg::with_name("verbose")
+            .short("v"))
+        .subcommand(SubCommand::with_name("sub"))
+        .get_matches_from_safe(vec!["find", "-e", "cmd", "-v", "--", "sub", ";", "-v"]);
+
+    assert!(m.is_ok());
+    let m = m.unwrap();
+    assert_eq!(m.values_of("exec").unwrap().collect::<Vec<_>>(),
+               ["cmd", "-v", "--", "sub"]);
+    assert_eq!(m.occurrences_of("verbose"), 1);
+    assert!(m.subcommand_name().is_none());
+}
+
+#[test]
+fn option_value_terminator_attached_value() {
+    let m = App::new("find")
+        .arg(Arg::with_name("exec")
+            .long("exec")
+            .value_terminator(";"))
+        .get_matches_from_safe(vec!["find", "--exec=echo", "hi", ";"]);
+
+    assert!(m.is_ok());
+    assert_eq!(m.unwrap().values_of("exec").unwrap().collect::<Vec<_>>(), ["echo", "hi"]);
+}
+
+#[test]
+fn option_value_terminator_multiple_occurrences() {
+    let m = App::new("find")
+        .arg(Arg::with_name("exec")
+            .long("exec")
+            .value_terminator(";"))
+        .get_matches_from_safe(vec!["find", "--exec", "a", ";", "--exec", "b", "c", ";"]);
+
+    assert!(m.is_ok());
+    let m = m.unwrap();
+    assert_eq!(m.values_of("exec").unwrap().collect::<Vec<_>>(), ["a", "b", "c"]);
+    assert_eq!(m.occurrences_of("exec"), 2);
+}
+
+#[test]
+fn option_value_terminator_missing() {
+    let m = App::new("find")
+        .arg(Arg::with_name("exec")
+            .long("exec")
+            .value_terminator(";"))
+        .get_matches_from_safe(vec!["find", "--exec", "echo", "-n"]);
+
+    assert!(m.is_ok());
+    assert_eq!(m.unwrap().values_of("exec").unwrap().collect::<Vec<_>>(), ["echo", "-n"]);
+}
+
+#[test]
+fn option_value_terminator_no_values() {
+    let m = App::new("find")
+        .arg(Arg::with_name("exec")
+            .long("exec")
+            .value_terminator(";"))
+        .get_matches_from_safe(vec!["find", "--exec"]);
+
+    assert!(m.is_err());
+    assert_eq!(m.unwrap_err().kind, ErrorKind::EmptyValue);
+}
+
+#[test]
+fn option_value_terminator_from_usage() {
+    let m = App::new("find")
+        .arg(Arg::from_usage("--exec <cmd>... ; 'run a command'"))
+        .arg(Arg::from_usage("[path] 'where to search'"))
+        .get_matches_from_safe(vec!["find", "--exec", "echo", "a,b", ";", "dir"]);
+
+    assert!(m.is_ok());
+    let m = m.unwrap();
+    assert_eq!(m.values_of("exec").unwrap().collect::<Vec<_>>(), ["echo", "a,b"]);
+    assert_eq!(m.value_of("path"), Some("dir"));
+}
+
+#[test]
+fn positional_value_terminator() {
+    let m = App::new("cp")
+        .arg(Arg::with_name("sources")
+            .required(true)
+            .value_terminator(";"))
+        .arg(Arg::with_name("dest")
+            .required(true))
+        .get_matches_from_safe(vec!["cp", "a", "b", ";", "dir"]);
+
+    assert!(m.is_ok());
+    let m = m.unwrap();
+    assert_eq!(m.values_of("sources").unwrap().collect::<Vec<_>>(), ["a", "b"]);
+    assert_eq!(m.value_of("dest"), Some("dir"));
+}
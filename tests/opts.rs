@@ -3,7 +3,11 @@ extern crate regex;
 
 include!("../clap-test.rs");
 
-use clap::{App, Arg, ErrorKind};
+use std::cell::RefCell;
+use std::ffi::OsString;
+use std::rc::Rc;
+
+use clap::{App, Arg, ArgAction, ErrorKind};
 
 #[test]
 fn stdin_char() {
@@ -237,3 +241,249 @@ USAGE:
 
 For more information try --help", true);
 }
+
+#[test]
+fn leading_hyphen_pass() {
+    let r = App::new("mvae")
+        .arg(Arg::from_usage("-o [opt]... 'some opt'").allow_hyphen_values(true))
+        .get_matches_from_safe(vec!["", "-o", "-2", "3"]);
+    assert!(r.is_ok());
+    let m = r.unwrap();
+    assert!(m.is_present("o"));
+    assert_eq!(m.values_of("o").unwrap().collect::<Vec<_>>(), &["-2", "3"]);
+}
+
+#[test]
+fn leading_hyphen_long() {
+    let r = App::new("mvae")
+        .arg(Arg::from_usage("--offset [offset] 'some offset'").allow_hyphen_values(true))
+        .arg(Arg::from_usage("--verbose 'some flag'"))
+        .get_matches_from_safe(vec!["", "--offset", "--verbose"]);
+    assert!(r.is_ok());
+    let m = r.unwrap();
+    assert_eq!(m.value_of("offset"), Some("--verbose"));
+    assert!(!m.is_present("verbose"));
+}
+
+#[test]
+fn leading_hyphen_fail() {
+    let r = App::new("mvae")
+        .arg(Arg::from_usage("-o [opt] 'some opt'"))
+        .get_matches_from_safe(vec!["", "-o", "-2"]);
+    assert!(r.is_err());
+    let m = r.unwrap_err();
+    assert_eq!(m.kind, ErrorKind::UnknownArgument);
+}
+
+#[test]
+fn leading_hyphen_other_args_fail() {
+    let r = App::new("mvae")
+        .arg(Arg::from_usage("--offset [offset] 'some offset'").allow_hyphen_values(true))
+        .arg(Arg::from_usage("--verbose 'some flag'"))
+        .get_matches_from_safe(vec!["", "--offset", "-5", "--verbos"]);
+    assert!(r.is_err());
+    let m = r.unwrap_err();
+    assert_eq!(m.kind, ErrorKind::UnknownArgument);
+}
+
+#[test]
+fn require_equals_pass() {
+    let r = App::new("prog")
+        .arg(Arg::with_name("cfg")
+            .long("config")
+            .require_equals(true))
+        .get_matches_from_safe(vec!["prog", "--config=file.conf"]);
+    assert!(r.is_ok());
+    assert_eq!(r.unwrap().value_of("cfg"), Some("file.conf"));
+}
+
+#[test]
+fn require_equals_fail() {
+    let r = App::new("prog")
+        .arg(Arg::with_name("cfg")
+            .long("config")
+            .require_equals(true))
+        .get_matches_from_safe(vec!["prog", "--config", "file.conf"]);
+    assert!(r.is_err());
+    assert_eq!(r.unwrap_err().kind, ErrorKind::EmptyValue);
+}
+
+#[test]
+fn require_equals_short() {
+    let app = App::new("prog")
+        .arg(Arg::with_name("cfg")
+            .short("c")
+            .require_equals(true));
+    let m = app.clone().get_matches_from_safe(vec!["prog", "-c=file.conf"]);
+    assert_eq!(m.unwrap().value_of("cfg"), Some("file.conf"));
+
+    let r = app.get_matches_from_safe(vec!["prog", "-cfile.conf"]);
+    assert!(r.is_err());
+    assert_eq!(r.unwrap_err().kind, ErrorKind::EmptyValue);
+}
+
+#[test]
+fn require_equals_empty_vals_pass() {
+    let r = App::new("prog")
+        .arg(Arg::with_name("cfg")
+            .long("config")
+            .require_equals(true)
+            .empty_values(true))
+        .get_matches_from_safe(vec!["prog", "--config="]);
+    assert!(r.is_ok());
+    assert_eq!(r.unwrap().value_of("cfg"), Some(""));
+}
+
+#[test]
+fn require_equals_empty_vals_fail() {
+    let r = App::new("prog")
+        .arg(Arg::with_name("cfg")
+            .long("config")
+            .require_equals(true)
+            .empty_values(false))
+        .get_matches_from_safe(vec!["prog", "--config="]);
+    assert!(r.is_err());
+    assert_eq!(r.unwrap_err().kind, ErrorKind::EmptyValue);
+}
+
+fn color_app() -> App<'static, 'static> {
+    App::new("prog")
+        .arg(Arg::with_name("color")
+            .long("color")
+            .value_name("WHEN")
+            .possible_values(&["always", "auto", "never"])
+            .default_value("auto")
+            .default_missing_value("always")
+            .require_equals(true))
+        .arg(Arg::with_name("file"))
+}
+
+#[test]
+fn default_missing_value_used() {
+    let r = color_app().get_matches_from_safe(vec!["prog", "--color"]);
+    assert!(r.is_ok());
+    let m = r.unwrap();
+    assert!(m.is_present("color"));
+    assert_eq!(m.value_of("color"), Some("always"));
+}
+
+#[test]
+fn default_missing_value_explicit() {
+    let r = color_app().get_matches_from_safe(vec!["prog", "--color=never"]);
+    assert!(r.is_ok());
+    assert_eq!(r.unwrap().value_of("color"), Some("never"));
+}
+
+#[test]
+fn default_missing_value_not_used() {
+    let r = color_app().get_matches_from_safe(vec!["prog"]);
+    assert!(r.is_ok());
+    assert_eq!(r.unwrap().value_of("color"), Some("auto"));
+}
+
+#[test]
+fn default_missing_value_doesnt_take_positional() {
+    let r = color_app().get_matches_from_safe(vec!["prog", "--color", "file.txt"]);
+    assert!(r.is_ok());
+    let m = r.unwrap();
+    assert_eq!(m.value_of("color"), Some("always"));
+    assert_eq!(m.value_of("file"), Some("file.txt"));
+}
+
+#[test]
+fn default_missing_value_invalid() {
+    let r = color_app().get_matches_from_safe(vec!["prog", "--color=sometimes"]);
+    assert!(r.is_err());
+    assert_eq!(r.unwrap_err().kind, ErrorKind::InvalidValue);
+}
+
+#[test]
+fn default_missing_value_without_require_equals() {
+    let r = App::new("prog")
+        .arg(Arg::with_name("color")
+            .long("color")
+            .default_missing_value("always"))
+        .get_matches_from_safe(vec!["prog", "--color"]);
+    assert!(r.is_ok());
+    assert_eq!(r.unwrap().value_of("color"), Some("always"));
+}
+
+#[test]
+fn optional_value_usage() {
+    let app = App::new("prog")
+        .arg(Arg::with_name("color")
+            .long("color")
+            .value_name("WHEN")
+            .default_missing_value("always")
+            .require_equals(true)
+            .empty_values(false));
+    test::check_err_output(app, "prog --color=",
+                           "error: The argument '--color[=<WHEN>]' requires a value but none was supplied
+
+USAGE:
+    prog [OPTIONS]
+
+For more information try --help", true);
+}
+
+#[test]
+fn action_set_last_wins() {
+    let r = App::new("prog")
+        .arg(Arg::with_name("level").long("level").action(ArgAction::Set))
+        .get_matches_from_safe(vec!["prog", "--level", "1", "--level=2"]);
+    assert!(r.is_ok());
+    let m = r.unwrap();
+    assert_eq!(m.values_of("level").unwrap().collect::<Vec<_>>(), ["2"]);
+    assert_eq!(m.occurrences_of("level"), 2);
+    assert_eq!(m.index_of("level"), Some(4));
+}
+
+#[test]
+fn action_append() {
+    let r = App::new("prog")
+        .arg(Arg::with_name("inc").short("I").action(ArgAction::Append))
+        .arg(Arg::with_name("file"))
+        .get_matches_from_safe(vec!["prog", "-I", "a", "-Ib", "file.c"]);
+    assert!(r.is_ok());
+    let m = r.unwrap();
+    assert_eq!(m.values_of("inc").unwrap().collect::<Vec<_>>(), ["a", "b"]);
+    assert_eq!(m.value_of("file"), Some("file.c"));
+}
+
+#[test]
+fn action_custom_gets_values() {
+    let seen = Rc::new(RefCell::new(vec![]));
+    let s = seen.clone();
+    let r = App::new("prog")
+        .arg(Arg::with_name("plugin")
+            .long("plugin")
+            .takes_value(true)
+            .default_value("none")
+            .action(ArgAction::Custom(Rc::new(move |val| {
+                s.borrow_mut().push(val.unwrap().to_owned());
+                Ok(())
+            }))))
+        .get_matches_from_safe(vec!["prog", "--plugin", "git", "--plugin=hg"]);
+    assert!(r.is_ok());
+    assert_eq!(r.unwrap().values_of("plugin").unwrap().collect::<Vec<_>>(), ["git", "hg"]);
+    assert_eq!(*seen.borrow(), [OsString::from("git"), OsString::from("hg")]);
+}
+
+#[test]
+fn action_custom_skips_invalid_values() {
+    let seen = Rc::new(RefCell::new(vec![]));
+    let s = seen.clone();
+    let r = App::new("prog")
+        .arg(Arg::with_name("plugin")
+            .long("plugin")
+            .takes_value(true)
+            .possible_values(&["git", "hg"])
+            .action(ArgAction::Custom(Rc::new(move |val| {
+                s.borrow_mut().push(val.unwrap().to_owned());
+                Ok(())
+            }))))
+        .get_matches_from_safe(vec!["prog", "--plugin", "git", "--plugin", "svn"]);
+    assert!(r.is_err());
+    assert_eq!(r.unwrap_err().kind, ErrorKind::InvalidValue);
+    assert_eq!(*seen.borrow(), [OsString::from("git")]);
+}
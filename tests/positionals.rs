@@ -214,3 +214,92 @@ fn single_positional_required_usage_string() {
         .get_matches_from(vec!["test", "file"]);
     assert_eq!(m.usage(), "USAGE:\n    test <FILE>");
 }
+
+#[test]
+fn last_positional() {
+    let r = App::new("test")
+        .arg_from_usage("<TARGET> 'some target'")
+        .arg_from_usage("[CORPUS] 'some corpus'")
+        .arg(Arg::from_usage("[ARGS]... 'some file'").last(true))
+        .get_matches_from_safe(vec!["test", "tgt", "--", "arg"]);
+    assert!(r.is_ok());
+    let m = r.unwrap();
+    assert_eq!(m.value_of("TARGET"), Some("tgt"));
+    assert!(!m.is_present("CORPUS"));
+    assert_eq!(m.values_of("ARGS").unwrap().collect::<Vec<_>>(), &["arg"]);
+}
+
+#[test]
+fn last_positional_no_double_dash() {
+    let r = App::new("test")
+        .arg_from_usage("<TARGET> 'some target'")
+        .arg_from_usage("[CORPUS] 'some corpus'")
+        .arg(Arg::from_usage("[ARGS]... 'some file'").last(true))
+        .get_matches_from_safe(vec!["test", "tgt", "crp", "arg"]);
+    assert!(r.is_err());
+    let err = r.unwrap_err();
+    assert_eq!(err.kind, ErrorKind::UnknownArgument);
+    assert_eq!(err.info, Some(vec!["arg".to_owned()]));
+}
+
+#[test]
+fn last_positional_second_to_last_mult() {
+    let r = App::new("test")
+        .arg_from_usage("<TARGET> 'some target'")
+        .arg_from_usage("[CORPUS]... 'some corpus'")
+        .arg(Arg::from_usage("[ARGS]... 'some file'").last(true))
+        .get_matches_from_safe(vec!["test", "tgt", "crp1", "crp2", "--", "-a", "arg"]);
+    assert!(r.is_ok());
+    let m = r.unwrap();
+    assert_eq!(m.values_of("CORPUS").unwrap().collect::<Vec<_>>(), &["crp1", "crp2"]);
+    assert_eq!(m.values_of("ARGS").unwrap().collect::<Vec<_>>(), &["-a", "arg"]);
+}
+
+#[test]
+fn last_positional_usage_string() {
+    let m = App::new("test")
+        .arg_from_usage("[FILE] 'some file'")
+        .arg(Arg::from_usage("[ARGS]... 'some arg'").last(true))
+        .get_matches_from(vec!["test"]);
+    assert_eq!(m.usage(), "USAGE:\n    test [FILE] [-- <ARGS>...]");
+}
+
+#[test]
+fn last_positional_required_usage_string() {
+    let m = App::new("test")
+        .arg_from_usage("[FILE] 'some file'")
+        .arg(Arg::from_usage("<ARGS>... 'some arg'").last(true))
+        .get_matches_from(vec!["test", "--", "arg"]);
+    assert_eq!(m.usage(), "USAGE:\n    test [FILE] -- <ARGS>...");
+}
+
+#[test]
+#[should_panic]
+fn last_positional_not_highest_index() {
+    App::new("test")
+        .arg(Arg::from_usage("[ARGS]... 'some arg'").last(true))
+        .arg_from_usage("[FILE] 'some file'")
+        .get_matches_from(vec!["test"]);
+}
+
+#[test]
+fn positional_hyphen_values() {
+    let r = App::new("test")
+        .arg(Arg::from_usage("-f, --flag 'some flag'"))
+        .arg(Arg::from_usage("[ARGS]... 'some args'").allow_hyphen_values(true))
+        .get_matches_from_safe(vec!["test", "-5", "--foo", "-f"]);
+    assert!(r.is_ok());
+    let m = r.unwrap();
+    assert!(m.is_present("flag"));
+    assert_eq!(m.values_of("ARGS").unwrap().collect::<Vec<_>>(), &["-5", "--foo"]);
+}
+
+#[test]
+fn positional_hyphen_values_other_pos_fail() {
+    let r = App::new("test")
+        .arg(Arg::from_usage("[FIRST] 'some arg'"))
+        .arg(Arg::from_usage("[ARGS]... 'some args'").allow_hyphen_values(true))
+        .get_matches_from_safe(vec!["test", "-5"]);
+    assert!(r.is_err());
+    assert_eq!(r.unwrap_err().kind, ErrorKind::UnknownArgument);
+}
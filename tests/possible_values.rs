@@ -161,3 +161,104 @@ fn possible_values_of_option_multiple_fail() {
 fn possible_values_output() {
     test::check_err_output(test::complex_app(), "clap-test -O slo", PV_ERROR, true);
 }
+
+#[test]
+fn case_insensitive() {
+    let m = App::new("pv")
+        .arg(Arg::with_name("option")
+            .short("o")
+            .long("--option")
+            .takes_value(true)
+            .possible_values(&["test123", "test321"])
+            .case_insensitive(true))
+        .get_matches_from_safe(vec!["pv", "--option", "TeSt123"]);
+
+    assert!(m.is_ok());
+    assert_eq!(m.unwrap().value_of("option"), Some("test123"));
+}
+
+#[test]
+fn case_insensitive_positional() {
+    let m = App::new("pv")
+        .arg(Arg::with_name("level")
+            .possible_values(&["info", "warn"])
+            .case_insensitive(true))
+        .get_matches_from_safe(vec!["pv", "INFO"]);
+
+    assert!(m.is_ok());
+    assert_eq!(m.unwrap().value_of("level"), Some("info"));
+}
+
+#[test]
+fn case_insensitive_fail() {
+    let m = App::new("pv")
+        .arg(Arg::with_name("option")
+            .short("o")
+            .long("--option")
+            .takes_value(true)
+            .possible_values(&["test123", "test321"]))
+        .get_matches_from_safe(vec!["pv", "--option", "TeSt123"]);
+
+    assert!(m.is_err());
+    assert_eq!(m.unwrap_err().kind, ErrorKind::InvalidValue);
+}
+
+#[test]
+fn case_insensitive_multiple() {
+    let m = App::new("pv")
+        .arg(Arg::with_name("option")
+            .short("o")
+            .long("--option")
+            .takes_value(true)
+            .possible_values(&["test123", "test321"])
+            .multiple(true)
+            .use_delimiter(true)
+            .case_insensitive(true))
+        .get_matches_from_safe(vec!["pv", "--option", "TeSt123,teST123,tESt321"]);
+
+    assert!(m.is_ok());
+    assert_eq!(m.unwrap().values_of("option").unwrap().collect::<Vec<_>>(),
+               &["test123", "test123", "test321"]);
+}
+
+#[test]
+fn case_insensitive_multiple_fail() {
+    let m = App::new("pv")
+        .arg(Arg::with_name("option")
+            .short("o")
+            .long("--option")
+            .takes_value(true)
+            .possible_values(&["test123", "test321"])
+            .multiple(true))
+        .get_matches_from_safe(vec!["pv", "--option", "test123", "teST123", "test321"]);
+
+    assert!(m.is_err());
+    assert_eq!(m.unwrap_err().kind, ErrorKind::InvalidValue);
+}
+
+#[test]
+fn case_insensitive_default_value() {
+    let m = App::new("pv")
+        .arg(Arg::with_name("option")
+            .long("--option")
+            .takes_value(true)
+            .possible_values(&["test123", "test321"])
+            .default_value("TEST321")
+            .case_insensitive(true))
+        .get_matches_from_safe(vec!["pv"]);
+
+    assert!(m.is_ok());
+    assert_eq!(m.unwrap().value_of("option"), Some("test321"));
+}
+
+#[test]
+fn case_insensitive_output() {
+    let app = App::new("clap-test")
+        .arg(Arg::with_name("option3")
+            .short("O")
+            .long("Option")
+            .takes_value(true)
+            .possible_values(&["fast", "slow"])
+            .case_insensitive(true));
+    test::check_err_output(app, "clap-test -O slo", PV_ERROR, true);
+}
@@ -0,0 +1,160 @@
+extern crate clap;
+
+use std::cell::RefCell;
+use std::io::{self, Cursor, Write};
+use std::rc::Rc;
+
+use clap::{App, Arg, ErrorKind, SubCommand};
+
+// Collects what's written to it, for checking the prompts after parsing
+#[derive(Clone, Default)]
+struct Output(Rc<RefCell<Vec<u8>>>);
+
+impl Output {
+    fn contents(&self) -> String {
+        String::from_utf8(self.0.borrow().clone()).unwrap()
+    }
+}
+
+impl Write for Output {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0.borrow_mut().write(buf)
+    }
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+fn cluster_arg() -> Arg<'static, 'static> {
+    Arg::with_name("cluster")
+        .long("cluster")
+        .required(true)
+        .prompt("Cluster name")
+}
+
+#[test]
+fn prompt_for_missing_value() {
+    let out = Output::default();
+    let m = App::new("prompt")
+        .prompt_streams(Cursor::new("prod\n"), out.clone())
+        .arg(cluster_arg())
+        .get_matches_from(vec!["prompt"]);
+
+    assert_eq!(m.value_of("cluster"), Some("prod"));
+    assert_eq!(m.occurrences_of("cluster"), 1);
+    assert_eq!(out.contents(), "Cluster name: ");
+}
+
+#[test]
+fn prompt_not_used_when_present() {
+    let out = Output::default();
+    let m = App::new("prompt")
+        .prompt_streams(Cursor::new("prod\n"), out.clone())
+        .arg(cluster_arg())
+        .get_matches_from(vec!["prompt", "--cluster", "staging"]);
+
+    assert_eq!(m.value_of("cluster"), Some("staging"));
+    assert_eq!(out.contents(), "");
+}
+
+#[test]
+fn prompt_asks_again_after_empty_answer() {
+    let out = Output::default();
+    let m = App::new("prompt")
+        .prompt_streams(Cursor::new("\nprod\n"), out.clone())
+        .arg(cluster_arg())
+        .get_matches_from(vec!["prompt"]);
+
+    assert_eq!(m.value_of("cluster"), Some("prod"));
+    assert_eq!(out.contents(), "Cluster name: Cluster name: ");
+}
+
+#[test]
+fn prompt_end_of_input() {
+    let res = App::new("prompt")
+        .prompt_streams(Cursor::new(""), io::sink())
+        .arg(cluster_arg())
+        .get_matches_from_safe(vec!["prompt"]);
+
+    assert!(res.is_err());
+    assert_eq!(res.unwrap_err().kind, ErrorKind::MissingRequiredArgument);
+}
+
+#[test]
+fn no_prompt_set() {
+    let res = App::new("prompt")
+        .prompt_streams(Cursor::new("prod\n"), io::sink())
+        .arg(Arg::with_name("cluster")
+            .long("cluster")
+            .takes_value(true)
+            .required(true))
+        .get_matches_from_safe(vec!["prompt"]);
+
+    assert!(res.is_err());
+    assert_eq!(res.unwrap_err().kind, ErrorKind::MissingRequiredArgument);
+}
+
+#[test]
+fn prompt_choice_menu() {
+    let out = Output::default();
+    let m = App::new("prompt")
+        .prompt_streams(Cursor::new("5\nstaging\n"), out.clone())
+        .arg(cluster_arg().possible_values(&["prod", "staging"]))
+        .get_matches_from(vec!["prompt"]);
+
+    assert_eq!(m.value_of("cluster"), Some("staging"));
+    assert_eq!(out.contents(),
+               "Cluster name:\n  1) prod\n  2) staging\nSelect 1-2: Invalid choice '5'\nSelect 1-2: ");
+}
+
+#[test]
+fn prompt_value_is_validated() {
+    let res = App::new("prompt")
+        .prompt_streams(Cursor::new("eighty\n"), io::sink())
+        .arg(Arg::with_name("port")
+            .long("port")
+            .required(true)
+            .prompt("Port")
+            .validator(|v| v.parse::<u16>().map(|_| ()).map_err(|e| e.to_string())))
+        .get_matches_from_safe(vec!["prompt"]);
+
+    assert!(res.is_err());
+    assert_eq!(res.unwrap_err().kind, ErrorKind::ValueValidation);
+}
+
+#[test]
+fn prompt_positional() {
+    let m = App::new("prompt")
+        .prompt_streams(Cursor::new("file.txt\n"), io::sink())
+        .arg(Arg::with_name("input")
+            .required(true)
+            .prompt("Input file"))
+        .get_matches_from(vec!["prompt"]);
+
+    assert_eq!(m.value_of("input"), Some("file.txt"));
+}
+
+#[test]
+fn prompt_hidden_input() {
+    let m = App::new("prompt")
+        .prompt_streams(Cursor::new("hunter2\n"), io::sink())
+        .arg(Arg::with_name("password")
+            .long("password")
+            .required(true)
+            .prompt("Password")
+            .hide_input(true))
+        .get_matches_from(vec!["prompt"]);
+
+    assert_eq!(m.value_of("password"), Some("hunter2"));
+}
+
+#[test]
+fn prompt_in_subcommand() {
+    let m = App::new("prompt")
+        .prompt_streams(Cursor::new("prod\n"), io::sink())
+        .subcommand(SubCommand::with_name("deploy").arg(cluster_arg()))
+        .get_matches_from(vec!["prompt", "deploy"]);
+
+    assert_eq!(m.subcommand_matches("deploy").unwrap().value_of("cluster"),
+               Some("prod"));
+}
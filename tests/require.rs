@@ -1,3 +1,4 @@
+#[macro_use]
 extern crate clap;
 extern crate regex;
 
@@ -358,3 +359,195 @@ USAGE:
 
 For more information try --help", true)
 }
+
+#[test]
+fn required_if_val_present_pass() {
+    let res = App::new("ri")
+        .arg(Arg::with_name("cfg")
+            .required_if("extra", "val")
+            .takes_value(true)
+            .long("config"))
+        .arg(Arg::with_name("extra")
+            .takes_value(true)
+            .long("extra"))
+        .get_matches_from_safe(vec!["ri", "--extra", "val", "--config", "my.cfg"]);
+
+    assert!(res.is_ok());
+}
+
+#[test]
+fn required_if_val_present_fail() {
+    let res = App::new("ri")
+        .arg(Arg::with_name("cfg")
+            .required_if("extra", "val")
+            .takes_value(true)
+            .long("config"))
+        .arg(Arg::with_name("extra")
+            .takes_value(true)
+            .long("extra"))
+        .get_matches_from_safe(vec!["ri", "--extra", "val"]);
+
+    assert!(res.is_err());
+    assert_eq!(res.unwrap_err().kind, ErrorKind::MissingRequiredArgument);
+}
+
+#[test]
+fn required_if_wrong_val() {
+    let res = App::new("ri")
+        .arg(Arg::with_name("cfg")
+            .required_if("extra", "val")
+            .takes_value(true)
+            .long("config"))
+        .arg(Arg::with_name("extra")
+            .takes_value(true)
+            .long("extra"))
+        .get_matches_from_safe(vec!["ri", "--extra", "other"]);
+
+    assert!(res.is_ok());
+}
+
+#[test]
+fn required_ifs_val_present_fail() {
+    let res = App::new("ri")
+        .arg(Arg::with_name("cfg")
+            .required_ifs(&[("extra", "val"), ("option", "spec")])
+            .takes_value(true)
+            .long("config"))
+        .arg(Arg::with_name("option")
+            .takes_value(true)
+            .long("option"))
+        .arg(Arg::with_name("extra")
+            .takes_value(true)
+            .long("extra"))
+        .get_matches_from_safe(vec!["ri", "--option", "spec"]);
+
+    assert!(res.is_err());
+    assert_eq!(res.unwrap_err().kind, ErrorKind::MissingRequiredArgument);
+}
+
+#[test]
+fn required_ifs_wrong_val() {
+    let res = App::new("ri")
+        .arg(Arg::with_name("cfg")
+            .required_ifs(&[("extra", "val"), ("option", "spec")])
+            .takes_value(true)
+            .long("config"))
+        .arg(Arg::with_name("option")
+            .takes_value(true)
+            .long("option"))
+        .arg(Arg::with_name("extra")
+            .takes_value(true)
+            .long("extra"))
+        .get_matches_from_safe(vec!["ri", "--option", "other"]);
+
+    assert!(res.is_ok());
+}
+
+#[test]
+fn requires_if_present_val() {
+    let res = App::new("unlessone")
+        .arg(Arg::with_name("cfg")
+            .requires_if("my.cfg", "extra")
+            .takes_value(true)
+            .long("config"))
+        .arg(Arg::with_name("extra")
+            .long("extra"))
+        .get_matches_from_safe(vec!["unlessone", "--config=my.cfg"]);
+
+    assert!(res.is_err());
+    assert_eq!(res.unwrap_err().kind, ErrorKind::MissingRequiredArgument);
+}
+
+#[test]
+fn requires_if_present_mult() {
+    let res = App::new("unlessone")
+        .arg(Arg::with_name("cfg")
+            .requires_ifs(&[("my.cfg", "extra"), ("other.cfg", "other")])
+            .takes_value(true)
+            .long("config"))
+        .arg(Arg::with_name("extra")
+            .long("extra"))
+        .arg(Arg::with_name("other")
+            .long("other"))
+        .get_matches_from_safe(vec!["unlessone", "--config=other.cfg"]);
+
+    assert!(res.is_err());
+    assert_eq!(res.unwrap_err().kind, ErrorKind::MissingRequiredArgument);
+}
+
+#[test]
+fn requires_if_present_mult_pass() {
+    let res = App::new("unlessone")
+        .arg(Arg::with_name("cfg")
+            .requires_ifs(&[("my.cfg", "extra"), ("other.cfg", "other")])
+            .takes_value(true)
+            .long("config"))
+        .arg(Arg::with_name("extra")
+            .long("extra"))
+        .arg(Arg::with_name("other")
+            .long("other"))
+        .get_matches_from_safe(vec!["unlessone", "--config=some.cfg"]);
+
+    assert!(res.is_ok());
+}
+
+#[test]
+fn requires_if_present_val_no_present_pass() {
+    let res = App::new("unlessone")
+        .arg(Arg::with_name("cfg")
+            .requires_if("my.cfg", "extra")
+            .takes_value(true)
+            .long("config"))
+        .arg(Arg::with_name("extra")
+            .long("extra"))
+        .get_matches_from_safe(vec!["unlessone"]);
+
+    assert!(res.is_ok());
+}
+
+static COND_REQ_ERR: &'static str = "error: The following required arguments were not provided:
+    --token <token> (required because --auth is 'token')
+
+USAGE:
+    clap-test --auth <auth>
+
+For more information try --help";
+
+#[test]
+fn requires_if_error_output() {
+    let app = App::new("clap-test")
+        .arg(Arg::with_name("auth")
+            .long("auth")
+            .takes_value(true)
+            .requires_if("token", "token"))
+        .arg(Arg::with_name("token")
+            .long("token")
+            .takes_value(true));
+
+    test::check_err_output(app, "clap-test --auth token", COND_REQ_ERR, true);
+}
+
+#[test]
+fn required_if_error_output() {
+    let app = App::new("clap-test")
+        .arg(Arg::with_name("auth")
+            .long("auth")
+            .takes_value(true))
+        .arg(Arg::with_name("token")
+            .long("token")
+            .takes_value(true)
+            .required_if("auth", "token"));
+
+    test::check_err_output(app, "clap-test --auth token", COND_REQ_ERR, true);
+}
+
+#[test]
+fn clap_app_required_if() {
+    let res = clap_app!(ri =>
+        (@arg mode: --mode +takes_value "the mode")
+        (@arg key: --key +takes_value required_if("mode", "tls") "the key")
+    ).get_matches_from_safe(vec!["ri", "--mode", "tls"]);
+
+    assert!(res.is_err());
+    assert_eq!(res.unwrap_err().kind, ErrorKind::MissingRequiredArgument);
+}
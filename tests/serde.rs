@@ -0,0 +1,164 @@
+#![cfg(feature="serde")]
+
+extern crate bincode;
+extern crate clap;
+extern crate serde_json;
+
+use clap::{App, AppSettings, Arg, ArgMatches, SubCommand, ValueSource};
+
+fn app() -> App<'static, 'static> {
+    App::new("batch")
+        .arg(Arg::with_name("verbose")
+            .short("v")
+            .multiple(true))
+        .arg(Arg::with_name("jobs")
+            .long("jobs")
+            .default_value("1"))
+        .arg(Arg::with_name("cache")
+            .long("cache")
+            .negatable(true))
+        .arg(Arg::with_name("input").multiple(true))
+        .subcommand(SubCommand::with_name("run")
+            .arg(Arg::with_name("target")
+                .long("target")
+                .takes_value(true)))
+}
+
+fn round_trip<'a>(json: &'a str) -> ArgMatches<'a> {
+    serde_json::from_str(json).unwrap()
+}
+
+#[test]
+fn serde_values_and_occurrences() {
+    let m = app().get_matches_from(vec!["batch", "-vv", "a.txt", "b.txt"]);
+    let json = serde_json::to_string(&m).unwrap();
+    let m = round_trip(&json);
+
+    assert_eq!(m.occurrences_of("verbose"), 2);
+    assert_eq!(m.values_of("input").unwrap().collect::<Vec<_>>(), ["a.txt", "b.txt"]);
+    assert_eq!(m.indices_of("input").unwrap().collect::<Vec<_>>(), [3, 4]);
+    assert_eq!(m.value_of("jobs"), Some("1"));
+    assert_eq!(m.value_source("jobs"), Some(ValueSource::DefaultValue));
+    assert_eq!(m.value_source("input"), Some(ValueSource::CommandLine));
+}
+
+#[test]
+fn serde_negated() {
+    let m = app().get_matches_from(vec!["batch", "--no-cache"]);
+    let json = serde_json::to_string(&m).unwrap();
+    let m = round_trip(&json);
+
+    assert!(m.is_negated("cache"));
+    assert!(!m.is_present("cache"));
+}
+
+#[test]
+fn serde_subcommand() {
+    let m = app().get_matches_from(vec!["batch", "-v", "run", "--target", "prod"]);
+    let json = serde_json::to_string(&m).unwrap();
+    let m = round_trip(&json);
+
+    assert_eq!(m.occurrences_of("verbose"), 1);
+    assert_eq!(m.subcommand_name(), Some("run"));
+    assert_eq!(m.subcommand_matches("run").unwrap().value_of("target"), Some("prod"));
+}
+
+#[test]
+fn serde_chained_subcommands() {
+    let m = App::new("tool")
+        .setting(AppSettings::SubcommandsChain)
+        .subcommand(SubCommand::with_name("fetch"))
+        .subcommand(SubCommand::with_name("build")
+            .arg(Arg::with_name("release").long("release")))
+        .get_matches_from(vec!["tool", "fetch", "build", "--release"]);
+    let json = serde_json::to_string(&m).unwrap();
+    let m = round_trip(&json);
+
+    let used: Vec<_> = m.subcommands().into_iter().map(|(name, _)| name).collect();
+    assert_eq!(used, ["fetch", "build"]);
+    assert!(m.subcommand_matches("build").unwrap().is_present("release"));
+}
+
+#[test]
+fn serde_usage() {
+    let m = app().get_matches_from(vec!["batch"]);
+    let json = serde_json::to_string(&m).unwrap();
+    let m2 = round_trip(&json);
+
+    assert_eq!(m.usage(), m2.usage());
+}
+
+#[cfg(unix)]
+#[test]
+fn serde_invalid_utf8() {
+    use std::ffi::OsString;
+    use std::os::unix::ffi::OsStringExt;
+
+    let m = App::new("batch")
+        .setting(AppSettings::AllowInvalidUtf8)
+        .arg(Arg::with_name("input"))
+        .get_matches_from(vec![OsString::from("batch"), OsString::from_vec(vec![0xe9, b'a'])]);
+    let json = serde_json::to_string(&m).unwrap();
+    let m = round_trip(&json);
+
+    assert_eq!(m.value_of_os("input").unwrap(), &*OsString::from_vec(vec![0xe9, b'a']));
+}
+
+#[test]
+fn serde_not_self_describing() {
+    let m = app().get_matches_from(vec!["batch", "-v", "--no-cache", "a.txt", "run", "--target", "prod"]);
+    let bytes = bincode::serialize(&m).unwrap();
+    let m: ArgMatches = bincode::deserialize(&bytes).unwrap();
+
+    assert_eq!(m.occurrences_of("verbose"), 1);
+    assert!(m.is_negated("cache"));
+    assert_eq!(m.value_of("input"), Some("a.txt"));
+    assert_eq!(m.value_source("jobs"), Some(ValueSource::DefaultValue));
+    assert_eq!(m.subcommand_matches("run").unwrap().value_of("target"), Some("prod"));
+}
+
+#[test]
+fn serde_matches_escaped_names() {
+    let app = App::new("batch").arg(Arg::with_name("a\"b").long("quote"));
+    let m = app.clone().get_matches_from(vec!["batch", "--quote"]);
+    let json = serde_json::to_string(&m).unwrap();
+    let m = app.deserialize_matches(&mut serde_json::Deserializer::from_str(&json)).unwrap();
+
+    assert!(m.is_present("a\"b"));
+}
+
+#[test]
+fn serde_matches_from_reader() {
+    let m = app().get_matches_from(vec!["batch", "-vv", "a.txt", "run", "--target", "prod"]);
+    let json = serde_json::to_vec(&m).unwrap();
+    let m = app()
+        .deserialize_matches(&mut serde_json::Deserializer::from_reader(&json[..]))
+        .unwrap();
+
+    assert_eq!(m.occurrences_of("verbose"), 2);
+    assert_eq!(m.value_of("input"), Some("a.txt"));
+    assert_eq!(m.subcommand_matches("run").unwrap().value_of("target"), Some("prod"));
+}
+
+#[test]
+fn serde_matches_global_in_subcommand() {
+    let app = app().arg(Arg::with_name("debug")
+        .long("debug")
+        .global(true));
+    let m = app.clone().get_matches_from(vec!["batch", "run", "--debug"]);
+    let json = serde_json::to_vec(&m).unwrap();
+    let m = app.deserialize_matches(&mut serde_json::Deserializer::from_reader(&json[..]))
+        .unwrap();
+
+    assert!(m.subcommand_matches("run").unwrap().is_present("debug"));
+}
+
+#[test]
+fn serde_matches_unknown_name() {
+    let m = app().get_matches_from(vec!["batch", "-v"]);
+    let json = serde_json::to_vec(&m).unwrap();
+    let other = App::new("batch").arg(Arg::with_name("quiet").short("q"));
+    let r = other.deserialize_matches(&mut serde_json::Deserializer::from_reader(&json[..]));
+
+    assert!(r.is_err());
+}
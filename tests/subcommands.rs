@@ -3,7 +3,7 @@ extern crate regex;
 
 include!("../clap-test.rs");
 
-use clap::{App, Arg, SubCommand, ErrorKind};
+use clap::{App, AppSettings, Arg, SubCommand, ErrorKind};
 
 static VISIBLE_ALIAS_HELP: &'static str = "clap-test 2.6
 
@@ -152,3 +152,273 @@ fn invisible_aliases_help_output() {
             .alias("invisible"));
     test::check_help(app, INVISIBLE_ALIAS_HELP);
 }
+
+static AMBIGUOUS_SC: &'static str = "error: The subcommand 'te' is ambiguous
+\tIt could be any of: 'temp', 'test'
+
+If you believe you received this message in error, try re-running with 'dym -- te'
+
+USAGE:
+    dym [SUBCOMMAND]
+
+For more information try --help";
+
+#[test]
+fn infer_subcommands_prefix() {
+    let m = App::new("prog")
+        .setting(AppSettings::InferSubcommands)
+        .subcommand(SubCommand::with_name("status"))
+        .subcommand(SubCommand::with_name("deploy"))
+        .get_matches_from(vec!["prog", "stat"]);
+    assert_eq!(m.subcommand_name(), Some("status"));
+}
+
+#[test]
+fn infer_subcommands_alias_prefix() {
+    let m = App::new("prog")
+        .setting(AppSettings::InferSubcommands)
+        .subcommand(SubCommand::with_name("status").alias("info"))
+        .get_matches_from(vec!["prog", "inf"]);
+    assert_eq!(m.subcommand_name(), Some("status"));
+}
+
+#[test]
+fn infer_subcommands_name_and_alias_same_sc() {
+    let m = App::new("prog")
+        .setting(AppSettings::InferSubcommands)
+        .subcommand(SubCommand::with_name("test").alias("testing"))
+        .get_matches_from(vec!["prog", "te"]);
+    assert_eq!(m.subcommand_name(), Some("test"));
+}
+
+#[test]
+fn infer_subcommands_exact_match_wins() {
+    let m = App::new("prog")
+        .setting(AppSettings::InferSubcommands)
+        .subcommand(SubCommand::with_name("test"))
+        .subcommand(SubCommand::with_name("testing"))
+        .get_matches_from(vec!["prog", "test"]);
+    assert_eq!(m.subcommand_name(), Some("test"));
+}
+
+#[test]
+fn infer_subcommands_with_args() {
+    let m = App::new("prog")
+        .setting(AppSettings::InferSubcommands)
+        .subcommand(SubCommand::with_name("deploy")
+            .arg(Arg::with_name("target")))
+        .get_matches_from(vec!["prog", "dep", "prod"]);
+    assert_eq!(m.subcommand_matches("deploy").unwrap().value_of("target"),
+               Some("prod"));
+}
+
+#[test]
+fn infer_subcommands_ambiguous() {
+    let res = App::new("prog")
+        .setting(AppSettings::InferSubcommands)
+        .subcommand(SubCommand::with_name("test"))
+        .subcommand(SubCommand::with_name("temp"))
+        .get_matches_from_safe(vec!["prog", "te"]);
+    assert!(res.is_err());
+    let err = res.unwrap_err();
+    assert_eq!(err.kind, ErrorKind::InvalidSubcommand);
+    assert_eq!(err.info,
+               Some(vec!["te".to_owned(), "temp".to_owned(), "test".to_owned()]));
+}
+
+#[test]
+fn infer_subcommands_ambiguous_output() {
+    let app = App::new("dym")
+        .setting(AppSettings::InferSubcommands)
+        .subcommand(SubCommand::with_name("test"))
+        .subcommand(SubCommand::with_name("temp"));
+    test::check_err_output(app, "dym te", AMBIGUOUS_SC, true);
+}
+
+#[test]
+fn infer_subcommands_not_set() {
+    let res = App::new("prog")
+        .subcommand(SubCommand::with_name("status"))
+        .get_matches_from_safe(vec!["prog", "stat"]);
+    assert!(res.is_err());
+    assert_eq!(res.unwrap_err().kind, ErrorKind::InvalidSubcommand);
+}
+
+#[test]
+fn infer_subcommands_help_subcommand() {
+    let res = App::new("prog")
+        .setting(AppSettings::InferSubcommands)
+        .subcommand(SubCommand::with_name("status")
+            .about("shows the status"))
+        .get_matches_from_safe(vec!["prog", "help", "stat"]);
+    assert!(res.is_err());
+    let err = res.unwrap_err();
+    assert_eq!(err.kind, ErrorKind::HelpDisplayed);
+    assert!(err.message.contains("shows the status"));
+}
+
+#[test]
+fn global_arg_used_in_subcommand_seen_by_parent() {
+    let m = App::new("prog")
+        .arg(Arg::with_name("verbose")
+            .long("verbose")
+            .global(true))
+        .subcommand(SubCommand::with_name("sub")
+            .subcommand(SubCommand::with_name("subsub")))
+        .get_matches_from(vec!["prog", "sub", "subsub", "--verbose"]);
+
+    assert!(m.is_present("verbose"));
+    let sub_m = m.subcommand_matches("sub").unwrap();
+    assert!(sub_m.is_present("verbose"));
+    assert!(sub_m.subcommand_matches("subsub").unwrap().is_present("verbose"));
+}
+
+#[test]
+fn global_arg_used_in_parent_seen_by_subcommand() {
+    let m = App::new("prog")
+        .arg(Arg::with_name("color")
+            .long("color")
+            .takes_value(true)
+            .global(true))
+        .subcommand(SubCommand::with_name("sub"))
+        .get_matches_from(vec!["prog", "--color", "never", "sub"]);
+
+    assert_eq!(m.value_of("color"), Some("never"));
+    assert_eq!(m.subcommand_matches("sub").unwrap().value_of("color"), Some("never"));
+}
+
+#[test]
+fn global_arg_deepest_value_wins() {
+    let m = App::new("prog")
+        .arg(Arg::with_name("color")
+            .long("color")
+            .takes_value(true)
+            .global(true))
+        .subcommand(SubCommand::with_name("sub"))
+        .get_matches_from(vec!["prog", "--color", "never", "sub", "--color", "always"]);
+
+    assert_eq!(m.value_of("color"), Some("always"));
+    assert_eq!(m.subcommand_matches("sub").unwrap().value_of("color"), Some("always"));
+}
+
+#[test]
+fn global_arg_default_does_not_override_used_value() {
+    let m = App::new("prog")
+        .arg(Arg::with_name("color")
+            .long("color")
+            .takes_value(true)
+            .default_value("auto")
+            .global(true))
+        .subcommand(SubCommand::with_name("sub"))
+        .get_matches_from(vec!["prog", "--color", "never", "sub"]);
+
+    assert_eq!(m.value_of("color"), Some("never"));
+    let sub_m = m.subcommand_matches("sub").unwrap();
+    assert_eq!(sub_m.value_of("color"), Some("never"));
+    assert_eq!(sub_m.occurrences_of("color"), 1);
+}
+
+fn chain_app() -> App<'static, 'static> {
+    App::new("tool")
+        .setting(AppSettings::SubcommandsChain)
+        .arg(Arg::with_name("verbose")
+            .long("verbose")
+            .global(true))
+        .subcommand(SubCommand::with_name("fetch")
+            .arg(Arg::with_name("all").long("all")))
+        .subcommand(SubCommand::with_name("build")
+            .alias("b")
+            .arg(Arg::with_name("release").long("release"))
+            .arg(Arg::with_name("target")))
+        .subcommand(SubCommand::with_name("test"))
+}
+
+#[test]
+fn subcommands_chain() {
+    let m = chain_app()
+        .get_matches_from(vec!["tool", "fetch", "--all", "build", "--release", "test"]);
+
+    let used: Vec<_> = m.subcommands().into_iter().map(|(name, _)| name).collect();
+    assert_eq!(used, ["fetch", "build", "test"]);
+    assert_eq!(m.subcommand_name(), Some("fetch"));
+    assert!(m.subcommand_matches("fetch").unwrap().is_present("all"));
+    assert!(m.subcommand_matches("build").unwrap().is_present("release"));
+    assert!(m.subcommand_matches("test").is_some());
+}
+
+#[test]
+fn subcommands_chain_after_positional_and_alias() {
+    let m = chain_app().get_matches_from(vec!["tool", "test", "b", "x86", "test"]);
+
+    let used: Vec<_> = m.subcommands().into_iter().map(|(name, _)| name).collect();
+    assert_eq!(used, ["test", "build", "test"]);
+    assert_eq!(m.subcommand_matches("build").unwrap().value_of("target"), Some("x86"));
+}
+
+#[test]
+fn subcommands_chain_ends_at_double_dash() {
+    let m = chain_app().get_matches_from(vec!["tool", "build", "--", "test"]);
+
+    let used: Vec<_> = m.subcommands().into_iter().map(|(name, _)| name).collect();
+    assert_eq!(used, ["build"]);
+    assert_eq!(m.subcommand_matches("build").unwrap().value_of("target"), Some("test"));
+}
+
+#[test]
+fn subcommands_chain_repeated_with_requirements() {
+    let m = App::new("tool")
+        .setting(AppSettings::SubcommandsChain)
+        .subcommand(SubCommand::with_name("build")
+            .arg(Arg::with_name("a").long("a").requires("b"))
+            .arg(Arg::with_name("b").long("b")))
+        .subcommand(SubCommand::with_name("test"))
+        .get_matches_from(vec!["tool", "build", "--a", "--b", "test", "build"]);
+
+    let used: Vec<_> = m.subcommands().into_iter().map(|(name, _)| name).collect();
+    assert_eq!(used, ["build", "test", "build"]);
+    let last = m.subcommands().into_iter().last().unwrap().1;
+    assert!(!last.is_present("a"));
+    assert!(!last.is_present("b"));
+}
+
+#[test]
+fn subcommands_chain_global_arg() {
+    let m = chain_app().get_matches_from(vec!["tool", "fetch", "test", "--verbose"]);
+
+    assert!(m.is_present("verbose"));
+    assert!(m.subcommand_matches("fetch").unwrap().is_present("verbose"));
+    assert!(m.subcommand_matches("test").unwrap().is_present("verbose"));
+}
+
+#[test]
+fn subcommands_chain_not_set() {
+    let res = App::new("tool")
+        .subcommand(SubCommand::with_name("fetch"))
+        .subcommand(SubCommand::with_name("test"))
+        .get_matches_from_safe(vec!["tool", "fetch", "test"]);
+    assert!(res.is_err());
+    assert_eq!(res.unwrap_err().kind, ErrorKind::UnknownArgument);
+}
+
+static CHAIN_USAGE: &'static str = "tool 
+
+USAGE:
+    tool [SUBCOMMAND]...
+
+FLAGS:
+    -h, --help       Prints help information
+    -V, --version    Prints version information
+
+SUBCOMMANDS:
+    build    
+    help     Prints this message or the help of the given subcommand(s)
+    test     ";
+
+#[test]
+fn subcommands_chain_usage() {
+    let app = App::new("tool")
+        .setting(AppSettings::SubcommandsChain)
+        .subcommand(SubCommand::with_name("build"))
+        .subcommand(SubCommand::with_name("test"));
+    test::check_help(app, CHAIN_USAGE);
+}
@@ -6,7 +6,7 @@ include!("../clap-test.rs");
 
 use clap::{App, Arg};
 
-static SCF2OP: &'static str = "flag NOT present
+static SCF2OP: &'static str = "flag present 2 times
 option NOT present
 positional NOT present
 flag2 NOT present
@@ -23,7 +23,7 @@ An scoption: some
 scpositional present with value: value
 ";
 
-static SCFOP: &'static str = "flag NOT present
+static SCFOP: &'static str = "flag present 1 times
 option NOT present
 positional NOT present
 flag2 NOT present
@@ -328,3 +328,19 @@ fn sc_short_flag_x2_short_opt_eq_pos() {
 fn sc_short_flag_x2_long_opt_eq_pos() {
     test::check_complex_output("clap-test subcmd value -f -f --option=some", SCF2OP);
 }
+
+#[test]
+fn arg_enum_case_insensitive() {
+    let m = App::new("enums")
+        .arg(Arg::with_name("val")
+            .possible_values(&Val1::variants())
+            .case_insensitive(true))
+        .get_matches_from(vec!["enums", "VALTWO"]);
+
+    assert_eq!(m.value_of("val"), Some("ValTwo"));
+    let v = value_t!(m, "val", Val1).unwrap();
+    match v {
+        Val1::ValTwo => (),
+        _ => panic!("Val1 didn't parse correctly"),
+    }
+}
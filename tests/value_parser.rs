@@ -0,0 +1,120 @@
+extern crate clap;
+
+use std::net::Ipv4Addr;
+
+use clap::{App, Arg, ArgAction, ErrorKind};
+
+#[test]
+fn value_parser_option() {
+    let m = App::new("vp")
+        .arg(Arg::with_name("port")
+            .long("port")
+            .takes_value(true)
+            .value_parser::<u16>())
+        .get_matches_from(vec!["vp", "--port", "8080"]);
+
+    assert_eq!(m.get_one::<u16>("port").unwrap(), Some(&8080));
+    assert_eq!(m.value_of("port"), Some("8080"));
+}
+
+#[test]
+fn value_parser_positional() {
+    let m = App::new("vp")
+        .arg(Arg::with_name("addr").value_parser::<Ipv4Addr>())
+        .get_matches_from(vec!["vp", "127.0.0.1"]);
+
+    assert_eq!(m.get_one::<Ipv4Addr>("addr").unwrap(),
+               Some(&Ipv4Addr::new(127, 0, 0, 1)));
+}
+
+#[test]
+fn value_parser_multiple() {
+    let m = App::new("vp")
+        .arg(Arg::with_name("num")
+            .short("n")
+            .takes_value(true)
+            .multiple(true)
+            .value_parser::<i32>())
+        .get_matches_from(vec!["vp", "-n", "1", "-n", "2", "-n", "3"]);
+
+    assert_eq!(m.get_many::<i32>("num").unwrap(), Some(vec![&1, &2, &3]));
+    assert_eq!(m.get_one::<i32>("num").unwrap(), Some(&1));
+}
+
+#[test]
+fn value_parser_invalid() {
+    let res = App::new("vp")
+        .arg(Arg::with_name("port")
+            .long("port")
+            .takes_value(true)
+            .value_parser::<u16>())
+        .get_matches_from_safe(vec!["vp", "--port", "99999"]);
+
+    assert!(res.is_err());
+    assert_eq!(res.unwrap_err().kind, ErrorKind::ValueValidation);
+}
+
+#[test]
+fn value_parser_default_value() {
+    let m = App::new("vp")
+        .arg(Arg::with_name("port")
+            .long("port")
+            .takes_value(true)
+            .default_value("80")
+            .value_parser::<u16>())
+        .get_matches_from(vec!["vp"]);
+
+    assert_eq!(m.get_one::<u16>("port").unwrap(), Some(&80));
+}
+
+#[test]
+fn value_parser_set_action() {
+    let m = App::new("vp")
+        .arg(Arg::with_name("port")
+            .long("port")
+            .action(ArgAction::Set)
+            .value_parser::<u16>())
+        .get_matches_from(vec!["vp", "--port", "80", "--port", "443"]);
+
+    assert_eq!(m.get_many::<u16>("port").unwrap(), Some(vec![&443]));
+}
+
+#[test]
+fn value_parser_absent() {
+    let m = App::new("vp")
+        .arg(Arg::with_name("port")
+            .long("port")
+            .takes_value(true)
+            .value_parser::<u16>())
+        .get_matches_from(vec!["vp"]);
+
+    assert_eq!(m.get_one::<u16>("port").unwrap(), None);
+    assert_eq!(m.get_many::<u16>("port").unwrap(), None);
+}
+
+#[test]
+fn value_parser_type_mismatch() {
+    let m = App::new("vp")
+        .arg(Arg::with_name("port")
+            .long("port")
+            .takes_value(true)
+            .value_parser::<u16>())
+        .get_matches_from(vec!["vp", "--port", "80"]);
+
+    let err = m.get_one::<String>("port").unwrap_err();
+    assert_eq!(err.kind, ErrorKind::ValueTypeMismatch);
+    assert_eq!(err.info, Some(vec!["port".to_owned()]));
+    assert!(m.get_many::<u32>("port").is_err());
+}
+
+#[test]
+fn get_one_without_value_parser() {
+    let m = App::new("vp")
+        .arg(Arg::with_name("port")
+            .long("port")
+            .takes_value(true))
+        .get_matches_from(vec!["vp", "--port", "80"]);
+
+    let err = m.get_one::<String>("port").unwrap_err();
+    assert_eq!(err.kind, ErrorKind::ValueTypeMismatch);
+}
@@ -0,0 +1,206 @@
+extern crate clap;
+
+use std::env;
+use std::io::{self, Cursor};
+
+use clap::{App, AppSettings, Arg, ArgAction, ArgGroup, ErrorKind, SubCommand, ValueSource};
+
+fn jobs() -> Arg<'static, 'static> {
+    Arg::with_name("jobs")
+        .long("jobs")
+        .default_value("1")
+}
+
+#[test]
+fn source_command_line() {
+    let m = App::new("src")
+        .arg(jobs())
+        .get_matches_from(vec!["src", "--jobs", "4"]);
+
+    assert_eq!(m.value_source("jobs"), Some(ValueSource::CommandLine));
+    assert!(m.is_explicitly_present("jobs"));
+}
+
+#[test]
+fn source_default() {
+    let m = App::new("src")
+        .arg(jobs())
+        .get_matches_from(vec!["src"]);
+
+    assert_eq!(m.value_source("jobs"), Some(ValueSource::DefaultValue));
+    assert!(m.is_present("jobs"));
+    assert!(!m.is_explicitly_present("jobs"));
+}
+
+#[test]
+fn source_not_present() {
+    let m = App::new("src")
+        .arg(Arg::with_name("debug").long("debug"))
+        .get_matches_from(vec!["src"]);
+
+    assert_eq!(m.value_source("debug"), None);
+    assert!(!m.is_explicitly_present("debug"));
+}
+
+#[test]
+fn source_flag() {
+    let m = App::new("src")
+        .arg(Arg::with_name("debug").long("debug"))
+        .get_matches_from(vec!["src", "--debug"]);
+
+    assert_eq!(m.value_source("debug"), Some(ValueSource::CommandLine));
+}
+
+#[test]
+fn source_env() {
+    env::set_var("CLP_SOURCE_JOBS", "8");
+    let m = App::new("src")
+        .arg(jobs().env("CLP_SOURCE_JOBS"))
+        .get_matches_from(vec!["src"]);
+
+    assert_eq!(m.value_of("jobs"), Some("8"));
+    assert_eq!(m.value_source("jobs"), Some(ValueSource::EnvVariable));
+    assert!(m.is_explicitly_present("jobs"));
+}
+
+#[test]
+fn source_prompt() {
+    let m = App::new("src")
+        .prompt_streams(Cursor::new("prod\n"), io::sink())
+        .arg(Arg::with_name("cluster")
+            .long("cluster")
+            .required(true)
+            .prompt("Cluster name"))
+        .get_matches_from(vec!["src"]);
+
+    assert_eq!(m.value_source("cluster"), Some(ValueSource::Prompt));
+}
+
+#[test]
+fn source_set_true_unused() {
+    let m = App::new("src")
+        .arg(Arg::with_name("color")
+            .long("color")
+            .action(ArgAction::SetTrue))
+        .get_matches_from(vec!["src"]);
+
+    assert_eq!(m.value_of("color"), Some("false"));
+    assert_eq!(m.value_source("color"), Some(ValueSource::DefaultValue));
+}
+
+#[test]
+fn source_group_most_explicit() {
+    let app = App::new("src")
+        .arg(jobs())
+        .arg(Arg::with_name("threads")
+            .long("threads")
+            .takes_value(true))
+        .group(ArgGroup::with_name("parallel")
+            .args(&["jobs", "threads"])
+            .multiple(true));
+
+    let m = app.clone().get_matches_from(vec!["src", "--threads", "2"]);
+    assert_eq!(m.value_source("parallel"), Some(ValueSource::CommandLine));
+
+    let m = app.get_matches_from(vec!["src"]);
+    assert_eq!(m.value_source("parallel"), Some(ValueSource::DefaultValue));
+}
+
+#[test]
+fn source_subcommand_is_explicit() {
+    let m = App::new("src")
+        .subcommand(SubCommand::with_name("build"))
+        .get_matches_from(vec!["src", "build"]);
+
+    assert!(m.is_explicitly_present("build"));
+}
+
+#[test]
+fn source_global_propagated() {
+    let m = App::new("src")
+        .arg(jobs().global(true))
+        .subcommand(SubCommand::with_name("build"))
+        .get_matches_from(vec!["src", "--jobs", "4", "build"]);
+
+    let sub_m = m.subcommand_matches("build").unwrap();
+    assert_eq!(sub_m.value_of("jobs"), Some("4"));
+    assert_eq!(sub_m.value_source("jobs"), Some(ValueSource::CommandLine));
+}
+
+#[test]
+fn default_conflicts() {
+    let res = App::new("src")
+        .arg(jobs())
+        .arg(Arg::with_name("serial")
+            .long("serial")
+            .conflicts_with("jobs"))
+        .get_matches_from_safe(vec!["src", "--serial"]);
+
+    assert!(res.is_err());
+    assert_eq!(res.unwrap_err().kind, ErrorKind::ArgumentConflict);
+}
+
+#[test]
+fn ignore_defaults_in_conflicts() {
+    let m = App::new("src")
+        .setting(AppSettings::IgnoreDefaultsInRules)
+        .arg(jobs())
+        .arg(Arg::with_name("serial")
+            .long("serial")
+            .conflicts_with("jobs"))
+        .get_matches_from(vec!["src", "--serial"]);
+
+    assert!(m.is_present("serial"));
+    assert_eq!(m.value_of("jobs"), Some("1"));
+}
+
+#[test]
+fn ignore_defaults_in_own_conflicts() {
+    let m = App::new("src")
+        .setting(AppSettings::IgnoreDefaultsInRules)
+        .arg(jobs().conflicts_with("serial"))
+        .arg(Arg::with_name("serial").long("serial"))
+        .get_matches_from(vec!["src", "--serial"]);
+
+    assert!(m.is_present("serial"));
+}
+
+#[test]
+fn ignore_defaults_still_conflicts_when_used() {
+    let res = App::new("src")
+        .setting(AppSettings::IgnoreDefaultsInRules)
+        .arg(jobs())
+        .arg(Arg::with_name("serial")
+            .long("serial")
+            .conflicts_with("jobs"))
+        .get_matches_from_safe(vec!["src", "--serial", "--jobs", "2"]);
+
+    assert!(res.is_err());
+    assert_eq!(res.unwrap_err().kind, ErrorKind::ArgumentConflict);
+}
+
+#[test]
+fn default_requires() {
+    let res = App::new("src")
+        .arg(jobs().requires("scheduler"))
+        .arg(Arg::with_name("scheduler")
+            .long("scheduler")
+            .takes_value(true))
+        .get_matches_from_safe(vec!["src"]);
+
+    assert!(res.is_err());
+    assert_eq!(res.unwrap_err().kind, ErrorKind::MissingRequiredArgument);
+}
+
+#[test]
+fn ignore_defaults_in_requires() {
+    let m = App::new("src")
+        .setting(AppSettings::IgnoreDefaultsInRules)
+        .arg(jobs().requires("scheduler"))
+        .arg(Arg::with_name("scheduler")
+            .long("scheduler")
+            .takes_value(true))
+        .get_matches_from(vec!["src"]);
+
+    assert!(!m.is_present("scheduler"));
+}
@@ -3,10 +3,51 @@
 #[macro_use]
 extern crate clap;
 
-use clap::App;
+use clap::{App, ErrorKind};
 
 #[test]
 fn create_app_from_yaml() {
     let yml = load_yaml!("app.yml");
     App::from_yaml(yml);
 }
+
+#[test]
+fn default_value_if_from_yaml() {
+    let yml = load_yaml!("app.yml");
+    let m = App::from_yaml(yml).get_matches_from(vec!["prog", "-O", "fast"]);
+    assert_eq!(m.value_of("defaultif"), Some("optioned"));
+}
+
+#[test]
+fn required_if_from_yaml() {
+    let yml = load_yaml!("app.yml");
+    let res = App::from_yaml(yml).get_matches_from_safe(vec!["prog", "-O", "slow"]);
+    assert!(res.is_err());
+    assert_eq!(res.unwrap_err().kind, ErrorKind::MissingRequiredArgument);
+}
+
+#[test]
+fn long_help_from_yaml() {
+    let yml = load_yaml!("app.yml");
+    let app = App::from_yaml(yml);
+    let mut help = vec![];
+    app.write_long_help(&mut help).unwrap();
+    let help = String::from_utf8(help).unwrap();
+    assert!(help.contains("tests the clap library in much more detail"));
+    assert!(help.contains("tests flags in much more detail"));
+}
+
+#[test]
+fn value_terminator_from_yaml() {
+    let yml = load_yaml!("app.yml");
+    let m = App::from_yaml(yml).get_matches_from(vec!["prog", "--exec", "ls", "-l", ";", "-f"]);
+    assert_eq!(m.values_of("exec").unwrap().collect::<Vec<_>>(), ["ls", "-l"]);
+    assert!(m.is_present("flag"));
+}
+
+#[test]
+fn action_from_yaml() {
+    let yml = load_yaml!("app.yml");
+    let m = App::from_yaml(yml).get_matches_from(vec!["prog", "--verbose", "--verbose"]);
+    assert_eq!(m.occurrences_of("verbosity"), 2);
+}